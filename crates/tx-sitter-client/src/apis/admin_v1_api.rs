@@ -1,7 +1,7 @@
 /*
  * Tx Sitter
  *
- * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/:api_token/tx` endpoint to create a transaction.
+ * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/tx` endpoint to create a transaction, the API key is sent in an `Authorization: Bearer <api_key>` header.
  *
  * The version of the OpenAPI document: 0.1.0
  *
@@ -15,6 +15,13 @@ use super::{configuration, Error};
 use crate::apis::ResponseContent;
 use crate::models;
 
+/// struct for passing parameters to the method [`add_relayer_pool_member`]
+#[derive(Clone, Debug)]
+pub struct AddRelayerPoolMemberParams {
+    pub pool_id: String,
+    pub relayer_id: String,
+}
+
 /// struct for passing parameters to the method [`create_network`]
 #[derive(Clone, Debug)]
 pub struct CreateNetworkParams {
@@ -28,16 +35,101 @@ pub struct CreateRelayerParams {
     pub create_relayer_request: models::CreateRelayerRequest,
 }
 
+/// struct for passing parameters to the method [`create_relayer_pool`]
+#[derive(Clone, Debug)]
+pub struct CreateRelayerPoolParams {
+    pub create_relayer_pool_request: models::CreateRelayerPoolRequest,
+}
+
+/// struct for passing parameters to the method [`create_relayer_pool_api_key`]
+#[derive(Clone, Debug)]
+pub struct CreateRelayerPoolApiKeyParams {
+    pub pool_id: String,
+    /// Label of the key
+    pub name: Option<String>,
+    /// Operations allowed with the key, defaults to all of them
+    pub scopes: Option<Vec<models::ApiKeyScope>>,
+    /// Unix timestamp in seconds after which the key is rejected
+    pub expires_at: Option<i64>,
+}
+
+/// struct for passing parameters to the method [`decommission_relayer`]
+#[derive(Clone, Debug)]
+pub struct DecommissionRelayerParams {
+    pub relayer_id: String,
+    pub decommission_relayer_request: models::DecommissionRelayerRequest,
+}
+
+/// struct for passing parameters to the method [`get_audit_log`]
+#[derive(Clone, Debug)]
+pub struct GetAuditLogParams {
+    /// Admin name or `api_key:<id>`
+    pub actor: Option<String>,
+    /// Action, e.g. `relayer.update`
+    pub action: Option<String>,
+    /// Id of the relayer, pool, API key or network
+    pub target: Option<String>,
+    /// Unix timestamp in seconds of the oldest entry
+    pub since: Option<i64>,
+    /// Unix timestamp in seconds after the newest entry
+    pub until: Option<i64>,
+    /// Maximum number of entries, defaults to 100
+    pub limit: Option<i32>,
+}
+
 /// struct for passing parameters to the method [`get_relayer`]
 #[derive(Clone, Debug)]
 pub struct GetRelayerParams {
     pub relayer_id: String,
 }
 
+/// struct for passing parameters to the method [`get_relayer_api_keys`]
+#[derive(Clone, Debug)]
+pub struct GetRelayerApiKeysParams {
+    pub relayer_id: String,
+}
+
+/// struct for passing parameters to the method [`get_relayer_decommission`]
+#[derive(Clone, Debug)]
+pub struct GetRelayerDecommissionParams {
+    pub relayer_id: String,
+}
+
+/// struct for passing parameters to the method [`get_relayer_pool`]
+#[derive(Clone, Debug)]
+pub struct GetRelayerPoolParams {
+    pub pool_id: String,
+}
+
+/// struct for passing parameters to the method [`get_relayer_pool_api_keys`]
+#[derive(Clone, Debug)]
+pub struct GetRelayerPoolApiKeysParams {
+    pub pool_id: String,
+}
+
+/// struct for passing parameters to the method [`import_relayer`]
+#[derive(Clone, Debug)]
+pub struct ImportRelayerParams {
+    pub import_relayer_request: models::ImportRelayerRequest,
+}
+
 /// struct for passing parameters to the method [`relayer_create_api_key`]
 #[derive(Clone, Debug)]
 pub struct RelayerCreateApiKeyParams {
     pub relayer_id: String,
+    /// Label of the key
+    pub name: Option<String>,
+    /// Operations allowed with the key, defaults to all of them
+    pub scopes: Option<Vec<models::ApiKeyScope>>,
+    /// Unix timestamp in seconds after which the key is rejected
+    pub expires_at: Option<i64>,
+}
+
+/// struct for passing parameters to the method [`remove_relayer_pool_member`]
+#[derive(Clone, Debug)]
+pub struct RemoveRelayerPoolMemberParams {
+    pub pool_id: String,
+    pub relayer_id: String,
 }
 
 /// struct for passing parameters to the method [`reset_relayer`]
@@ -46,6 +138,26 @@ pub struct ResetRelayerParams {
     pub relayer_id: String,
 }
 
+/// struct for passing parameters to the method [`revoke_relayer_api_key`]
+#[derive(Clone, Debug)]
+pub struct RevokeRelayerApiKeyParams {
+    pub relayer_id: String,
+    pub key_id: String,
+}
+
+/// struct for passing parameters to the method [`revoke_relayer_pool_api_key`]
+#[derive(Clone, Debug)]
+pub struct RevokeRelayerPoolApiKeyParams {
+    pub pool_id: String,
+    pub key_id: String,
+}
+
+/// struct for passing parameters to the method [`rotate_relayer_key`]
+#[derive(Clone, Debug)]
+pub struct RotateRelayerKeyParams {
+    pub relayer_id: String,
+}
+
 /// struct for passing parameters to the method [`update_relayer`]
 #[derive(Clone, Debug)]
 pub struct UpdateRelayerParams {
@@ -53,6 +165,13 @@ pub struct UpdateRelayerParams {
     pub relayer_update_request: models::RelayerUpdateRequest,
 }
 
+/// struct for typed errors of method [`add_relayer_pool_member`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum AddRelayerPoolMemberError {
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`create_network`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -67,6 +186,34 @@ pub enum CreateRelayerError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`create_relayer_pool`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateRelayerPoolError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`create_relayer_pool_api_key`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum CreateRelayerPoolApiKeyError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`decommission_relayer`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DecommissionRelayerError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_audit_log`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetAuditLogError {
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_networks`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -81,6 +228,41 @@ pub enum GetRelayerError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`get_relayer_api_keys`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetRelayerApiKeysError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_relayer_decommission`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetRelayerDecommissionError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_relayer_pool`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetRelayerPoolError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_relayer_pool_api_keys`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetRelayerPoolApiKeysError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`get_relayer_pools`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum GetRelayerPoolsError {
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`get_relayers`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -88,6 +270,13 @@ pub enum GetRelayersError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`import_relayer`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ImportRelayerError {
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`relayer_create_api_key`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -95,6 +284,13 @@ pub enum RelayerCreateApiKeyError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`remove_relayer_pool_member`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RemoveRelayerPoolMemberError {
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`reset_relayer`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -102,6 +298,27 @@ pub enum ResetRelayerError {
     UnknownValue(serde_json::Value),
 }
 
+/// struct for typed errors of method [`revoke_relayer_api_key`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RevokeRelayerApiKeyError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`revoke_relayer_pool_api_key`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RevokeRelayerPoolApiKeyError {
+    UnknownValue(serde_json::Value),
+}
+
+/// struct for typed errors of method [`rotate_relayer_key`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum RotateRelayerKeyError {
+    UnknownValue(serde_json::Value),
+}
+
 /// struct for typed errors of method [`update_relayer`]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
@@ -109,25 +326,26 @@ pub enum UpdateRelayerError {
     UnknownValue(serde_json::Value),
 }
 
-pub async fn create_network(
+pub async fn add_relayer_pool_member(
     configuration: &configuration::Configuration,
-    params: CreateNetworkParams,
-) -> Result<(), Error<CreateNetworkError>> {
+    params: AddRelayerPoolMemberParams,
+) -> Result<(), Error<AddRelayerPoolMemberError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
-    let chain_id = params.chain_id;
-    let create_network_request = params.create_network_request;
+    let pool_id = params.pool_id;
+    let relayer_id = params.relayer_id;
 
     let local_var_client = &local_var_configuration.client;
 
     let local_var_uri_str = format!(
-        "{}/1/admin/network/{chain_id}",
+        "{}/1/admin/pool/{pool_id}/relayer/{relayer_id}",
         local_var_configuration.base_path,
-        chain_id = chain_id
+        pool_id = crate::apis::urlencode(pool_id),
+        relayer_id = crate::apis::urlencode(relayer_id)
     );
     let mut local_var_req_builder = local_var_client
-        .request(reqwest::Method::POST, local_var_uri_str.as_str());
+        .request(reqwest::Method::PUT, local_var_uri_str.as_str());
 
     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
         local_var_req_builder = local_var_req_builder
@@ -139,7 +357,12 @@ pub async fn create_network(
             local_var_auth_conf.1.to_owned(),
         );
     };
-    local_var_req_builder = local_var_req_builder.json(&create_network_request);
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
 
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
@@ -152,7 +375,7 @@ pub async fn create_network(
     {
         Ok(())
     } else {
-        let local_var_entity: Option<CreateNetworkError> =
+        let local_var_entity: Option<AddRelayerPoolMemberError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -163,19 +386,23 @@ pub async fn create_network(
     }
 }
 
-pub async fn create_relayer(
+pub async fn create_network(
     configuration: &configuration::Configuration,
-    params: CreateRelayerParams,
-) -> Result<models::CreateRelayerResponse, Error<CreateRelayerError>> {
+    params: CreateNetworkParams,
+) -> Result<(), Error<CreateNetworkError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
-    let create_relayer_request = params.create_relayer_request;
+    let chain_id = params.chain_id;
+    let create_network_request = params.create_network_request;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str =
-        format!("{}/1/admin/relayer", local_var_configuration.base_path);
+    let local_var_uri_str = format!(
+        "{}/1/admin/network/{chain_id}",
+        local_var_configuration.base_path,
+        chain_id = chain_id
+    );
     let mut local_var_req_builder = local_var_client
         .request(reqwest::Method::POST, local_var_uri_str.as_str());
 
@@ -189,7 +416,13 @@ pub async fn create_relayer(
             local_var_auth_conf.1.to_owned(),
         );
     };
-    local_var_req_builder = local_var_req_builder.json(&create_relayer_request);
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+    local_var_req_builder = local_var_req_builder.json(&create_network_request);
 
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
@@ -200,9 +433,9 @@ pub async fn create_relayer(
     if !local_var_status.is_client_error()
         && !local_var_status.is_server_error()
     {
-        serde_json::from_str(&local_var_content).map_err(Error::from)
+        Ok(())
     } else {
-        let local_var_entity: Option<CreateRelayerError> =
+        let local_var_entity: Option<CreateNetworkError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -213,19 +446,21 @@ pub async fn create_relayer(
     }
 }
 
-pub async fn get_networks(
+pub async fn create_relayer(
     configuration: &configuration::Configuration,
-) -> Result<Vec<models::NetworkResponse>, Error<GetNetworksError>> {
+    params: CreateRelayerParams,
+) -> Result<models::CreateRelayerResponse, Error<CreateRelayerError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
+    let create_relayer_request = params.create_relayer_request;
 
     let local_var_client = &local_var_configuration.client;
 
     let local_var_uri_str =
-        format!("{}/1/admin/networks", local_var_configuration.base_path);
+        format!("{}/1/admin/relayer", local_var_configuration.base_path);
     let mut local_var_req_builder = local_var_client
-        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
 
     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
         local_var_req_builder = local_var_req_builder
@@ -237,6 +472,13 @@ pub async fn get_networks(
             local_var_auth_conf.1.to_owned(),
         );
     };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+    local_var_req_builder = local_var_req_builder.json(&create_relayer_request);
 
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
@@ -249,7 +491,7 @@ pub async fn get_networks(
     {
         serde_json::from_str(&local_var_content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<GetNetworksError> =
+        let local_var_entity: Option<CreateRelayerError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -260,24 +502,22 @@ pub async fn get_networks(
     }
 }
 
-pub async fn get_relayer(
+/// Groups relayers on the same chain under shared API keys
+pub async fn create_relayer_pool(
     configuration: &configuration::Configuration,
-    params: GetRelayerParams,
-) -> Result<models::RelayerResponse, Error<GetRelayerError>> {
+    params: CreateRelayerPoolParams,
+) -> Result<models::RelayerPoolResponse, Error<CreateRelayerPoolError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
-    let relayer_id = params.relayer_id;
+    let create_relayer_pool_request = params.create_relayer_pool_request;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str = format!(
-        "{}/1/admin/relayer/{relayer_id}",
-        local_var_configuration.base_path,
-        relayer_id = crate::apis::urlencode(relayer_id)
-    );
+    let local_var_uri_str =
+        format!("{}/1/admin/pool", local_var_configuration.base_path);
     let mut local_var_req_builder = local_var_client
-        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
 
     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
         local_var_req_builder = local_var_req_builder
@@ -289,6 +529,14 @@ pub async fn get_relayer(
             local_var_auth_conf.1.to_owned(),
         );
     };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+    local_var_req_builder =
+        local_var_req_builder.json(&create_relayer_pool_request);
 
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
@@ -301,7 +549,7 @@ pub async fn get_relayer(
     {
         serde_json::from_str(&local_var_content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<GetRelayerError> =
+        let local_var_entity: Option<CreateRelayerPoolError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -312,20 +560,45 @@ pub async fn get_relayer(
     }
 }
 
-pub async fn get_relayers(
+/// Transactions sent with pool API keys are routed to pool members
+pub async fn create_relayer_pool_api_key(
     configuration: &configuration::Configuration,
-) -> Result<Vec<models::RelayerResponse>, Error<GetRelayersError>> {
+    params: CreateRelayerPoolApiKeyParams,
+) -> Result<models::CreateApiKeyResponse, Error<CreateRelayerPoolApiKeyError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
+    let pool_id = params.pool_id;
+    let name = params.name;
+    let scopes = params.scopes;
+    let expires_at = params.expires_at;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str =
-        format!("{}/1/admin/relayers", local_var_configuration.base_path);
+    let local_var_uri_str = format!(
+        "{}/1/admin/pool/{pool_id}/key",
+        local_var_configuration.base_path,
+        pool_id = crate::apis::urlencode(pool_id)
+    );
     let mut local_var_req_builder = local_var_client
-        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
 
+    if let Some(ref local_var_str) = name {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("name", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = scopes {
+        local_var_req_builder = local_var_req_builder.query(
+            &local_var_str
+                .iter()
+                .map(|p| ("scopes", p.to_string()))
+                .collect::<Vec<_>>(),
+        );
+    }
+    if let Some(ref local_var_str) = expires_at {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("expiresAt", &local_var_str.to_string())]);
+    }
     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
         local_var_req_builder = local_var_req_builder
             .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
@@ -336,6 +609,12 @@ pub async fn get_relayers(
             local_var_auth_conf.1.to_owned(),
         );
     };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
 
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
@@ -348,7 +627,7 @@ pub async fn get_relayers(
     {
         serde_json::from_str(&local_var_content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<GetRelayersError> =
+        let local_var_entity: Option<CreateRelayerPoolApiKeyError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -359,19 +638,22 @@ pub async fn get_relayers(
     }
 }
 
-pub async fn relayer_create_api_key(
+/// The relayer leaves its pools and rejects new transactions. Once its transactions are mined the remaining balance is swept to `sweepTo`, its API keys are revoked and it is deleted. Transactions of deleted relayers are kept.
+pub async fn decommission_relayer(
     configuration: &configuration::Configuration,
-    params: RelayerCreateApiKeyParams,
-) -> Result<models::CreateApiKeyResponse, Error<RelayerCreateApiKeyError>> {
+    params: DecommissionRelayerParams,
+) -> Result<models::RelayerDecommissionResponse, Error<DecommissionRelayerError>>
+{
     let local_var_configuration = configuration;
 
     // unbox the parameters
     let relayer_id = params.relayer_id;
+    let decommission_relayer_request = params.decommission_relayer_request;
 
     let local_var_client = &local_var_configuration.client;
 
     let local_var_uri_str = format!(
-        "{}/1/admin/relayer/{relayer_id}/key",
+        "{}/1/admin/relayer/{relayer_id}/decommission",
         local_var_configuration.base_path,
         relayer_id = crate::apis::urlencode(relayer_id)
     );
@@ -388,6 +670,14 @@ pub async fn relayer_create_api_key(
             local_var_auth_conf.1.to_owned(),
         );
     };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+    local_var_req_builder =
+        local_var_req_builder.json(&decommission_relayer_request);
 
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
@@ -400,7 +690,7 @@ pub async fn relayer_create_api_key(
     {
         serde_json::from_str(&local_var_content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<RelayerCreateApiKeyError> =
+        let local_var_entity: Option<DecommissionRelayerError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -411,26 +701,52 @@ pub async fn relayer_create_api_key(
     }
 }
 
-/// Purges unsent transactions, useful for unstucking the relayer
-pub async fn reset_relayer(
+/// Admin and key material operations, newest first
+pub async fn get_audit_log(
     configuration: &configuration::Configuration,
-    params: ResetRelayerParams,
-) -> Result<(), Error<ResetRelayerError>> {
+    params: GetAuditLogParams,
+) -> Result<Vec<models::AuditLogEntryResponse>, Error<GetAuditLogError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
-    let relayer_id = params.relayer_id;
+    let actor = params.actor;
+    let action = params.action;
+    let target = params.target;
+    let since = params.since;
+    let until = params.until;
+    let limit = params.limit;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str = format!(
-        "{}/1/admin/relayer/{relayer_id}/reset",
-        local_var_configuration.base_path,
-        relayer_id = crate::apis::urlencode(relayer_id)
-    );
+    let local_var_uri_str =
+        format!("{}/1/admin/audit-log", local_var_configuration.base_path);
     let mut local_var_req_builder = local_var_client
-        .request(reqwest::Method::POST, local_var_uri_str.as_str());
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
 
+    if let Some(ref local_var_str) = actor {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("actor", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = action {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("action", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = target {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("target", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = since {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("since", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = until {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("until", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = limit {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("limit", &local_var_str.to_string())]);
+    }
     if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
         local_var_req_builder = local_var_req_builder
             .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
@@ -442,6 +758,13 @@ pub async fn reset_relayer(
         );
     };
 
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
     let local_var_req = local_var_req_builder.build()?;
     let local_var_resp = local_var_client.execute(local_var_req).await?;
 
@@ -451,9 +774,9 @@ pub async fn reset_relayer(
     if !local_var_status.is_client_error()
         && !local_var_status.is_server_error()
     {
-        Ok(())
+        serde_json::from_str(&local_var_content).map_err(Error::from)
     } else {
-        let local_var_entity: Option<ResetRelayerError> =
+        let local_var_entity: Option<GetAuditLogError> =
             serde_json::from_str(&local_var_content).ok();
         let local_var_error = ResponseContent {
             status: local_var_status,
@@ -464,19 +787,906 @@ pub async fn reset_relayer(
     }
 }
 
-pub async fn update_relayer(
+pub async fn get_networks(
     configuration: &configuration::Configuration,
-    params: UpdateRelayerParams,
-) -> Result<(), Error<UpdateRelayerError>> {
+) -> Result<Vec<models::NetworkResponse>, Error<GetNetworksError>> {
     let local_var_configuration = configuration;
 
     // unbox the parameters
-    let relayer_id = params.relayer_id;
-    let relayer_update_request = params.relayer_update_request;
 
     let local_var_client = &local_var_configuration.client;
 
-    let local_var_uri_str = format!(
+    let local_var_uri_str =
+        format!("{}/1/admin/networks", local_var_configuration.base_path);
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetNetworksError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayer(
+    configuration: &configuration::Configuration,
+    params: GetRelayerParams,
+) -> Result<models::RelayerResponse, Error<GetRelayerError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayerError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayer_api_keys(
+    configuration: &configuration::Configuration,
+    params: GetRelayerApiKeysParams,
+) -> Result<Vec<models::ApiKeyResponse>, Error<GetRelayerApiKeysError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}/keys",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayerApiKeysError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayer_decommission(
+    configuration: &configuration::Configuration,
+    params: GetRelayerDecommissionParams,
+) -> Result<
+    models::RelayerDecommissionResponse,
+    Error<GetRelayerDecommissionError>,
+> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}/decommission",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayerDecommissionError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayer_pool(
+    configuration: &configuration::Configuration,
+    params: GetRelayerPoolParams,
+) -> Result<models::RelayerPoolResponse, Error<GetRelayerPoolError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let pool_id = params.pool_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/pool/{pool_id}",
+        local_var_configuration.base_path,
+        pool_id = crate::apis::urlencode(pool_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayerPoolError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayer_pool_api_keys(
+    configuration: &configuration::Configuration,
+    params: GetRelayerPoolApiKeysParams,
+) -> Result<Vec<models::ApiKeyResponse>, Error<GetRelayerPoolApiKeysError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let pool_id = params.pool_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/pool/{pool_id}/keys",
+        local_var_configuration.base_path,
+        pool_id = crate::apis::urlencode(pool_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayerPoolApiKeysError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayer_pools(
+    configuration: &configuration::Configuration,
+) -> Result<Vec<models::RelayerPoolResponse>, Error<GetRelayerPoolsError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str =
+        format!("{}/1/admin/pools", local_var_configuration.base_path);
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayerPoolsError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn get_relayers(
+    configuration: &configuration::Configuration,
+) -> Result<Vec<models::RelayerResponse>, Error<GetRelayersError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str =
+        format!("{}/1/admin/relayers", local_var_configuration.base_path);
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::GET, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<GetRelayersError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Creates a relayer from an existing key, its nonce is synced from the chain
+pub async fn import_relayer(
+    configuration: &configuration::Configuration,
+    params: ImportRelayerParams,
+) -> Result<models::CreateRelayerResponse, Error<ImportRelayerError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let import_relayer_request = params.import_relayer_request;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/import",
+        local_var_configuration.base_path
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+    local_var_req_builder = local_var_req_builder.json(&import_relayer_request);
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<ImportRelayerError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn relayer_create_api_key(
+    configuration: &configuration::Configuration,
+    params: RelayerCreateApiKeyParams,
+) -> Result<models::CreateApiKeyResponse, Error<RelayerCreateApiKeyError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+    let name = params.name;
+    let scopes = params.scopes;
+    let expires_at = params.expires_at;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}/key",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_str) = name {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("name", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_str) = scopes {
+        local_var_req_builder = local_var_req_builder.query(
+            &local_var_str
+                .iter()
+                .map(|p| ("scopes", p.to_string()))
+                .collect::<Vec<_>>(),
+        );
+    }
+    if let Some(ref local_var_str) = expires_at {
+        local_var_req_builder = local_var_req_builder
+            .query(&[("expiresAt", &local_var_str.to_string())]);
+    }
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<RelayerCreateApiKeyError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Queued txs of the relayer are still sent, txs with route keys pinned to it are routed to other members
+pub async fn remove_relayer_pool_member(
+    configuration: &configuration::Configuration,
+    params: RemoveRelayerPoolMemberParams,
+) -> Result<(), Error<RemoveRelayerPoolMemberError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let pool_id = params.pool_id;
+    let relayer_id = params.relayer_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/pool/{pool_id}/relayer/{relayer_id}",
+        local_var_configuration.base_path,
+        pool_id = crate::apis::urlencode(pool_id),
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        Ok(())
+    } else {
+        let local_var_entity: Option<RemoveRelayerPoolMemberError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Purges unsent transactions, useful for unstucking the relayer
+pub async fn reset_relayer(
+    configuration: &configuration::Configuration,
+    params: ResetRelayerParams,
+) -> Result<(), Error<ResetRelayerError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}/reset",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        Ok(())
+    } else {
+        let local_var_entity: Option<ResetRelayerError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn revoke_relayer_api_key(
+    configuration: &configuration::Configuration,
+    params: RevokeRelayerApiKeyParams,
+) -> Result<(), Error<RevokeRelayerApiKeyError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+    let key_id = params.key_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}/key/{key_id}",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id),
+        key_id = crate::apis::urlencode(key_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        Ok(())
+    } else {
+        let local_var_entity: Option<RevokeRelayerApiKeyError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn revoke_relayer_pool_api_key(
+    configuration: &configuration::Configuration,
+    params: RevokeRelayerPoolApiKeyParams,
+) -> Result<(), Error<RevokeRelayerPoolApiKeyError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let pool_id = params.pool_id;
+    let key_id = params.key_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/pool/{pool_id}/key/{key_id}",
+        local_var_configuration.base_path,
+        pool_id = crate::apis::urlencode(pool_id),
+        key_id = crate::apis::urlencode(key_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::DELETE, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        Ok(())
+    } else {
+        let local_var_entity: Option<RevokeRelayerPoolApiKeyError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+/// Creates a new key for the relayer. Once all transactions of the current key are mined its remaining balance is swept to the new key and the relayer switches to it, new transactions are rejected until then.
+pub async fn rotate_relayer_key(
+    configuration: &configuration::Configuration,
+    params: RotateRelayerKeyParams,
+) -> Result<models::RotateRelayerKeyResponse, Error<RotateRelayerKeyError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
+        "{}/1/admin/relayer/{relayer_id}/rotate-key",
+        local_var_configuration.base_path,
+        relayer_id = crate::apis::urlencode(relayer_id)
+    );
+    let mut local_var_req_builder = local_var_client
+        .request(reqwest::Method::POST, local_var_uri_str.as_str());
+
+    if let Some(ref local_var_user_agent) = local_var_configuration.user_agent {
+        local_var_req_builder = local_var_req_builder
+            .header(reqwest::header::USER_AGENT, local_var_user_agent.clone());
+    }
+    if let Some(ref local_var_auth_conf) = local_var_configuration.basic_auth {
+        local_var_req_builder = local_var_req_builder.basic_auth(
+            local_var_auth_conf.0.to_owned(),
+            local_var_auth_conf.1.to_owned(),
+        );
+    };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
+
+    let local_var_req = local_var_req_builder.build()?;
+    let local_var_resp = local_var_client.execute(local_var_req).await?;
+
+    let local_var_status = local_var_resp.status();
+    let local_var_content = local_var_resp.text().await?;
+
+    if !local_var_status.is_client_error()
+        && !local_var_status.is_server_error()
+    {
+        serde_json::from_str(&local_var_content).map_err(Error::from)
+    } else {
+        let local_var_entity: Option<RotateRelayerKeyError> =
+            serde_json::from_str(&local_var_content).ok();
+        let local_var_error = ResponseContent {
+            status: local_var_status,
+            content: local_var_content,
+            entity: local_var_entity,
+        };
+        Err(Error::ResponseError(local_var_error))
+    }
+}
+
+pub async fn update_relayer(
+    configuration: &configuration::Configuration,
+    params: UpdateRelayerParams,
+) -> Result<(), Error<UpdateRelayerError>> {
+    let local_var_configuration = configuration;
+
+    // unbox the parameters
+    let relayer_id = params.relayer_id;
+    let relayer_update_request = params.relayer_update_request;
+
+    let local_var_client = &local_var_configuration.client;
+
+    let local_var_uri_str = format!(
         "{}/1/admin/relayer/{relayer_id}",
         local_var_configuration.base_path,
         relayer_id = crate::apis::urlencode(relayer_id)
@@ -494,6 +1704,12 @@ pub async fn update_relayer(
             local_var_auth_conf.1.to_owned(),
         );
     };
+    if let Some(ref local_var_token) =
+        local_var_configuration.bearer_access_token
+    {
+        local_var_req_builder =
+            local_var_req_builder.bearer_auth(local_var_token.to_owned());
+    };
     local_var_req_builder = local_var_req_builder.json(&relayer_update_request);
 
     let local_var_req = local_var_req_builder.build()?;
@@ -1,7 +1,7 @@
 /*
  * Tx Sitter
  *
- * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/:api_token/tx` endpoint to create a transaction.
+ * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/tx` endpoint to create a transaction, the API key is sent in an `Authorization: Bearer <api_key>` header.
  *
  * The version of the OpenAPI document: 0.1.0
  *
@@ -49,6 +49,7 @@ pub struct ConfigurationBuilder {
     pub base_path: Option<String>,
     pub user_agent: Option<String>,
     pub basic_auth: Option<BasicAuth>,
+    pub bearer_access_token: Option<String>,
 }
 
 impl ConfigurationBuilder {
@@ -57,6 +58,7 @@ impl ConfigurationBuilder {
             base_path: None,
             user_agent: None,
             basic_auth: None,
+            bearer_access_token: None,
         }
     }
 
@@ -75,6 +77,11 @@ impl ConfigurationBuilder {
         self
     }
 
+    pub fn bearer_auth(mut self, token: String) -> Self {
+        self.bearer_access_token = Some(token);
+        self
+    }
+
     pub fn build(self) -> Configuration {
         let mut conf: Configuration = Default::default();
 
@@ -90,6 +97,10 @@ impl ConfigurationBuilder {
             conf.basic_auth = Some(basic_auth);
         }
 
+        if let Some(bearer_access_token) = self.bearer_access_token {
+            conf.bearer_access_token = Some(bearer_access_token);
+        }
+
         conf
     }
 }
@@ -0,0 +1,60 @@
+/*
+ * Tx Sitter
+ *
+ * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/tx` endpoint to create a transaction, the API key is sent in an `Authorization: Bearer <api_key>` header.
+ *
+ * The version of the OpenAPI document: 0.1.0
+ *
+ * Generated by: https://openapi-generator.tech
+ */
+
+use serde::{Deserialize, Serialize};
+
+use crate::models;
+
+#[derive(Clone, Default, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogEntryResponse {
+    #[serde(rename = "id")]
+    pub id: i64,
+    /// Unix timestamp in seconds
+    #[serde(rename = "createdAt")]
+    pub created_at: i64,
+    /// Admin or API key (`api_key:<id>`) that performed the action
+    #[serde(rename = "actor")]
+    pub actor: String,
+    /// Action performed, e.g. `relayer.update`
+    #[serde(rename = "action")]
+    pub action: String,
+    /// Id of the changed relayer, pool, API key or network
+    #[serde(rename = "target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// State of the target before the action
+    #[serde(rename = "before", skip_serializing_if = "Option::is_none")]
+    pub before: Option<serde_json::Value>,
+    /// State of the target after the action
+    #[serde(rename = "after", skip_serializing_if = "Option::is_none")]
+    pub after: Option<serde_json::Value>,
+    /// Id of the request, sent back in the `x-request-id` header
+    #[serde(rename = "requestId", skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+}
+
+impl AuditLogEntryResponse {
+    pub fn new(
+        id: i64,
+        created_at: i64,
+        actor: String,
+        action: String,
+    ) -> AuditLogEntryResponse {
+        AuditLogEntryResponse {
+            id,
+            created_at,
+            actor,
+            action,
+            target: None,
+            before: None,
+            after: None,
+            request_id: None,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 /*
  * Tx Sitter
  *
- * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/:api_token/tx` endpoint to create a transaction.
+ * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/tx` endpoint to create a transaction, the API key is sent in an `Authorization: Bearer <api_key>` header.
  *
  * The version of the OpenAPI document: 0.1.0
  *
@@ -17,8 +17,8 @@ pub struct GetTxResponse {
     #[serde(rename = "txId")]
     pub tx_id: String,
     /// Hex encoded ethereum address
-    #[serde(rename = "to")]
-    pub to: base_api_types::Address,
+    #[serde(rename = "to", skip_serializing_if = "Option::is_none")]
+    pub to: Option<base_api_types::Address>,
     #[serde(rename = "data", skip_serializing_if = "Option::is_none")]
     pub data: Option<base_api_types::HexBytes>,
     /// A decimal 256-bit unsigned integer
@@ -34,25 +34,35 @@ pub struct GetTxResponse {
     pub tx_hash: Option<base_api_types::H256>,
     #[serde(rename = "status", skip_serializing_if = "Option::is_none")]
     pub status: Option<models::TxStatus>,
+    /// Address of the deployed contract, available once mined
+    #[serde(
+        rename = "contractAddress",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub contract_address: Option<base_api_types::Address>,
+    /// Decoded function call, if the tx was submitted with `call`
+    #[serde(rename = "call", skip_serializing_if = "Option::is_none")]
+    pub call: Option<models::DecodedCallResponse>,
 }
 
 impl GetTxResponse {
     pub fn new(
         tx_id: String,
-        to: base_api_types::Address,
         value: base_api_types::DecimalU256,
         gas_limit: base_api_types::DecimalU256,
         nonce: i32,
     ) -> GetTxResponse {
         GetTxResponse {
             tx_id,
-            to,
+            to: None,
             data: None,
             value,
             gas_limit,
             nonce,
             tx_hash: None,
             status: None,
+            contract_address: None,
+            call: None,
         }
     }
 }
@@ -1,29 +1,81 @@
+pub mod allowed_target_response;
+pub use self::allowed_target_response::AllowedTargetResponse;
+pub mod api_key_response;
+pub use self::api_key_response::ApiKeyResponse;
+pub mod api_key_scope;
+pub use self::api_key_scope::ApiKeyScope;
+pub mod audit_log_entry_response;
+pub use self::audit_log_entry_response::AuditLogEntryResponse;
+pub mod contract_call;
+pub use self::contract_call::ContractCall;
 pub mod create_api_key_response;
 pub use self::create_api_key_response::CreateApiKeyResponse;
 pub mod create_network_request;
 pub use self::create_network_request::CreateNetworkRequest;
+pub mod create_relayer_pool_request;
+pub use self::create_relayer_pool_request::CreateRelayerPoolRequest;
 pub mod create_relayer_request;
 pub use self::create_relayer_request::CreateRelayerRequest;
 pub mod create_relayer_response;
 pub use self::create_relayer_response::CreateRelayerResponse;
+pub mod decoded_arg_response;
+pub use self::decoded_arg_response::DecodedArgResponse;
+pub mod decoded_call_response;
+pub use self::decoded_call_response::DecodedCallResponse;
+pub mod decommission_relayer_request;
+pub use self::decommission_relayer_request::DecommissionRelayerRequest;
 pub mod get_tx_response;
 pub use self::get_tx_response::GetTxResponse;
+pub mod import_relayer_request;
+pub use self::import_relayer_request::ImportRelayerRequest;
 pub mod json_rpc_version;
 pub use self::json_rpc_version::JsonRpcVersion;
 pub mod network_response;
 pub use self::network_response::NetworkResponse;
+pub mod rate_limit_response;
+pub use self::rate_limit_response::RateLimitResponse;
+pub mod relayer_balance_response;
+pub use self::relayer_balance_response::RelayerBalanceResponse;
+pub mod relayer_budget_response;
+pub use self::relayer_budget_response::RelayerBudgetResponse;
+pub mod relayer_decommission_response;
+pub use self::relayer_decommission_response::RelayerDecommissionResponse;
+pub mod relayer_funding_response;
+pub use self::relayer_funding_response::RelayerFundingResponse;
 pub mod relayer_gas_price_limit_response;
 pub use self::relayer_gas_price_limit_response::RelayerGasPriceLimitResponse;
+pub mod relayer_policy_response;
+pub use self::relayer_policy_response::RelayerPolicyResponse;
+pub mod relayer_pool_response;
+pub use self::relayer_pool_response::RelayerPoolResponse;
+pub mod relayer_rate_limits_response;
+pub use self::relayer_rate_limits_response::RelayerRateLimitsResponse;
 pub mod relayer_response;
 pub use self::relayer_response::RelayerResponse;
 pub mod relayer_update_request;
 pub use self::relayer_update_request::RelayerUpdateRequest;
+pub mod rotate_relayer_key_response;
+pub use self::rotate_relayer_key_response::RotateRelayerKeyResponse;
+pub mod rpc_payload;
+pub use self::rpc_payload::RpcPayload;
 pub mod rpc_request;
 pub use self::rpc_request::RpcRequest;
 pub mod send_tx_request;
 pub use self::send_tx_request::SendTxRequest;
 pub mod send_tx_response;
 pub use self::send_tx_response::SendTxResponse;
+pub mod sign_message_request;
+pub use self::sign_message_request::SignMessageRequest;
+pub mod sign_typed_data_request;
+pub use self::sign_typed_data_request::SignTypedDataRequest;
+pub mod service_status;
+pub use self::service_status::ServiceStatus;
+pub mod signature_response;
+pub use self::signature_response::SignatureResponse;
+pub mod task_status;
+pub use self::task_status::TaskStatus;
+pub mod tracked_token_response;
+pub use self::tracked_token_response::TrackedTokenResponse;
 pub mod transaction_priority;
 pub use self::transaction_priority::TransactionPriority;
 pub mod tx_status;
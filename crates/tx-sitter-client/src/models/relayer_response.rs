@@ -1,7 +1,7 @@
 /*
  * Tx Sitter
  *
- * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/:api_token/tx` endpoint to create a transaction.
+ * A transaction relayer service!  ## Operating a relayer Below is a guide on using this service. Note that steps 1 through 4 require authentication using HTTP Basic auth. Using swagger explorer make sure to click the authorize button and use the correct credentials. Default dev creds are `admin:admin`.  ### 1. Setup a network tx-sitter keeps track of supported networks in its internal database. In order to be able to create any relayers at least one network must be present. To add a network use the `POST /1/admin/networks/:chain_id` endpoint.  To see the list of currently added networks use the `GET /1/admin/networks` endpoint.  ### 2. Create a relayer A relayer is an abstraction layer on top of a private key stored locally (for testing purposes only!) or using a secrets manager (currently only AWS KMS is supported).  To create a relayer use the `POST /1/admin/relayer` endpoint. The data returned will contain a relayer id, make sure to copy it to the clipboard.  ### 3. Create an API key By itself a relayer is not very useful. In order to send transactions one must create an API key. To do that use the `POST /1/admin/relayer/:relayer_id/key` endpoint. **Make sure to copy the API key from the response. It's not possible to recover it!** But it's always possible to create a new one.  ### 4. Use the API key Once an API keys has been created it's possible to use the relayer api to, among other things, send transactions.  You can use the `POST /1/api/tx` endpoint to create a transaction, the API key is sent in an `Authorization: Bearer <api_key>` header.
  *
  * The version of the OpenAPI document: 0.1.0
  *
@@ -37,6 +37,19 @@ pub struct RelayerResponse {
     pub gas_price_limits: Vec<models::RelayerGasPriceLimitResponse>,
     #[serde(rename = "enabled")]
     pub enabled: bool,
+    #[serde(rename = "budget")]
+    pub budget: models::RelayerBudgetResponse,
+    #[serde(rename = "policy")]
+    pub policy: models::RelayerPolicyResponse,
+    #[serde(rename = "signingEnabled")]
+    pub signing_enabled: bool,
+    #[serde(rename = "funding")]
+    pub funding: models::RelayerFundingResponse,
+    #[serde(rename = "rateLimits")]
+    pub rate_limits: models::RelayerRateLimitsResponse,
+    /// Last observed balances of the relayer
+    #[serde(rename = "balances", skip_serializing_if = "Option::is_none")]
+    pub balances: Option<Vec<models::RelayerBalanceResponse>>,
 }
 
 impl RelayerResponse {
@@ -52,6 +65,11 @@ impl RelayerResponse {
         max_queued_txs: i32,
         gas_price_limits: Vec<models::RelayerGasPriceLimitResponse>,
         enabled: bool,
+        budget: models::RelayerBudgetResponse,
+        policy: models::RelayerPolicyResponse,
+        signing_enabled: bool,
+        funding: models::RelayerFundingResponse,
+        rate_limits: models::RelayerRateLimitsResponse,
     ) -> RelayerResponse {
         RelayerResponse {
             id,
@@ -65,6 +83,12 @@ impl RelayerResponse {
             max_queued_txs,
             gas_price_limits,
             enabled,
+            budget,
+            policy,
+            signing_enabled,
+            funding,
+            rate_limits,
+            balances: None,
         }
     }
 }
@@ -0,0 +1,10 @@
+ALTER TABLE relayers
+ADD COLUMN budget JSON NOT NULL DEFAULT '{}';
+
+ALTER TABLE transactions
+ADD COLUMN created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP;
+
+-- Receipt data, filled in once the valid tx hash is mined
+ALTER TABLE sent_transactions
+ADD COLUMN gas_used            BYTEA,
+ADD COLUMN effective_gas_price BYTEA;
@@ -0,0 +1,2 @@
+ALTER TABLE relayers
+ADD COLUMN policy JSON NOT NULL DEFAULT '{}';
@@ -0,0 +1,6 @@
+-- Contract creation txs have no destination address
+ALTER TABLE transactions
+ALTER COLUMN tx_to DROP NOT NULL;
+
+ALTER TABLE sent_transactions
+ADD COLUMN contract_address BYTEA;
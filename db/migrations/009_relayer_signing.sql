@@ -0,0 +1,19 @@
+ALTER TABLE relayers
+ADD COLUMN signing_enabled BOOL NOT NULL DEFAULT FALSE;
+
+CREATE TYPE signature_kind AS ENUM ('message', 'typedData');
+
+-- Audit log of all off-chain signatures created by relayers
+CREATE TABLE signatures (
+    id         BIGSERIAL PRIMARY KEY,
+    relayer_id CHAR(36) NOT NULL REFERENCES relayers(id) ON DELETE CASCADE,
+    kind       signature_kind NOT NULL,
+    -- Raw message or JSON encoded typed data
+    payload    BYTEA NOT NULL,
+    -- EIP-191 or EIP-712 hash that was signed
+    hash       BYTEA NOT NULL,
+    signature  BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE INDEX signatures_relayer_id_idx ON signatures(relayer_id);
@@ -0,0 +1,2 @@
+ALTER TABLE transactions
+ADD COLUMN call JSON;
@@ -0,0 +1,6 @@
+CREATE TABLE local_keys (
+    id         CHAR(36) PRIMARY KEY,
+    -- AES-256-GCM nonce followed by the encrypted private key
+    sealed_key BYTEA NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,18 @@
+-- Retired keys of relayers, newest last
+CREATE TABLE relayer_keys (
+    relayer_id    CHAR(36) NOT NULL REFERENCES relayers(id) ON DELETE CASCADE,
+    key_id        VARCHAR(255) NOT NULL,
+    address       BYTEA NOT NULL,
+    -- Hash of the tx sweeping the remaining balance to the new key
+    sweep_tx_hash BYTEA,
+    retired_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+-- Key rotations in progress
+CREATE TABLE relayer_key_rotations (
+    relayer_id    CHAR(36) PRIMARY KEY REFERENCES relayers(id) ON DELETE CASCADE,
+    key_id        VARCHAR(255) NOT NULL,
+    address       BYTEA NOT NULL,
+    sweep_tx_hash BYTEA,
+    created_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,27 @@
+-- Groups of relayers on the same chain sharing API keys
+CREATE TABLE relayer_pools (
+    -- UUID v4, pool API keys embed it like relayer API keys
+    id         CHAR(36) PRIMARY KEY,
+    name       VARCHAR(255) NOT NULL,
+    chain_id   BIGINT NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TABLE relayer_pool_members (
+    pool_id    CHAR(36) NOT NULL REFERENCES relayer_pools(id) ON DELETE CASCADE,
+    relayer_id CHAR(36) NOT NULL REFERENCES relayers(id) ON DELETE CASCADE,
+    PRIMARY KEY (pool_id, relayer_id)
+);
+
+-- API keys belong to either a relayer or a pool
+ALTER TABLE api_keys
+ALTER COLUMN relayer_id DROP NOT NULL,
+ADD COLUMN pool_id CHAR(36) REFERENCES relayer_pools(id) ON DELETE CASCADE,
+ADD CONSTRAINT api_keys_owner_check CHECK ((relayer_id IS NULL) <> (pool_id IS NULL));
+
+-- Txs sent through a pool, txs with the same route key stick to one relayer
+ALTER TABLE transactions
+ADD COLUMN pool_id CHAR(36) REFERENCES relayer_pools(id) ON DELETE SET NULL,
+ADD COLUMN route_key VARCHAR(255);
+
+CREATE INDEX transactions_pool_id_route_key_idx ON transactions(pool_id, route_key);
@@ -0,0 +1,3 @@
+-- Balance thresholds and the treasury relayer topping up the relayer
+ALTER TABLE relayers
+ADD COLUMN funding JSON NOT NULL DEFAULT '{}';
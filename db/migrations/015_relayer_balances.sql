@@ -0,0 +1,12 @@
+-- Low balance watermark of relayers on the network which have none set
+ALTER TABLE networks
+ADD COLUMN low_balance BYTEA;
+
+-- Last observed balances of relayers, the zero address stands for the native balance
+CREATE TABLE relayer_balances (
+    relayer_id CHAR(36) NOT NULL REFERENCES relayers(id) ON DELETE CASCADE,
+    token      BYTEA NOT NULL,
+    balance    BYTEA NOT NULL,
+    updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (relayer_id, token)
+);
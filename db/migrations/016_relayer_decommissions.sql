@@ -0,0 +1,11 @@
+-- Deleted relayers are hidden, their transactions are kept
+ALTER TABLE relayers
+ADD COLUMN deleted_at TIMESTAMP;
+
+-- Relayers draining before their balance is swept and they are deleted
+CREATE TABLE relayer_decommissions (
+    relayer_id    CHAR(36) PRIMARY KEY REFERENCES relayers(id) ON DELETE CASCADE,
+    sweep_to      BYTEA NOT NULL,
+    sweep_tx_hash BYTEA,
+    created_at    TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
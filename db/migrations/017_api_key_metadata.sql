@@ -0,0 +1,16 @@
+-- Duplicate keys are dropped so key hashes can be unique
+DELETE FROM api_keys a
+USING  api_keys b
+WHERE  a.ctid < b.ctid
+AND    a.key_hash = b.key_hash;
+
+-- Named API keys with usage tracking and an optional expiry
+ALTER TABLE api_keys
+ADD COLUMN id           CHAR(36) NOT NULL DEFAULT gen_random_uuid()::TEXT,
+ADD COLUMN name         VARCHAR(255),
+ADD COLUMN created_at   TIMESTAMPTZ NOT NULL DEFAULT CURRENT_TIMESTAMP,
+ADD COLUMN last_used_at TIMESTAMPTZ,
+ADD COLUMN expires_at   TIMESTAMPTZ,
+ADD PRIMARY KEY (id);
+
+CREATE UNIQUE INDEX api_keys_key_hash_idx ON api_keys(key_hash);
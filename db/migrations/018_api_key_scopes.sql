@@ -0,0 +1,3 @@
+-- Relayer API operations allowed with the key, existing keys keep full access
+ALTER TABLE api_keys
+ADD COLUMN scopes JSON NOT NULL DEFAULT '["send", "read", "rpc", "sign"]';
@@ -0,0 +1,3 @@
+-- Per route class token bucket limits of the API keys of a relayer
+ALTER TABLE relayers
+ADD COLUMN rate_limits JSON NOT NULL DEFAULT '{}';
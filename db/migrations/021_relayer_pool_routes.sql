@@ -0,0 +1,17 @@
+-- Pool member sending the txs of a route key, one per key
+CREATE TABLE relayer_pool_routes (
+    pool_id    CHAR(36) NOT NULL,
+    route_key  VARCHAR(255) NOT NULL,
+    relayer_id CHAR(36) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    PRIMARY KEY (pool_id, route_key),
+    FOREIGN KEY (pool_id, relayer_id) REFERENCES relayer_pool_members(pool_id, relayer_id) ON DELETE CASCADE
+);
+
+-- Routes of earlier txs go to the member which sent the latest one
+INSERT INTO relayer_pool_routes (pool_id, route_key, relayer_id)
+SELECT     DISTINCT ON (t.pool_id, t.route_key) t.pool_id, t.route_key, t.relayer_id
+FROM       transactions t
+INNER JOIN relayer_pool_members m ON (m.pool_id = t.pool_id AND m.relayer_id = t.relayer_id)
+WHERE      t.route_key IS NOT NULL
+ORDER BY   t.pool_id, t.route_key, t.created_at DESC;
@@ -0,0 +1,535 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use argon2::password_hash::{
+    PasswordHash, PasswordHasher, PasswordVerifier, SaltString,
+};
+use argon2::Argon2;
+use eyre::{Context, ContextCompat};
+use jsonwebtoken::jwk::{AlgorithmParameters, EllipticCurve, Jwk, JwkSet};
+use jsonwebtoken::{Algorithm, DecodingKey, Validation};
+use rand::rngs::OsRng;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tokio::sync::RwLock;
+
+use crate::config::{AdminAccount, JwtConfig, ServerConfig};
+
+/// Minimum time between fetches of the JWKS triggered by unknown key ids
+const JWKS_REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum AdminRole {
+    /// Can only read relayers, networks, pools and keys
+    ReadOnly,
+    /// Can also create and change them
+    Operator,
+}
+
+/// Admin on whose behalf an admin API call is made
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdminIdentity {
+    pub name: String,
+    pub role: AdminRole,
+}
+
+impl fmt::Display for AdminIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+/// Verifies the credentials of admin API calls
+pub struct AdminAuthenticator {
+    disabled: bool,
+    admins: Vec<AdminAccount>,
+    /// Checked for unknown usernames, so that they take as long to reject
+    /// as wrong passwords
+    dummy_hash: String,
+    jwt: Option<JwtVerifier>,
+}
+
+impl AdminAuthenticator {
+    pub async fn new(config: &ServerConfig) -> eyre::Result<Self> {
+        let mut admins = config.admins.clone();
+
+        for admin in &admins {
+            PasswordHash::new(&admin.password_hash).map_err(|err| {
+                eyre::eyre!(
+                    "Invalid password hash of admin {}: {err}",
+                    admin.username
+                )
+            })?;
+        }
+
+        if let Some((username, password)) = config.credentials() {
+            tracing::warn!(
+                "server.username and server.password are deprecated, use server.admins"
+            );
+
+            admins.push(AdminAccount {
+                username: username.to_string(),
+                password_hash: hash_password(password)?,
+                role: AdminRole::Operator,
+            });
+        }
+
+        let jwt = match &config.jwt {
+            Some(jwt) => Some(JwtVerifier::new(jwt.clone()).await?),
+            None => None,
+        };
+
+        if admins.is_empty() && jwt.is_none() {
+            if !config.disable_auth {
+                eyre::bail!(
+                    "No admin credentials configured, set server.admins or server.jwt, or server.disable_auth to allow unauthenticated admin access"
+                );
+            }
+
+            tracing::warn!("Admin API authentication is disabled");
+        }
+
+        Ok(Self {
+            disabled: admins.is_empty() && jwt.is_none(),
+            admins,
+            dummy_hash: hash_password("")?,
+            jwt,
+        })
+    }
+
+    /// Whether admin calls are allowed without credentials
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
+    pub async fn verify_password(
+        &self,
+        username: &str,
+        password: &str,
+    ) -> Option<AdminIdentity> {
+        let admin = self
+            .admins
+            .iter()
+            .find(|admin| admin.username == username)
+            .cloned();
+
+        let password_hash = admin
+            .as_ref()
+            .map_or(&self.dummy_hash, |admin| &admin.password_hash)
+            .clone();
+        let password = password.to_string();
+
+        // Hashing is slow on purpose, keep it off the async workers
+        let verified = tokio::task::spawn_blocking(move || {
+            let Ok(hash) = PasswordHash::new(&password_hash) else {
+                return false;
+            };
+
+            Argon2::default()
+                .verify_password(password.as_bytes(), &hash)
+                .is_ok()
+        })
+        .await
+        .unwrap_or(false);
+
+        let admin = admin.filter(|_| verified)?;
+
+        Some(AdminIdentity {
+            name: admin.username,
+            role: admin.role,
+        })
+    }
+
+    /// Returns the admin of a valid token, tokens are rejected if JWT
+    /// authentication is not configured
+    pub async fn verify_token(
+        &self,
+        token: &str,
+    ) -> eyre::Result<Option<AdminIdentity>> {
+        let Some(jwt) = &self.jwt else {
+            return Ok(None);
+        };
+
+        jwt.verify(token).await
+    }
+}
+
+/// Hashes a password with the default argon2 parameters
+pub fn hash_password(password: &str) -> eyre::Result<String> {
+    let salt = SaltString::generate(&mut OsRng);
+
+    let hash = Argon2::default()
+        .hash_password(password.as_bytes(), &salt)
+        .map_err(|err| eyre::eyre!("Failed hashing password: {err}"))?;
+
+    Ok(hash.to_string())
+}
+
+struct JwtVerifier {
+    config: JwtConfig,
+    keys: RwLock<JwksCache>,
+}
+
+struct JwksCache {
+    keys: JwkSet,
+    fetched_at: Instant,
+}
+
+impl JwtVerifier {
+    async fn new(config: JwtConfig) -> eyre::Result<Self> {
+        let keys = load_jwks(&config).await?;
+
+        Ok(Self {
+            config,
+            keys: RwLock::new(JwksCache {
+                keys,
+                fetched_at: Instant::now(),
+            }),
+        })
+    }
+
+    async fn verify(&self, token: &str) -> eyre::Result<Option<AdminIdentity>> {
+        let Ok(header) = jsonwebtoken::decode_header(token) else {
+            return Ok(None);
+        };
+
+        let Some((key, algorithms)) =
+            self.decoding_key(header.kid.as_deref()).await?
+        else {
+            tracing::debug!(kid = ?header.kid, "Unknown JWT signing key");
+            return Ok(None);
+        };
+
+        // The key decides the algorithm, not the token
+        if !algorithms.contains(&header.alg) {
+            tracing::debug!(alg = ?header.alg, "JWT algorithm not allowed");
+            return Ok(None);
+        }
+
+        let mut validation = Validation::new(header.alg);
+        validation.algorithms = algorithms;
+        if let Some(issuer) = &self.config.issuer {
+            validation.set_issuer(&[issuer]);
+        }
+        if let Some(audience) = &self.config.audience {
+            validation.set_audience(&[audience]);
+        }
+
+        let claims = match jsonwebtoken::decode::<HashMap<String, Value>>(
+            token,
+            &key,
+            &validation,
+        ) {
+            Ok(data) => data.claims,
+            Err(err) => {
+                tracing::debug!(error = %err, "Invalid JWT");
+                return Ok(None);
+            }
+        };
+
+        let Some(name) = claims.get("sub").and_then(Value::as_str) else {
+            return Ok(None);
+        };
+
+        let Some(role) =
+            claims.get(&self.config.role_claim).and_then(highest_role)
+        else {
+            tracing::debug!(sub = name, "JWT without an admin role");
+            return Ok(None);
+        };
+
+        Ok(Some(AdminIdentity {
+            name: name.to_string(),
+            role,
+        }))
+    }
+
+    async fn decoding_key(
+        &self,
+        kid: Option<&str>,
+    ) -> eyre::Result<Option<(DecodingKey, Vec<Algorithm>)>> {
+        if let Some(key) = find_key(&self.keys.read().await.keys, kid)? {
+            return Ok(Some(key));
+        }
+
+        // Keys might have been rotated by the identity provider
+        let mut cache = self.keys.write().await;
+        if self.config.jwks_url.is_none()
+            || cache.fetched_at.elapsed() < JWKS_REFRESH_INTERVAL
+        {
+            return Ok(None);
+        }
+
+        cache.keys = load_jwks(&self.config).await?;
+        cache.fetched_at = Instant::now();
+
+        find_key(&cache.keys, kid)
+    }
+}
+
+async fn load_jwks(config: &JwtConfig) -> eyre::Result<JwkSet> {
+    if let Some(path) = &config.jwks_file {
+        let jwks = tokio::fs::read_to_string(path)
+            .await
+            .with_context(|| format!("Reading JWKS from {}", path.display()))?;
+
+        return serde_json::from_str(&jwks).context("Parsing JWKS");
+    }
+
+    let url = config
+        .jwks_url
+        .as_ref()
+        .context("Either jwks_url or jwks_file must be set")?;
+
+    let jwks = reqwest::get(url)
+        .await
+        .and_then(|res| res.error_for_status())
+        .with_context(|| format!("Fetching JWKS from {url}"))?
+        .json()
+        .await
+        .context("Parsing JWKS")?;
+
+    Ok(jwks)
+}
+
+/// Finds the key with the given id and the algorithms it verifies, tokens
+/// without an id are only accepted with a single key in the set
+fn find_key(
+    jwks: &JwkSet,
+    kid: Option<&str>,
+) -> eyre::Result<Option<(DecodingKey, Vec<Algorithm>)>> {
+    let jwk = match kid {
+        Some(kid) => jwks.find(kid),
+        None if jwks.keys.len() == 1 => jwks.keys.first(),
+        None => None,
+    };
+
+    let Some(jwk) = jwk else {
+        return Ok(None);
+    };
+
+    // Shared secrets don't belong in a published key set
+    if matches!(jwk.algorithm, AlgorithmParameters::OctetKey(_)) {
+        return Ok(None);
+    }
+
+    Ok(Some((DecodingKey::from_jwk(jwk)?, key_algorithms(jwk))))
+}
+
+/// The `alg` of the key, or the algorithms of its key type without one
+fn key_algorithms(jwk: &Jwk) -> Vec<Algorithm> {
+    if let Some(algorithm) = jwk.common.algorithm {
+        return vec![algorithm];
+    }
+
+    match &jwk.algorithm {
+        AlgorithmParameters::RSA(_) => vec![
+            Algorithm::RS256,
+            Algorithm::RS384,
+            Algorithm::RS512,
+            Algorithm::PS256,
+            Algorithm::PS384,
+            Algorithm::PS512,
+        ],
+        AlgorithmParameters::EllipticCurve(params) => match params.curve {
+            EllipticCurve::P256 => vec![Algorithm::ES256],
+            EllipticCurve::P384 => vec![Algorithm::ES384],
+            _ => vec![],
+        },
+        AlgorithmParameters::OctetKeyPair(_) => vec![Algorithm::EdDSA],
+        AlgorithmParameters::OctetKey(_) => vec![],
+    }
+}
+
+fn highest_role(claim: &Value) -> Option<AdminRole> {
+    let parse = |value: &Value| {
+        value
+            .as_str()
+            .and_then(|role| serde_json::from_value(role.into()).ok())
+    };
+
+    match claim {
+        Value::Array(roles) => roles.iter().filter_map(parse).max(),
+        value => parse(value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use base64::Engine;
+    use jsonwebtoken::{Algorithm, EncodingKey, Header};
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+    use serde_json::json;
+
+    use super::*;
+
+    const ISSUER: &str = "https://issuer.example.com";
+
+    fn server_config() -> ServerConfig {
+        ServerConfig {
+            host: ([127, 0, 0, 1], 3000).into(),
+            username: None,
+            password: None,
+            admins: vec![],
+            jwt: None,
+            disable_auth: false,
+            server_address: None,
+            path_api_keys: true,
+            audit_log_file: None,
+            rate_limits: None,
+            rpc_proxy: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn requires_credentials_unless_disabled() {
+        let mut config = server_config();
+        assert!(AdminAuthenticator::new(&config).await.is_err());
+
+        config.disable_auth = true;
+        let auth = AdminAuthenticator::new(&config).await.unwrap();
+        assert!(auth.is_disabled());
+    }
+
+    #[tokio::test]
+    async fn verifies_admin_passwords() {
+        let mut config = server_config();
+        config.admins = vec![AdminAccount {
+            username: "viewer".to_string(),
+            password_hash: hash_password("secret").unwrap(),
+            role: AdminRole::ReadOnly,
+        }];
+        config.username = Some("legacy".to_string().into());
+        config.password = Some("legacy-secret".to_string().into());
+
+        let auth = AdminAuthenticator::new(&config).await.unwrap();
+
+        assert_eq!(
+            auth.verify_password("viewer", "secret").await,
+            Some(AdminIdentity {
+                name: "viewer".to_string(),
+                role: AdminRole::ReadOnly,
+            })
+        );
+        assert_eq!(auth.verify_password("viewer", "wrong").await, None);
+        assert_eq!(auth.verify_password("other", "secret").await, None);
+        assert_eq!(
+            auth.verify_password("legacy", "legacy-secret")
+                .await
+                .map(|admin| admin.role),
+            Some(AdminRole::Operator)
+        );
+    }
+
+    #[tokio::test]
+    async fn verifies_tokens_against_jwks() {
+        let pkcs8 =
+            Ed25519KeyPair::generate_pkcs8(&SystemRandom::new()).unwrap();
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref()).unwrap();
+        let encoding_key = EncodingKey::from_ed_der(pkcs8.as_ref());
+
+        let jwks = json!({
+            "keys": [{
+                "kty": "OKP",
+                "crv": "Ed25519",
+                "kid": "test",
+                "x": base64::prelude::BASE64_URL_SAFE_NO_PAD
+                    .encode(key_pair.public_key().as_ref()),
+            }]
+        });
+        let jwks_file = std::env::temp_dir()
+            .join(format!("jwks-{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&jwks_file, jwks.to_string()).unwrap();
+
+        let mut config = server_config();
+        config.jwt = Some(JwtConfig {
+            jwks_url: None,
+            jwks_file: Some(jwks_file.clone()),
+            issuer: Some(ISSUER.to_string()),
+            audience: None,
+            role_claim: "roles".to_string(),
+        });
+
+        let auth = AdminAuthenticator::new(&config).await.unwrap();
+        std::fs::remove_file(jwks_file).unwrap();
+
+        let exp = chrono::Utc::now().timestamp() + 60;
+        let token = |claims: Value| {
+            let header = Header {
+                kid: Some("test".to_string()),
+                ..Header::new(Algorithm::EdDSA)
+            };
+
+            jsonwebtoken::encode(&header, &claims, &encoding_key).unwrap()
+        };
+
+        let valid = token(json!({
+            "sub": "alice", "iss": ISSUER, "exp": exp, "roles": ["operator"]
+        }));
+        assert_eq!(
+            auth.verify_token(&valid).await.unwrap(),
+            Some(AdminIdentity {
+                name: "alice".to_string(),
+                role: AdminRole::Operator,
+            })
+        );
+
+        let invalid = [
+            token(json!({
+                "sub": "alice", "iss": "other", "exp": exp, "roles": "operator"
+            })),
+            token(json!({
+                "sub": "alice", "iss": ISSUER, "exp": exp - 3600, "roles": "operator"
+            })),
+            token(json!({ "sub": "alice", "iss": ISSUER, "exp": exp })),
+            "not-a-token".to_string(),
+        ];
+
+        for token in invalid {
+            assert_eq!(auth.verify_token(&token).await.unwrap(), None);
+        }
+    }
+
+    #[test]
+    fn verifies_hashed_password() {
+        let hash = hash_password("secret").unwrap();
+        let hash = PasswordHash::new(&hash).unwrap();
+
+        assert!(Argon2::default().verify_password(b"secret", &hash).is_ok());
+        assert!(Argon2::default().verify_password(b"wrong", &hash).is_err());
+    }
+
+    #[test]
+    fn algorithms_of_keys() {
+        let jwk = |jwk: Value| serde_json::from_value::<Jwk>(jwk).unwrap();
+
+        let rsa = json!({ "kty": "RSA", "n": "AQAB", "e": "AQAB" });
+        assert!(key_algorithms(&jwk(rsa.clone())).contains(&Algorithm::PS256));
+
+        let mut rsa_with_alg = rsa;
+        rsa_with_alg["alg"] = json!("RS256");
+        assert_eq!(key_algorithms(&jwk(rsa_with_alg)), vec![Algorithm::RS256]);
+
+        let ed25519 = json!({ "kty": "OKP", "crv": "Ed25519", "x": "AA" });
+        assert_eq!(key_algorithms(&jwk(ed25519)), vec![Algorithm::EdDSA]);
+    }
+
+    #[test]
+    fn highest_role_of_claim() {
+        assert_eq!(
+            highest_role(&json!("read_only")),
+            Some(AdminRole::ReadOnly)
+        );
+        assert_eq!(
+            highest_role(&json!(["read_only", "operator", "other"])),
+            Some(AdminRole::Operator)
+        );
+        assert_eq!(highest_role(&json!("other")), None);
+        assert_eq!(highest_role(&json!(42)), None);
+    }
+}
@@ -3,11 +3,20 @@ use ethers::providers::{Http, Provider, Ws};
 use ethers::signers::Signer;
 use eyre::Context;
 
+use crate::admin_auth::AdminAuthenticator;
 use crate::api_key::ApiKey;
+use crate::audit::AuditLog;
 use crate::config::{Config, KeysConfig};
-use crate::db::data::RpcKind;
+use crate::db::data::{ApiKeyInfo, RpcKind};
 use crate::db::Database;
-use crate::keys::{KeysSource, KmsKeys, LocalKeys, UniversalSigner};
+use crate::keys::local_keys::seal_plaintext_keys;
+use crate::keys::{
+    KeysSource, KmsKeys, LocalKeys, RemoteKeys, UniversalSigner, VaultKeys,
+};
+use crate::leader_election::LeaderElection;
+use crate::rate_limit::RateLimiter;
+use crate::rpc_proxy::RpcProxy;
+use crate::shutdown::Shutdown;
 
 pub type AppGenericMiddleware<T> =
     SignerMiddleware<Provider<T>, UniversalSigner>;
@@ -19,20 +28,55 @@ pub struct App {
     pub keys_source: Box<dyn KeysSource>,
 
     pub db: Database,
+
+    pub admin_auth: AdminAuthenticator,
+
+    pub audit_log: AuditLog,
+
+    pub rate_limiter: RateLimiter,
+
+    pub rpc_proxy: RpcProxy,
+
+    pub shutdown: Shutdown,
+
+    pub leader_election: LeaderElection,
 }
 
 impl App {
     pub async fn new(config: Config) -> eyre::Result<Self> {
-        tracing::info!("Initializing keys source");
-        let keys_source = init_keys_source(&config).await?;
+        tracing::info!("Initializing admin authentication");
+        let admin_auth = AdminAuthenticator::new(&config.server).await?;
+
+        let audit_log = AuditLog::new(config.server.audit_log_file.as_deref())?;
+
+        let rate_limiter = RateLimiter::new(config.server.rate_limits.clone())?;
+
+        let rpc_proxy =
+            RpcProxy::new(config.server.rpc_proxy.clone().unwrap_or_default())?;
+
+        let leader_election =
+            LeaderElection::new(config.service.leader_election.clone());
 
         tracing::info!("Initializing db");
         let db = Database::new(&config.database).await?;
 
+        tracing::info!("Initializing keys source");
+        let keys_source = init_keys_source(&config, &db).await?;
+
+        if config.keys.is_local() {
+            seal_plaintext_keys(&db, keys_source.as_ref()).await?;
+        }
+
         Ok(Self {
             config,
             keys_source,
             db,
+            admin_auth,
+            audit_log,
+            rate_limiter,
+            rpc_proxy,
+            shutdown: Shutdown::new(),
+            leader_election,
         })
     }
 
@@ -81,12 +125,13 @@ impl App {
         Ok(middlware)
     }
 
-    pub async fn is_authorized(
+    /// Returns the API key info, None if it is invalid or expired
+    pub async fn api_key_info(
         &self,
         api_token: &ApiKey,
-    ) -> eyre::Result<bool> {
+    ) -> eyre::Result<Option<ApiKeyInfo>> {
         self.db
-            .is_api_key_valid(
+            .use_api_key(
                 api_token.relayer_id(),
                 api_token.api_key_secret_hash(),
             )
@@ -96,13 +141,20 @@ impl App {
 
 async fn init_keys_source(
     config: &Config,
+    db: &Database,
 ) -> eyre::Result<Box<dyn KeysSource>> {
     let keys_source: Box<dyn KeysSource> = match &config.keys {
         KeysConfig::Kms(kms_config) => {
             Box::new(KmsKeys::new(kms_config).await?)
         }
         KeysConfig::Local(local_config) => {
-            Box::new(LocalKeys::new(local_config))
+            Box::new(LocalKeys::new(local_config, db.clone())?)
+        }
+        KeysConfig::Vault(vault_config) => {
+            Box::new(VaultKeys::new(vault_config).await?)
+        }
+        KeysConfig::Remote(remote_config) => {
+            Box::new(RemoteKeys::new(remote_config, db.clone())?)
         }
     };
 
@@ -0,0 +1,177 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::path::Path;
+
+use eyre::Context;
+use serde::Serialize;
+use serde_json::Value;
+use tokio::fs::File;
+use tokio::io::AsyncWriteExt;
+use tokio::sync::Mutex;
+
+use crate::db::DatabaseTx;
+use crate::types::AuditLogEntryResponse;
+
+/// Actor of the changes made by background tasks
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// Admin and key material operations recorded in the audit log
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    CreateRelayer,
+    ImportRelayer,
+    UpdateRelayer,
+    ResetRelayer,
+    RotateRelayerKey,
+    CompleteKeyRotation,
+    DecommissionRelayer,
+    CompleteDecommission,
+    CreateApiKey,
+    RevokeApiKey,
+    CreateRelayerPool,
+    AddRelayerPoolMember,
+    RemoveRelayerPoolMember,
+    CreateNetwork,
+    SignMessage,
+    SignTypedData,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::CreateRelayer => "relayer.create",
+            Self::ImportRelayer => "relayer.import",
+            Self::UpdateRelayer => "relayer.update",
+            Self::ResetRelayer => "relayer.reset",
+            Self::RotateRelayerKey => "relayer.rotate_key",
+            Self::CompleteKeyRotation => "relayer.complete_key_rotation",
+            Self::DecommissionRelayer => "relayer.decommission",
+            Self::CompleteDecommission => "relayer.complete_decommission",
+            Self::CreateApiKey => "api_key.create",
+            Self::RevokeApiKey => "api_key.revoke",
+            Self::CreateRelayerPool => "pool.create",
+            Self::AddRelayerPoolMember => "pool.add_member",
+            Self::RemoveRelayerPoolMember => "pool.remove_member",
+            Self::CreateNetwork => "network.create",
+            Self::SignMessage => "relayer.sign_message",
+            Self::SignTypedData => "relayer.sign_typed_data",
+        }
+    }
+}
+
+impl fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Single audited operation with the state of its target before and after
+///
+/// Secrets like API keys or private keys must never be part of the state.
+#[derive(Debug, Clone)]
+pub struct AuditEvent {
+    action: AuditAction,
+    target: Option<String>,
+    before: Option<Value>,
+    after: Option<Value>,
+}
+
+impl AuditEvent {
+    pub fn new(action: AuditAction, target: impl ToString) -> Self {
+        Self {
+            action,
+            target: Some(target.to_string()),
+            before: None,
+            after: None,
+        }
+    }
+
+    pub fn before(mut self, state: impl Serialize) -> Self {
+        self.before = serde_json::to_value(state).ok();
+        self
+    }
+
+    pub fn after(mut self, state: impl Serialize) -> Self {
+        self.after = serde_json::to_value(state).ok();
+        self
+    }
+}
+
+/// Writes audit entries to the database and to an optional file sink
+pub struct AuditLog {
+    sink: Option<Mutex<File>>,
+}
+
+impl AuditLog {
+    pub fn new(sink_path: Option<&Path>) -> eyre::Result<Self> {
+        let sink = sink_path
+            .map(|path| {
+                OpenOptions::new()
+                    .create(true)
+                    .append(true)
+                    .open(path)
+                    .with_context(|| {
+                        format!("Opening audit log file {}", path.display())
+                    })
+            })
+            .transpose()?
+            .map(|file| Mutex::new(File::from_std(file)));
+
+        Ok(Self { sink })
+    }
+
+    /// Appends the event to the audit log in the transaction of the audited
+    /// change and commits it
+    ///
+    /// The database is the source of truth, failing to write to the file sink
+    /// is only logged.
+    pub async fn record(
+        &self,
+        mut tx: DatabaseTx,
+        actor: &str,
+        request_id: Option<&str>,
+        event: AuditEvent,
+    ) -> eyre::Result<()> {
+        let entry = tx
+            .insert_audit_entry(
+                actor,
+                event.action.as_str(),
+                event.target.as_deref(),
+                event.before.as_ref(),
+                event.after.as_ref(),
+                request_id,
+            )
+            .await?;
+
+        tx.commit().await?;
+
+        tracing::info!(
+            actor,
+            action = %event.action,
+            target = event.target,
+            "Audit log entry recorded"
+        );
+
+        if let Some(sink) = &self.sink {
+            if let Err(err) = write_line(sink, &entry.into()).await {
+                tracing::error!(error = ?err, "Error writing audit log file");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+async fn write_line(
+    sink: &Mutex<File>,
+    entry: &AuditLogEntryResponse,
+) -> eyre::Result<()> {
+    let mut line = serde_json::to_vec(entry)?;
+    line.push(b'\n');
+
+    let mut file = sink.lock().await;
+    file.write_all(&line).await?;
+    file.flush().await?;
+
+    Ok(())
+}
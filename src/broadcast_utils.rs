@@ -1,9 +1,21 @@
-use ethers::types::U256;
+use ethers::providers::Middleware;
+use ethers::types::transaction::eip2718::TypedTransaction;
+use ethers::types::transaction::eip2930::AccessList;
+use ethers::types::{
+    Address, Bytes, Eip1559TransactionRequest, NameOrAddress, H256, U256,
+};
 use eyre::ContextCompat;
+use thiserror::Error;
 
 use self::gas_estimation::FeesEstimate;
 use crate::app::App;
+use crate::budget::{BudgetViolation, Spending};
 use crate::db::data::RelayerInfo;
+use crate::db::UnsentTx;
+use crate::policy::PolicyViolation;
+use crate::types::TransactionPriority;
+
+const TRANSFER_GAS_LIMIT: u64 = 21_000;
 
 pub mod gas_estimation;
 
@@ -24,7 +36,9 @@ pub async fn should_send_relayer_transactions(
     app: &App,
     relayer: &RelayerInfo,
 ) -> eyre::Result<bool> {
-    if !relayer.enabled {
+    // Decommissioned relayers are disabled but still send their queued txs
+    if !relayer.enabled && app.db.get_decommission(&relayer.id).await?.is_none()
+    {
         tracing::warn!(
             relayer_id = relayer.id,
             chain_id = relayer.chain_id,
@@ -56,3 +70,174 @@ pub async fn should_send_relayer_transactions(
 
     Ok(true)
 }
+
+/// Reasons for a relayer to reject a new transaction
+#[derive(Debug, Error)]
+pub enum TxRejection {
+    #[error(transparent)]
+    Policy(#[from] PolicyViolation),
+    #[error("Relayer queue is full")]
+    QueueFull,
+    #[error(transparent)]
+    Budget(#[from] BudgetViolation),
+    #[error(transparent)]
+    Other(#[from] eyre::Report),
+}
+
+/// Checks a new transaction of the relayer against its policy, queue size
+/// and budget
+///
+/// Applies to transactions sent through the API and to top-ups alike.
+pub async fn check_new_tx(
+    app: &App,
+    relayer: &RelayerInfo,
+    to: Option<Address>,
+    data: &[u8],
+    value: U256,
+    gas_limit: U256,
+    gas_price: U256,
+) -> Result<(), TxRejection> {
+    relayer.policy.check_tx(to, data)?;
+
+    let relayer_queued_tx_count =
+        app.db.get_relayer_pending_txs(&relayer.id).await?;
+
+    if relayer_queued_tx_count > relayer.max_queued_txs as usize {
+        return Err(TxRejection::QueueFull);
+    }
+
+    relayer.budget.check_tx_value(value)?;
+
+    if relayer.budget.has_window_limits() {
+        let spending = app
+            .db
+            .get_relayer_spending(&relayer.id, relayer.budget.window(), false)
+            .await?;
+
+        relayer.budget.check_window(
+            Spending::from_txs(&spending, gas_price),
+            Spending::for_tx(value, gas_limit, gas_price),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Returns the unsent txs (in nonce order) that fit within the relayer budget
+///
+/// Txs are held back once the budget for the current window would be
+/// exceeded, they will be sent once the window moves on.
+pub async fn txs_within_budget(
+    app: &App,
+    relayer: &RelayerInfo,
+    txs: Vec<UnsentTx>,
+) -> eyre::Result<Vec<UnsentTx>> {
+    if !relayer.budget.has_window_limits() {
+        return Ok(txs);
+    }
+
+    let fees = app
+        .db
+        .get_latest_block_fees_by_chain_id(relayer.chain_id)
+        .await?
+        .context("Missing block fees")?;
+
+    let sent_txs = app
+        .db
+        .get_relayer_spending(&relayer.id, relayer.budget.window(), true)
+        .await?;
+
+    let mut spent = Spending::from_txs(&sent_txs, fees.gas_price);
+    let mut txs_within_budget = vec![];
+
+    for tx in txs {
+        let tx_spending =
+            Spending::for_tx(tx.value.0, tx.gas_limit.0, fees.gas_price);
+
+        if let Err(violation) = relayer.budget.check_window(spent, tx_spending)
+        {
+            tracing::warn!(
+                relayer_id = relayer.id,
+                tx_id = tx.id,
+                %violation,
+                "Holding relayer transactions until the budget window resets"
+            );
+
+            break;
+        }
+
+        spent = spent + tx_spending;
+        txs_within_budget.push(tx);
+    }
+
+    Ok(txs_within_budget)
+}
+
+/// Signed transfer of the whole balance of a relayer
+pub struct SweepTx {
+    pub tx_hash: H256,
+    pub raw_tx: Bytes,
+    pub value: U256,
+}
+
+/// Signs a transfer of the relayer balance minus gas to `to`
+///
+/// Returns None if the balance does not cover the gas of the transfer
+pub async fn sign_sweep_tx(
+    app: &App,
+    relayer: &RelayerInfo,
+    to: Address,
+) -> eyre::Result<Option<SweepTx>> {
+    let middleware = app
+        .signer_middleware(relayer.chain_id, relayer.key_id.clone())
+        .await?;
+
+    let balance = middleware.get_balance(relayer.address.0, None).await?;
+
+    let fees = app
+        .db
+        .get_latest_block_fees_by_chain_id(relayer.chain_id)
+        .await?
+        .context("Missing block fees")?;
+
+    let (max_fee_per_gas, max_priority_fee_per_gas) =
+        calculate_gas_fees_from_estimates(
+            &fees.fee_estimates,
+            TransactionPriority::Regular.to_percentile_index(),
+            fees.fee_estimates.base_fee_per_gas,
+        );
+
+    let gas_cost = max_fee_per_gas * U256::from(TRANSFER_GAS_LIMIT);
+
+    if balance <= gas_cost {
+        return Ok(None);
+    }
+
+    let nonce = middleware
+        .get_transaction_count(relayer.address.0, None)
+        .await?;
+
+    let value = balance - gas_cost;
+
+    let tx = TypedTransaction::Eip1559(Eip1559TransactionRequest {
+        from: Some(relayer.address.0),
+        to: Some(NameOrAddress::Address(to)),
+        gas: Some(TRANSFER_GAS_LIMIT.into()),
+        value: Some(value),
+        data: None,
+        nonce: Some(nonce),
+        access_list: AccessList::default(),
+        max_priority_fee_per_gas: Some(max_priority_fee_per_gas),
+        max_fee_per_gas: Some(max_fee_per_gas),
+        chain_id: Some(relayer.chain_id.into()),
+    });
+
+    let raw_tx = middleware.signer().raw_signed_tx(&tx).await?;
+    let tx_hash = H256::from(ethers::utils::keccak256(&raw_tx));
+
+    Ok(Some(SweepTx {
+        tx_hash,
+        raw_tx,
+        value,
+    }))
+}
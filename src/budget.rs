@@ -0,0 +1,221 @@
+use std::time::Duration;
+
+use ethers::types::U256;
+use poem::http::StatusCode;
+use thiserror::Error;
+
+use crate::db::data::{RelayerBudget, TxSpending};
+
+/// Total value and gas spend of a set of transactions
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Spending {
+    pub value: U256,
+    pub gas_spend: U256,
+}
+
+impl Spending {
+    /// Estimated spending of a single transaction
+    pub fn for_tx(value: U256, gas_limit: U256, gas_price: U256) -> Self {
+        Self {
+            value,
+            gas_spend: gas_limit.saturating_mul(gas_price),
+        }
+    }
+
+    /// Sums up the spending of the given txs
+    ///
+    /// Mined txs use the gas cost from their receipts, sent txs are estimated
+    /// using their max fee per gas and unsent txs using the provided gas price.
+    pub fn from_txs(txs: &[TxSpending], gas_price: U256) -> Self {
+        txs.iter().fold(Self::default(), |acc, tx| {
+            let gas_spend = match (
+                &tx.gas_used,
+                &tx.effective_gas_price,
+                &tx.max_fee_per_gas,
+            ) {
+                (Some(gas_used), Some(effective_gas_price), _) => {
+                    gas_used.0.saturating_mul(effective_gas_price.0)
+                }
+                (_, _, Some(max_fee_per_gas)) => {
+                    tx.gas_limit.0.saturating_mul(max_fee_per_gas.0)
+                }
+                _ => tx.gas_limit.0.saturating_mul(gas_price),
+            };
+
+            acc + Self {
+                value: tx.value.0,
+                gas_spend,
+            }
+        })
+    }
+}
+
+impl std::ops::Add for Spending {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            value: self.value.saturating_add(rhs.value),
+            gas_spend: self.gas_spend.saturating_add(rhs.gas_spend),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum BudgetViolation {
+    #[error("Transaction value exceeds the relayer max tx value")]
+    TxValue,
+    #[error("Relayer value budget for the current window is exhausted")]
+    WindowValue,
+    #[error("Relayer gas budget for the current window is exhausted")]
+    WindowGasSpend,
+}
+
+impl BudgetViolation {
+    pub fn error_id(&self) -> &'static str {
+        match self {
+            Self::TxValue => "tx_value_limit_exceeded",
+            Self::WindowValue => "value_budget_exceeded",
+            Self::WindowGasSpend => "gas_budget_exceeded",
+        }
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            // Will never succeed with the current budget
+            Self::TxValue => StatusCode::UNPROCESSABLE_ENTITY,
+            // Will succeed once the window moves on
+            Self::WindowValue | Self::WindowGasSpend => {
+                StatusCode::TOO_MANY_REQUESTS
+            }
+        }
+    }
+}
+
+impl RelayerBudget {
+    pub fn window(&self) -> Duration {
+        Duration::from_secs(self.window_seconds)
+    }
+
+    pub fn has_window_limits(&self) -> bool {
+        self.max_window_value.is_some() || self.max_window_gas_spend.is_some()
+    }
+
+    pub fn check_tx_value(&self, value: U256) -> Result<(), BudgetViolation> {
+        match &self.max_tx_value {
+            Some(max_tx_value) if value > max_tx_value.0 => {
+                Err(BudgetViolation::TxValue)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks whether `tx` can be spent on top of what was `spent`
+    /// within the current window
+    pub fn check_window(
+        &self,
+        spent: Spending,
+        tx: Spending,
+    ) -> Result<(), BudgetViolation> {
+        let total = spent + tx;
+
+        if let Some(max_window_value) = &self.max_window_value {
+            if total.value > max_window_value.0 {
+                return Err(BudgetViolation::WindowValue);
+            }
+        }
+
+        if let Some(max_window_gas_spend) = &self.max_window_gas_spend {
+            if total.gas_spend > max_window_gas_spend.0 {
+                return Err(BudgetViolation::WindowGasSpend);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spending_tx(
+        value: u64,
+        gas_limit: u64,
+        max_fee_per_gas: Option<u64>,
+        receipt: Option<(u64, u64)>,
+    ) -> TxSpending {
+        TxSpending {
+            value: U256::from(value).into(),
+            gas_limit: U256::from(gas_limit).into(),
+            max_fee_per_gas: max_fee_per_gas.map(|v| U256::from(v).into()),
+            gas_used: receipt.map(|(gas_used, _)| U256::from(gas_used).into()),
+            effective_gas_price: receipt
+                .map(|(_, price)| U256::from(price).into()),
+        }
+    }
+
+    #[test]
+    fn spending_from_txs() {
+        let txs = vec![
+            // Mined
+            spending_tx(1, 100, Some(10), Some((50, 5))),
+            // Sent
+            spending_tx(2, 100, Some(10), None),
+            // Unsent
+            spending_tx(3, 100, None, None),
+        ];
+
+        let spending = Spending::from_txs(&txs, U256::from(1));
+
+        assert_eq!(spending.value, U256::from(6));
+        assert_eq!(spending.gas_spend, U256::from(250 + 1_000 + 100));
+    }
+
+    #[test]
+    fn check_tx_value() {
+        let budget = RelayerBudget {
+            max_tx_value: Some(U256::from(10).into()),
+            ..Default::default()
+        };
+
+        assert!(budget.check_tx_value(U256::from(10)).is_ok());
+        assert_eq!(
+            budget.check_tx_value(U256::from(11)),
+            Err(BudgetViolation::TxValue)
+        );
+        assert!(RelayerBudget::default().check_tx_value(U256::MAX).is_ok());
+    }
+
+    #[test]
+    fn check_window() {
+        let budget = RelayerBudget {
+            max_window_value: Some(U256::from(100).into()),
+            max_window_gas_spend: Some(U256::from(1_000).into()),
+            ..Default::default()
+        };
+
+        let spent = Spending::for_tx(U256::from(90), U256::from(90), 10.into());
+
+        assert!(budget
+            .check_window(
+                spent,
+                Spending::for_tx(10.into(), 10.into(), 10.into())
+            )
+            .is_ok());
+        assert_eq!(
+            budget.check_window(
+                spent,
+                Spending::for_tx(11.into(), 0.into(), 10.into())
+            ),
+            Err(BudgetViolation::WindowValue)
+        );
+        assert_eq!(
+            budget.check_window(
+                spent,
+                Spending::for_tx(0.into(), 11.into(), 10.into())
+            ),
+            Err(BudgetViolation::WindowGasSpend)
+        );
+    }
+}
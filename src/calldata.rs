@@ -0,0 +1,313 @@
+use ethers::abi::token::{LenientTokenizer, Tokenizer};
+use ethers::abi::{AbiParser, Function, ParamType, Token};
+use ethers::types::I256;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::db::data::{DecodedArg, DecodedCall};
+use crate::types::ContractCall;
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum CallError {
+    #[error("Exactly one of `signature` or `abi` must be provided")]
+    MissingFunction,
+    #[error("Invalid function signature: {0}")]
+    Signature(String),
+    #[error("Invalid ABI fragment: {0}")]
+    Abi(String),
+    #[error("Expected {expected} arguments, got {actual}")]
+    ArgumentCount { expected: usize, actual: usize },
+    #[error("Invalid argument `{path}` of type `{kind}`: {reason}")]
+    Argument {
+        path: String,
+        kind: String,
+        reason: String,
+    },
+}
+
+/// Encodes the call into calldata and its decoded representation
+pub fn encode_call(
+    call: &ContractCall,
+) -> Result<(Vec<u8>, DecodedCall), CallError> {
+    let function = parse_function(call)?;
+
+    if function.inputs.len() != call.args.len() {
+        return Err(CallError::ArgumentCount {
+            expected: function.inputs.len(),
+            actual: call.args.len(),
+        });
+    }
+
+    let tokens = function
+        .inputs
+        .iter()
+        .zip(&call.args)
+        .enumerate()
+        .map(|(idx, (param, value))| {
+            let path = if param.name.is_empty() {
+                idx.to_string()
+            } else {
+                param.name.clone()
+            };
+
+            tokenize(&path, &param.kind, value)
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let data = function.encode_input(&tokens).map_err(|err| {
+        CallError::Signature(format!("Failed to encode call: {err}"))
+    })?;
+
+    let args = function
+        .inputs
+        .iter()
+        .zip(&tokens)
+        .map(|(param, token)| DecodedArg {
+            name: param.name.clone(),
+            kind: param.kind.to_string(),
+            value: token_to_json(token),
+        })
+        .collect();
+
+    let decoded = DecodedCall {
+        function: function.name.clone(),
+        signature: function.signature(),
+        args,
+    };
+
+    Ok((data, decoded))
+}
+
+fn parse_function(call: &ContractCall) -> Result<Function, CallError> {
+    match (&call.signature, &call.abi) {
+        (Some(signature), None) => AbiParser::default()
+            .parse_function(signature)
+            .map_err(|err| CallError::Signature(err.to_string())),
+        (None, Some(abi)) => serde_json::from_value(abi.clone())
+            .map_err(|err| CallError::Abi(err.to_string())),
+        _ => Err(CallError::MissingFunction),
+    }
+}
+
+fn tokenize(
+    path: &str,
+    kind: &ParamType,
+    value: &Value,
+) -> Result<Token, CallError> {
+    let invalid = |reason: String| CallError::Argument {
+        path: path.to_string(),
+        kind: kind.to_string(),
+        reason,
+    };
+
+    let tokenize_all =
+        |inner: &mut dyn Iterator<Item = (&ParamType, &Value)>| {
+            inner
+                .enumerate()
+                .map(|(idx, (kind, value))| {
+                    tokenize(&format!("{path}[{idx}]"), kind, value)
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+    match (kind, value) {
+        (ParamType::Array(inner), Value::Array(values)) => {
+            tokenize_all(&mut values.iter().map(|v| (inner.as_ref(), v)))
+                .map(Token::Array)
+        }
+        (ParamType::FixedArray(inner, len), Value::Array(values)) => {
+            if values.len() != *len {
+                return Err(invalid(format!(
+                    "expected {len} elements, got {}",
+                    values.len()
+                )));
+            }
+
+            tokenize_all(&mut values.iter().map(|v| (inner.as_ref(), v)))
+                .map(Token::FixedArray)
+        }
+        (ParamType::Tuple(kinds), Value::Array(values)) => {
+            if values.len() != kinds.len() {
+                return Err(invalid(format!(
+                    "expected {} tuple fields, got {}",
+                    kinds.len(),
+                    values.len()
+                )));
+            }
+
+            tokenize_all(&mut kinds.iter().zip(values)).map(Token::Tuple)
+        }
+        (
+            ParamType::Array(_)
+            | ParamType::FixedArray(_, _)
+            | ParamType::Tuple(_),
+            _,
+        ) => Err(invalid("expected a JSON array".to_string())),
+        (_, Value::String(s)) => LenientTokenizer::tokenize(kind, s)
+            .map_err(|err| invalid(err.to_string())),
+        (_, Value::Number(n)) => {
+            LenientTokenizer::tokenize(kind, &n.to_string())
+                .map_err(|err| invalid(err.to_string()))
+        }
+        (_, Value::Bool(b)) => LenientTokenizer::tokenize(kind, &b.to_string())
+            .map_err(|err| invalid(err.to_string())),
+        _ => Err(invalid("unsupported JSON value".to_string())),
+    }
+}
+
+fn token_to_json(token: &Token) -> Value {
+    match token {
+        Token::Address(address) => Value::String(format!("{address:?}")),
+        Token::FixedBytes(bytes) | Token::Bytes(bytes) => {
+            Value::String(format!("0x{}", hex::encode(bytes)))
+        }
+        Token::Int(value) => Value::String(I256::from_raw(*value).to_string()),
+        Token::Uint(value) => Value::String(value.to_string()),
+        Token::Bool(value) => Value::Bool(*value),
+        Token::String(value) => Value::String(value.clone()),
+        Token::FixedArray(tokens)
+        | Token::Array(tokens)
+        | Token::Tuple(tokens) => {
+            Value::Array(tokens.iter().map(token_to_json).collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn call(signature: &str, args: Vec<Value>) -> ContractCall {
+        ContractCall {
+            signature: Some(signature.to_string()),
+            abi: None,
+            args,
+        }
+    }
+
+    #[test]
+    fn encode_human_readable() {
+        let (data, decoded) = encode_call(&call(
+            "transfer(address to, uint256 amount)",
+            vec![
+                json!("0x1Ed53d680B8890DAe2a63f673a85fFDE1FD5C7a2"),
+                json!(1000),
+            ],
+        ))
+        .unwrap();
+
+        assert_eq!(&data[..4], &hex_literal::hex!("a9059cbb"));
+        assert_eq!(data.len(), 4 + 2 * 32);
+
+        assert_eq!(decoded.function, "transfer");
+        assert_eq!(decoded.signature, "transfer(address,uint256)");
+        assert_eq!(decoded.args[0].name, "to");
+        assert_eq!(
+            decoded.args[0].value,
+            json!("0x1ed53d680b8890dae2a63f673a85ffde1fd5c7a2")
+        );
+        assert_eq!(decoded.args[1].kind, "uint256");
+        assert_eq!(decoded.args[1].value, json!("1000"));
+    }
+
+    #[test]
+    fn encode_abi_fragment() {
+        let abi = json!({
+            "type": "function",
+            "name": "approve",
+            "inputs": [
+                { "name": "spender", "type": "address" },
+                { "name": "amount", "type": "uint256" }
+            ],
+            "outputs": [{ "name": "", "type": "bool" }],
+            "stateMutability": "nonpayable"
+        });
+
+        let (data, _) = encode_call(&ContractCall {
+            signature: None,
+            abi: Some(abi),
+            args: vec![
+                json!("0x1Ed53d680B8890DAe2a63f673a85fFDE1FD5C7a2"),
+                json!("1"),
+            ],
+        })
+        .unwrap();
+
+        assert_eq!(&data[..4], &hex_literal::hex!("095ea7b3"));
+    }
+
+    #[test]
+    fn encode_nested() {
+        let abi = json!({
+            "type": "function",
+            "name": "fill",
+            "inputs": [{
+                "name": "orders",
+                "type": "tuple[]",
+                "components": [
+                    { "name": "amount", "type": "uint256" },
+                    { "name": "partial", "type": "bool" }
+                ]
+            }],
+            "outputs": []
+        });
+
+        let (_, decoded) = encode_call(&ContractCall {
+            signature: None,
+            abi: Some(abi),
+            args: vec![json!([[1, true], ["2", false]])],
+        })
+        .unwrap();
+
+        assert_eq!(decoded.signature, "fill((uint256,bool)[])");
+        assert_eq!(decoded.args[0].value, json!([["1", true], ["2", false]]));
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        assert_eq!(
+            encode_call(&call("transfer(address,uint256)", vec![])),
+            Err(CallError::ArgumentCount {
+                expected: 2,
+                actual: 0
+            })
+        );
+
+        let err = encode_call(&call(
+            "transfer(address to, uint256 amount)",
+            vec![json!("0x1234"), json!(1)],
+        ))
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            CallError::Argument { ref path, .. } if path == "to"
+        ));
+
+        let err =
+            encode_call(&call("f(uint256[2] values)", vec![json!([1, 2, 3])]))
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            CallError::Argument { ref path, .. } if path == "values"
+        ));
+
+        let err =
+            encode_call(&call("f(uint8[] values)", vec![json!([1, "x"])]))
+                .unwrap_err();
+        assert!(matches!(
+            err,
+            CallError::Argument { ref path, .. } if path == "values[1]"
+        ));
+
+        assert_eq!(
+            encode_call(&ContractCall {
+                signature: None,
+                abi: None,
+                args: vec![],
+            }),
+            Err(CallError::MissingFunction)
+        );
+    }
+}
@@ -4,34 +4,68 @@ use std::time::Duration;
 
 use chrono::{DateTime, Utc};
 use ethers::types::{Address, H256, U256};
+use eyre::ContextCompat;
 use sqlx::migrate::{MigrateDatabase, Migrator};
 use sqlx::types::{BigDecimal, Json};
-use sqlx::{Pool, Postgres, Row};
+use sqlx::{PgExecutor, Pool, Postgres, Row, Transaction};
 use tracing::instrument;
 
 use crate::broadcast_utils::gas_estimation::FeesEstimate;
 use crate::config::DatabaseConfig;
-use crate::db::data::{NetworkInfo, RelayerInfo};
+use crate::db::data::{
+    ApiKeyInfo, AuditLogEntry, NetworkInfo, RelayerBalance,
+    RelayerDecommission, RelayerInfo, RelayerPool,
+};
+use crate::db::wrappers::address::AddressWrapper;
+use crate::db::wrappers::decimal_u256::DecimalU256Wrapper;
 use crate::db::wrappers::h256::H256Wrapper;
 use crate::db::wrappers::hex_u256::HexU256Wrapper;
-use crate::types::{RelayerUpdateRequest, TransactionPriority, TxStatus};
+use crate::types::{
+    ApiKeyScope, RelayerUpdateRequest, TransactionPriority, TxStatus,
+};
 
 pub mod data;
 pub mod wrappers;
 
-use self::data::{BlockFees, NetworkStats, ReadTxData, RpcKind};
+use self::data::{
+    BlockFees, DecodedCall, KeyRotation, NetworkStats, ReadTxData, RpcKind,
+    SignatureKind, TxSpending,
+};
 pub use self::data::{TxForEscalation, UnsentTx};
 
 // Statically link in migration files
 static MIGRATOR: Migrator = sqlx::migrate!("db/migrations");
 
+/// Channel notified with the relayer id whenever the relayer may have
+/// transactions to broadcast, e.g. after a new transaction or a nonce update
+pub const UNSENT_TXS_CHANNEL: &str = "unsent_txs";
+
+/// Resolution of the last use of API keys, keeps requests from writing to the
+/// key on every call
+const API_KEY_LAST_USED_RESOLUTION: Duration = Duration::from_secs(60);
+
+#[derive(Clone)]
 pub struct Database {
     pub pool: Pool<Postgres>,
 }
 
+/// Transaction of changes made through the admin API, committed together
+/// with their audit log entries
+///
+/// Dropping it without committing rolls the changes back.
+pub struct DatabaseTx {
+    tx: Transaction<'static, Postgres>,
+}
+
 pub enum CreateResult {
-    SUCCESS,
+    SUCCESS {
+        nonce: u64,
+    },
     CONFLICT,
+    /// The relayer is rotating its key
+    ROTATING,
+    /// The relayer is being decommissioned
+    DECOMMISSIONING,
 }
 
 impl Database {
@@ -53,6 +87,12 @@ impl Database {
         Ok(Self { pool })
     }
 
+    pub async fn begin(&self) -> eyre::Result<DatabaseTx> {
+        Ok(DatabaseTx {
+            tx: self.pool.begin().await?,
+        })
+    }
+
     #[instrument(skip(self), level = "debug")]
     pub async fn create_relayer(
         &self,
@@ -61,19 +101,28 @@ impl Database {
         chain_id: u64,
         key_id: &str,
         address: Address,
+    ) -> eyre::Result<()> {
+        let mut tx = self.begin().await?;
+        tx.create_relayer(id, name, chain_id, key_id, address)
+            .await?;
+        tx.commit().await
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn update_relayer_key_id(
+        &self,
+        id: &str,
+        key_id: &str,
     ) -> eyre::Result<()> {
         sqlx::query(
             r#"
-            INSERT INTO relayers (id, name, chain_id, key_id, address)
-            VALUES ($1, $2, $3, $4, $5)
-            ON CONFLICT DO NOTHING
-        "#,
+            UPDATE relayers
+            SET    key_id = $2
+            WHERE  id = $1
+            "#,
         )
         .bind(id)
-        .bind(name)
-        .bind(chain_id as i64)
         .bind(key_id)
-        .bind(address.as_bytes())
         .execute(&self.pool)
         .await?;
 
@@ -81,96 +130,216 @@ impl Database {
     }
 
     #[instrument(skip(self), level = "debug")]
-    pub async fn update_relayer(
+    pub async fn get_key_rotation(
         &self,
-        id: &str,
-        update: &RelayerUpdateRequest,
+        relayer_id: &str,
+    ) -> eyre::Result<Option<KeyRotation>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT relayer_id, key_id, address, sweep_tx_hash
+            FROM   relayer_key_rotations
+            WHERE  relayer_id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_key_rotations(&self) -> eyre::Result<Vec<KeyRotation>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT relayer_id, key_id, address, sweep_tx_hash
+            FROM   relayer_key_rotations
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Addresses of all relayers, including deleted ones, of pending key
+    /// rotations and of retired keys, none of them may be assigned to another
+    /// relayer
+    ///
+    /// Only addresses used on `chain_id` are returned if it is given.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_used_addresses(
+        &self,
+        chain_id: Option<u64>,
+    ) -> eyre::Result<Vec<Address>> {
+        let items: Vec<(AddressWrapper,)> = sqlx::query_as(
+            r#"
+            SELECT DISTINCT used.address
+            FROM (
+                SELECT chain_id, address
+                FROM   relayers
+                UNION
+                SELECT r.chain_id, k.address
+                FROM   relayer_key_rotations k
+                JOIN   relayers r ON r.id = k.relayer_id
+                UNION
+                SELECT r.chain_id, k.address
+                FROM   relayer_keys k
+                JOIN   relayers r ON r.id = k.relayer_id
+            ) used
+            WHERE  $1::BIGINT IS NULL OR used.chain_id = $1
+            "#,
+        )
+        .bind(chain_id.map(|chain_id| chain_id as i64))
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(items.into_iter().map(|(address,)| address.0).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn set_key_rotation_sweep_tx(
+        &self,
+        relayer_id: &str,
+        sweep_tx_hash: Option<H256>,
     ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE relayer_key_rotations
+            SET    sweep_tx_hash = $2
+            WHERE  relayer_id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(sweep_tx_hash.as_ref().map(|h| h.as_bytes()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Returns true if none of the relayer's txs are unsent or pending
+    ///
+    /// Waits for txs being created for the relayer to be committed.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn is_relayer_idle(
+        &self,
+        relayer_id: &str,
+    ) -> eyre::Result<bool> {
         let mut tx = self.pool.begin().await?;
 
-        let RelayerUpdateRequest {
-            relayer_name,
-            max_inflight_txs,
-            max_queued_txs,
-            gas_price_limits,
-            enabled,
-        } = update;
+        sqlx::query(
+            r#"
+            SELECT 1
+            FROM   relayers
+            WHERE  id = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(tx.as_mut())
+        .await?;
 
-        if let Some(name) = relayer_name {
-            sqlx::query(
-                r#"
-                UPDATE relayers
-                SET    name = $2
-                WHERE  id = $1
-                "#,
-            )
-            .bind(id)
-            .bind(name)
-            .execute(tx.as_mut())
-            .await?;
-        }
+        let (busy_txs,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT     COUNT(1)
+            FROM       transactions t
+            LEFT JOIN  sent_transactions s ON t.id = s.tx_id
+            WHERE      t.relayer_id = $1
+            AND        (s.tx_id IS NULL OR s.status = $2)
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(TxStatus::Pending)
+        .fetch_one(tx.as_mut())
+        .await?;
 
-        if let Some(max_inflight_txs) = max_inflight_txs {
-            sqlx::query(
-                r#"
-                UPDATE relayers
-                SET    max_inflight_txs = $2
-                WHERE  id = $1
-                "#,
-            )
-            .bind(id)
-            .bind(*max_inflight_txs as i64)
-            .execute(tx.as_mut())
-            .await?;
-        }
+        tx.commit().await?;
 
-        if let Some(max_queued_txs) = max_queued_txs {
-            sqlx::query(
-                r#"
-                UPDATE relayers
-                SET    max_queued_txs = $2
-                WHERE  id = $1
-                "#,
-            )
-            .bind(id)
-            .bind(*max_queued_txs as i64)
-            .execute(tx.as_mut())
-            .await?;
-        }
+        Ok(busy_txs == 0)
+    }
 
-        if let Some(gas_price_limits) = gas_price_limits {
-            sqlx::query(
-                r#"
-                UPDATE relayers
-                SET    gas_price_limits = $2
-                WHERE  id = $1
-                "#,
-            )
-            .bind(id)
-            .bind(Json(gas_price_limits))
-            .execute(tx.as_mut())
-            .await?;
-        }
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_decommission(
+        &self,
+        relayer_id: &str,
+    ) -> eyre::Result<Option<RelayerDecommission>> {
+        fetch_decommission(&self.pool, relayer_id).await
+    }
 
-        if let Some(enabled) = enabled {
-            sqlx::query(
-                r#"
-                UPDATE relayers
-                SET    enabled = $2
-                WHERE  id = $1
-                "#,
-            )
-            .bind(id)
-            .bind(*enabled)
-            .execute(tx.as_mut())
-            .await?;
-        }
+    /// Returns decommissions of relayers which are not deleted yet
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_decommissions(
+        &self,
+    ) -> eyre::Result<Vec<RelayerDecommission>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT d.relayer_id, d.sweep_to, d.sweep_tx_hash, FALSE AS deleted
+            FROM   relayer_decommissions d
+            JOIN   relayers r ON d.relayer_id = r.id
+            WHERE  r.deleted_at IS NULL
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
 
-        tx.commit().await?;
+    #[instrument(skip(self), level = "debug")]
+    pub async fn set_decommission_sweep_tx(
+        &self,
+        relayer_id: &str,
+        sweep_tx_hash: Option<H256>,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE relayer_decommissions
+            SET    sweep_tx_hash = $2
+            WHERE  relayer_id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(sweep_tx_hash.as_ref().map(|h| h.as_bytes()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, sealed_key), level = "debug")]
+    pub async fn insert_local_key(
+        &self,
+        id: &str,
+        sealed_key: &[u8],
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO local_keys (id, sealed_key)
+            VALUES ($1, $2)
+            "#,
+        )
+        .bind(id)
+        .bind(sealed_key)
+        .execute(&self.pool)
+        .await?;
 
         Ok(())
     }
 
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_local_key(
+        &self,
+        id: &str,
+    ) -> eyre::Result<Option<Vec<u8>>> {
+        let sealed_key: Option<(Vec<u8>,)> = sqlx::query_as(
+            r#"
+            SELECT sealed_key
+            FROM   local_keys
+            WHERE  id = $1
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(sealed_key.map(|(sealed_key,)| sealed_key))
+    }
+
     #[instrument(skip(self), level = "debug")]
     pub async fn get_relayers(&self) -> eyre::Result<Vec<RelayerInfo>> {
         Ok(sqlx::query_as(
@@ -186,8 +355,14 @@ impl Database {
                 max_inflight_txs,
                 max_queued_txs,
                 gas_price_limits,
-                enabled
+                enabled,
+                budget,
+                policy,
+                signing_enabled,
+                funding,
+                rate_limits
             FROM relayers
+            WHERE deleted_at IS NULL
             "#,
         )
         .fetch_all(&self.pool)
@@ -212,9 +387,15 @@ impl Database {
                 max_inflight_txs,
                 max_queued_txs,
                 gas_price_limits,
-                enabled
+                enabled,
+                budget,
+                policy,
+                signing_enabled,
+                funding,
+                rate_limits
             FROM relayers
             WHERE chain_id = $1
+            AND deleted_at IS NULL
             "#,
         )
         .bind(chain_id as i64)
@@ -227,27 +408,7 @@ impl Database {
         &self,
         id: &str,
     ) -> eyre::Result<Option<RelayerInfo>> {
-        Ok(sqlx::query_as(
-            r#"
-            SELECT
-                id,
-                name,
-                chain_id,
-                key_id,
-                address,
-                nonce,
-                current_nonce,
-                max_inflight_txs,
-                max_queued_txs,
-                gas_price_limits,
-                enabled
-            FROM relayers
-            WHERE id = $1
-            "#,
-        )
-        .bind(id)
-        .fetch_optional(&self.pool)
-        .await?)
+        fetch_relayer(&self.pool, id).await
     }
 
     #[instrument(skip(self), level = "debug")]
@@ -272,6 +433,34 @@ impl Database {
         Ok(tx_count as usize)
     }
 
+    /// Checks for unsent or pending txs of the relayer sent to the address
+    #[instrument(skip(self), level = "debug")]
+    pub async fn has_unconfirmed_transfer(
+        &self,
+        relayer_id: &str,
+        to: Address,
+    ) -> eyre::Result<bool> {
+        let (exists,): (bool,) = sqlx::query_as(
+            r#"
+            SELECT EXISTS (
+                SELECT    1
+                FROM      transactions t
+                LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
+                WHERE     t.relayer_id = $1
+                AND       t.tx_to = $2
+                AND       (s.tx_id IS NULL OR s.status = $3)
+            )
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(to.as_bytes())
+        .bind(TxStatus::Pending)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(exists)
+    }
+
     #[instrument(skip(self), level = "debug")]
     pub async fn get_relayer_pending_txs_gas_limit_sum(
         &self,
@@ -296,17 +485,49 @@ impl Database {
             .fold(U256::zero(), |acc, (v,)| acc + v.0))
     }
 
+    /// Returns value and gas cost data of relayer txs within the budget window
+    ///
+    /// Txs which have been sent are counted from the moment they were first
+    /// broadcast, unsent txs from the moment they were created.
     #[instrument(skip(self), level = "debug")]
-    pub async fn create_transaction(
+    pub async fn get_relayer_spending(
         &self,
-        tx_id: &str,
-        to: Address,
-        data: &[u8],
+        relayer_id: &str,
+        window: Duration,
+        sent_only: bool,
+    ) -> eyre::Result<Vec<TxSpending>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT    t.value, t.gas_limit, h.max_fee_per_gas, s.gas_used, s.effective_gas_price
+            FROM      transactions t
+            LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
+            LEFT JOIN tx_hashes h ON (s.valid_tx_hash = h.tx_hash)
+            WHERE     t.relayer_id = $1
+            AND       now() - COALESCE(s.created_at, t.created_at) < $2
+            AND       ($3 = false OR s.tx_id IS NOT NULL)
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(window)
+        .bind(sent_only)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn create_transaction(
+        &self,
+        tx_id: &str,
+        to: Option<Address>,
+        data: &[u8],
         value: U256,
         gas_limit: U256,
         priority: TransactionPriority,
         blobs: Option<Vec<Vec<u8>>>,
+        call: Option<&DecodedCall>,
         relayer_id: &str,
+        pool_id: Option<&str>,
+        route_key: Option<&str>,
     ) -> eyre::Result<CreateResult> {
         let mut tx = self.pool.begin().await?;
 
@@ -316,6 +537,29 @@ impl Database {
         let mut gas_limit_bytes = [0u8; 32];
         gas_limit.to_big_endian(&mut gas_limit_bytes);
 
+        // Rotations and decommissions take the same lock before checking
+        // that the relayer is idle, so no tx slips in after that check
+        let (rotating, decommissioning): (bool, bool) = sqlx::query_as(
+            r#"
+            SELECT EXISTS (SELECT 1 FROM relayer_key_rotations WHERE relayer_id = $1),
+                   EXISTS (SELECT 1 FROM relayer_decommissions WHERE relayer_id = $1)
+            FROM   relayers
+            WHERE  id = $1
+            FOR UPDATE
+            "#,
+        )
+        .bind(relayer_id)
+        .fetch_one(tx.as_mut())
+        .await?;
+
+        if rotating {
+            return Ok(CreateResult::ROTATING);
+        }
+
+        if decommissioning {
+            return Ok(CreateResult::DECOMMISSIONING);
+        }
+
         let (nonce,): (i64,) = sqlx::query_as(
             r#"
             UPDATE relayers
@@ -331,12 +575,12 @@ impl Database {
 
         let res = sqlx::query(
             r#"
-            INSERT INTO transactions (id, tx_to, data, value, gas_limit, priority, relayer_id, nonce, blobs)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            INSERT INTO transactions (id, tx_to, data, value, gas_limit, priority, relayer_id, nonce, blobs, call, pool_id, route_key)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
         "#,
         )
         .bind(tx_id)
-        .bind(to.as_bytes())
+        .bind(to.as_ref().map(|to| to.as_bytes()))
         .bind(data)
         .bind(value_bytes)
         .bind(gas_limit_bytes)
@@ -344,6 +588,9 @@ impl Database {
         .bind(relayer_id)
         .bind(nonce)
         .bind(blobs)
+        .bind(call.map(Json))
+        .bind(pool_id)
+        .bind(route_key)
         .execute(tx.as_mut())
         .await;
 
@@ -355,13 +602,32 @@ impl Database {
 
         res?;
 
+        notify_unsent_txs(tx.as_mut(), relayer_id).await?;
+
         tx.commit().await?;
 
-        Ok(CreateResult::SUCCESS)
+        Ok(CreateResult::SUCCESS {
+            nonce: nonce as u64,
+        })
     }
 
     #[instrument(skip(self), level = "debug")]
     pub async fn get_unsent_txs(&self) -> eyre::Result<Vec<UnsentTx>> {
+        self.fetch_unsent_txs(None).await
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_relayer_unsent_txs(
+        &self,
+        relayer_id: &str,
+    ) -> eyre::Result<Vec<UnsentTx>> {
+        self.fetch_unsent_txs(Some(relayer_id)).await
+    }
+
+    async fn fetch_unsent_txs(
+        &self,
+        relayer_id: Option<&str>,
+    ) -> eyre::Result<Vec<UnsentTx>> {
         Ok(sqlx::query_as(
             r#"
             SELECT     r.id as relayer_id, t.id, t.tx_to, t.data, t.value, t.gas_limit, t.priority, t.nonce, t.blobs, r.key_id, r.chain_id
@@ -370,13 +636,18 @@ impl Database {
             INNER JOIN relayers r ON (t.relayer_id = r.id)
             WHERE      s.tx_id IS NULL
             AND        (t.nonce - r.current_nonce < r.max_inflight_txs)
+            AND        ($1::TEXT IS NULL OR r.id = $1)
             ORDER BY   r.id, t.nonce ASC
             "#,
         )
+        .bind(relayer_id)
         .fetch_all(&self.pool)
         .await?)
     }
 
+    /// Records the first broadcast of the tx
+    ///
+    /// Returns how long the tx waited to be broadcast since it was created
     #[instrument(skip(self), level = "debug")]
     pub async fn insert_tx_broadcast(
         &self,
@@ -384,7 +655,7 @@ impl Database {
         tx_hash: H256,
         initial_max_fee_per_gas: U256,
         initial_max_priority_fee_per_gas: U256,
-    ) -> eyre::Result<()> {
+    ) -> eyre::Result<Duration> {
         let mut initial_max_fee_per_gas_bytes = [0u8; 32];
         initial_max_fee_per_gas
             .to_big_endian(&mut initial_max_fee_per_gas_bytes);
@@ -408,21 +679,22 @@ impl Database {
         .execute(tx.as_mut())
         .await?;
 
-        sqlx::query(
+        let (latency,): (f64,) = sqlx::query_as(
             r#"
             INSERT INTO sent_transactions (tx_id, initial_max_fee_per_gas, initial_max_priority_fee_per_gas, valid_tx_hash)
             VALUES ($1, $2, $3, $4)
+            RETURNING EXTRACT(EPOCH FROM created_at - (SELECT created_at FROM transactions WHERE id = $1))::FLOAT8
             "#
         )
         .bind(tx_id)
         .bind(initial_max_fee_per_gas_bytes)
         .bind(initial_max_priority_fee_per_gas_bytes)
         .bind(tx_hash.as_bytes())
-        .execute(tx.as_mut()).await?;
+        .fetch_one(tx.as_mut()).await?;
 
         tx.commit().await?;
 
-        Ok(())
+        Ok(Duration::from_secs_f64(latency.max(0.0)))
     }
 
     #[instrument(skip(self), level = "debug")]
@@ -642,7 +914,10 @@ impl Database {
         sqlx::query(
             r#"
             UPDATE sent_transactions s
-            SET    valid_tx_hash = mined.tx_hash
+            SET    valid_tx_hash = mined.tx_hash,
+                   gas_used = NULL,
+                   effective_gas_price = NULL,
+                   contract_address = NULL
             FROM   transactions t,
                    UNNEST($1::TEXT[], $2::BYTEA[]) AS mined(tx_id, tx_hash)
             WHERE  t.id = mined.tx_id
@@ -701,7 +976,10 @@ impl Database {
                        ORDER BY created_at DESC
                        LIMIT  1
                    ),
-                   mined_at = NULL
+                   mined_at = NULL,
+                   gas_used = NULL,
+                   effective_gas_price = NULL,
+                   contract_address = NULL
             FROM   transactions t, UNNEST($2::TEXT[]) AS reorged(tx_id)
             WHERE  t.id = reorged.tx_id
             AND    t.id = s.tx_id
@@ -754,12 +1032,86 @@ impl Database {
         .fetch_all(&self.pool)
         .await?;
 
+        if !updated_txs.is_empty() {
+            let tx_ids: Vec<&str> =
+                updated_txs.iter().map(|(id, _)| id.as_str()).collect();
+
+            sqlx::query(
+                r#"
+                SELECT pg_notify($1, relayer_id)
+                FROM   (
+                    SELECT DISTINCT relayer_id
+                    FROM   transactions
+                    WHERE  id = ANY($2)
+                ) relayers
+                "#,
+            )
+            .bind(UNSENT_TXS_CHANNEL)
+            .bind(&tx_ids)
+            .execute(&self.pool)
+            .await?;
+        }
+
         Ok(updated_txs
             .into_iter()
             .map(|(id, hash)| (id, hash.0))
             .collect())
     }
 
+    /// Returns the tx ids and valid tx hashes of mined or finalized txs
+    /// for which no receipt has been saved yet
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_mined_txs_without_receipt(
+        &self,
+        chain_id: u64,
+    ) -> eyre::Result<Vec<(String, H256)>> {
+        let items: Vec<(String, H256Wrapper)> = sqlx::query_as(
+            r#"
+            SELECT s.tx_id, s.valid_tx_hash
+            FROM   sent_transactions s
+            JOIN   transactions t ON s.tx_id = t.id
+            JOIN   relayers r ON t.relayer_id = r.id
+            WHERE  s.status IN ($1, $2)
+            AND    s.gas_used IS NULL
+            AND    r.chain_id = $3
+            "#,
+        )
+        .bind(TxStatus::Mined)
+        .bind(TxStatus::Finalized)
+        .bind(chain_id as i64)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(items.into_iter().map(|(id, hash)| (id, hash.0)).collect())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn save_tx_receipt(
+        &self,
+        tx_id: &str,
+        gas_used: U256,
+        effective_gas_price: U256,
+        contract_address: Option<Address>,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            UPDATE sent_transactions
+            SET    gas_used = $2,
+                   effective_gas_price = $3,
+                   contract_address = $4
+            WHERE  tx_id = $1
+            "#,
+        )
+        .bind(tx_id)
+        .bind(HexU256Wrapper(gas_used))
+        .bind(HexU256Wrapper(effective_gas_price))
+        .bind(contract_address.as_ref().map(|a| a.as_bytes()))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     #[instrument(skip(self), level = "debug")]
     pub async fn finalize_txs(
         &self,
@@ -816,7 +1168,19 @@ impl Database {
         Ok(sqlx::query_as(
             r#"
             SELECT r.id as relayer_id, t.id, t.tx_to, t.data, t.value, t.gas_limit, t.nonce,
-                   t.blobs, r.key_id, r.chain_id,
+                   t.blobs, r.chain_id,
+                   -- Txs created before a key rotation belong to the retired key
+                   COALESCE(
+                       (
+                           SELECT   k.key_id
+                           FROM     relayer_keys k
+                           WHERE    k.relayer_id = r.id
+                           AND      k.retired_at > t.created_at
+                           ORDER BY k.retired_at
+                           LIMIT    1
+                       ),
+                       r.key_id
+                   ) AS key_id,
                    s.initial_max_fee_per_gas, s.initial_max_priority_fee_per_gas, s.escalation_count
             FROM   transactions t
             JOIN   sent_transactions s ON t.id = s.tx_id
@@ -902,7 +1266,7 @@ impl Database {
         Ok(sqlx::query_as(
             r#"
             SELECT t.id as tx_id, t.tx_to as to, t.data, t.value, t.gas_limit, t.nonce,
-                   t.blobs, h.tx_hash, s.status
+                   t.blobs, h.tx_hash, s.status, s.contract_address, t.call
             FROM transactions t
             LEFT JOIN sent_transactions s ON t.id = s.tx_id
             LEFT JOIN tx_hashes h ON s.valid_tx_hash = h.tx_hash
@@ -914,6 +1278,7 @@ impl Database {
         .await?)
     }
 
+    /// Reads a tx of a relayer, or of a pool if `relayer_id` is a pool id
     #[instrument(skip(self), level = "debug")]
     pub async fn read_relayer_tx(
         &self,
@@ -923,12 +1288,12 @@ impl Database {
         Ok(sqlx::query_as(
             r#"
             SELECT t.id as tx_id, t.tx_to as to, t.data, t.value, t.gas_limit, t.nonce,
-                   t.blobs, h.tx_hash, s.status
+                   t.blobs, h.tx_hash, s.status, s.contract_address, t.call
             FROM transactions t
             LEFT JOIN sent_transactions s ON t.id = s.tx_id
             LEFT JOIN tx_hashes h ON s.valid_tx_hash = h.tx_hash
             WHERE t.id = $1
-            AND   t.relayer_id = $2
+            AND   (t.relayer_id = $2 OR t.pool_id = $2)
             "#,
         )
         .bind(tx_id)
@@ -937,6 +1302,7 @@ impl Database {
         .await?)
     }
 
+    /// Reads txs of a relayer, or of a pool if `relayer_id` is a pool id
     #[instrument(skip(self), level = "debug")]
     pub async fn read_relayer_txs(
         &self,
@@ -951,11 +1317,11 @@ impl Database {
         Ok(sqlx::query_as(
             r#"
             SELECT t.id as tx_id, t.tx_to as to, t.data, t.value, t.gas_limit, t.nonce,
-                   t. blobs, h.tx_hash, s.status
+                   t. blobs, h.tx_hash, s.status, s.contract_address, t.call
             FROM transactions t
             LEFT JOIN sent_transactions s ON t.id = s.tx_id
             LEFT JOIN tx_hashes h ON s.valid_tx_hash = h.tx_hash
-            WHERE t.relayer_id = $1
+            WHERE (t.relayer_id = $1 OR t.pool_id = $1)
             AND   (($2 = true AND s.status = $3) OR $2 = false)
             "#,
         )
@@ -979,7 +1345,7 @@ impl Database {
         Ok(sqlx::query_as(
             r#"
             SELECT t.id as tx_id, t.tx_to as to, t.data, t.value, t.gas_limit, t.nonce,
-                   t. blobs, h.tx_hash, s.status
+                   t. blobs, h.tx_hash, s.status, s.contract_address, t.call
             FROM transactions t
             LEFT JOIN sent_transactions s ON t.id = s.tx_id
             LEFT JOIN tx_hashes h ON s.valid_tx_hash = h.tx_hash
@@ -1001,16 +1367,22 @@ impl Database {
     ) -> eyre::Result<()> {
         sqlx::query(
             r#"
-            UPDATE relayers
-            SET    current_nonce = $3,
-                   updated_at = now()
-            WHERE  chain_id = $1
-            AND    address = $2
+            WITH updated AS (
+                UPDATE relayers
+                SET    current_nonce = $3,
+                       updated_at = now()
+                WHERE  chain_id = $1
+                AND    address = $2
+                AND    deleted_at IS NULL
+                RETURNING id
+            )
+            SELECT pg_notify($4, id) FROM updated
             "#,
         )
         .bind(chain_id as i64)
         .bind(relayer_address.as_bytes())
         .bind(nonce as i64)
+        .bind(UNSENT_TXS_CHANNEL)
         .execute(&self.pool)
         .await?;
 
@@ -1065,55 +1437,12 @@ impl Database {
         name: &str,
         http_rpc: &str,
         ws_rpc: &str,
+        low_balance: Option<U256>,
     ) -> eyre::Result<()> {
-        let mut tx = self.pool.begin().await?;
-
-        sqlx::query(
-            r#"
-            INSERT INTO networks (chain_id, name)
-            VALUES ($1, $2)
-            ON CONFLICT (chain_id) DO UPDATE
-            SET name = EXCLUDED.name
-            "#,
-        )
-        .bind(chain_id as i64)
-        .bind(name)
-        .execute(tx.as_mut())
-        .await?;
-
-        sqlx::query(
-            r#"
-            INSERT INTO rpcs (chain_id, url, kind)
-            VALUES
-                ($1, $2, $3)
-            ON CONFLICT (chain_id, kind) DO UPDATE
-            SET url = EXCLUDED.url
-            "#,
-        )
-        .bind(chain_id as i64)
-        .bind(http_rpc)
-        .bind(RpcKind::Http)
-        .execute(tx.as_mut())
-        .await?;
-
-        sqlx::query(
-            r#"
-            INSERT INTO rpcs (chain_id, url, kind)
-            VALUES
-                ($1, $2, $3)
-            ON CONFLICT (chain_id, kind) DO UPDATE
-            SET url = EXCLUDED.url
-            "#,
-        )
-        .bind(chain_id as i64)
-        .bind(ws_rpc)
-        .bind(RpcKind::Ws)
-        .execute(tx.as_mut())
-        .await?;
-
-        tx.commit().await?;
-
-        Ok(())
+        let mut tx = self.begin().await?;
+        tx.upsert_network(chain_id, name, http_rpc, ws_rpc, low_balance)
+            .await?;
+        tx.commit().await
     }
 
     #[instrument(skip(self), level = "debug")]
@@ -1156,7 +1485,7 @@ impl Database {
     pub async fn get_networks(&self) -> eyre::Result<Vec<NetworkInfo>> {
         Ok(sqlx::query_as(
             r#"
-            SELECT networks.chain_id, name, http.url as http_rpc, ws.url as ws_rpc
+            SELECT networks.chain_id, name, http.url as http_rpc, ws.url as ws_rpc, low_balance
             FROM   networks
             INNER JOIN rpcs http ON networks.chain_id = http.chain_id AND http.kind = 'http'
             INNER JOIN rpcs ws ON networks.chain_id = ws.chain_id AND ws.kind = 'ws'
@@ -1171,35 +1500,55 @@ impl Database {
         &self,
         chain_id: u64,
     ) -> eyre::Result<Option<NetworkInfo>> {
-        Ok(sqlx::query_as(
+        fetch_network(&self.pool, chain_id).await
+    }
+
+    /// Stores the balance of a relayer, the zero address stands for the
+    /// native token
+    #[instrument(skip(self), level = "debug")]
+    pub async fn upsert_relayer_balance(
+        &self,
+        relayer_id: &str,
+        token: Address,
+        balance: U256,
+    ) -> eyre::Result<()> {
+        sqlx::query(
             r#"
-            SELECT networks.chain_id, name, http.url as http_rpc, ws.url as ws_rpc
-            FROM   networks
-            INNER JOIN rpcs http ON networks.chain_id = http.chain_id AND http.kind = 'http'
-            INNER JOIN rpcs ws ON networks.chain_id = ws.chain_id AND ws.kind = 'ws'
-            WHERE networks.chain_id = $1
+            INSERT INTO relayer_balances (relayer_id, token, balance)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (relayer_id, token) DO UPDATE
+            SET balance = EXCLUDED.balance,
+                updated_at = now()
             "#,
         )
-        .bind(chain_id as i64)
-        .fetch_optional(&self.pool)
-        .await?)
+        .bind(relayer_id)
+        .bind(token.as_bytes())
+        .bind(DecimalU256Wrapper(balance))
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
     }
 
+    /// Removes balances of tokens which are no longer tracked
     #[instrument(skip(self), level = "debug")]
-    pub async fn upsert_api_key(
+    pub async fn retain_relayer_balances(
         &self,
         relayer_id: &str,
-        api_key_hash: [u8; 32],
+        tokens: &[Address],
     ) -> eyre::Result<()> {
+        let tokens: Vec<&[u8]> =
+            tokens.iter().map(|token| token.as_bytes()).collect();
+
         sqlx::query(
             r#"
-            INSERT INTO api_keys (relayer_id, key_hash)
-            VALUES ($1, $2)
-            ON CONFLICT DO NOTHING
+            DELETE FROM relayer_balances
+            WHERE relayer_id = $1
+            AND   NOT (token = ANY($2))
             "#,
         )
         .bind(relayer_id)
-        .bind(api_key_hash)
+        .bind(tokens)
         .execute(&self.pool)
         .await?;
 
@@ -1207,27 +1556,233 @@ impl Database {
     }
 
     #[instrument(skip(self), level = "debug")]
-    pub async fn is_api_key_valid(
+    pub async fn get_relayer_balances(
+        &self,
+        relayer_id: Option<&str>,
+    ) -> eyre::Result<Vec<RelayerBalance>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT   relayer_id, token, balance
+            FROM     relayer_balances
+            WHERE    ($1::TEXT IS NULL OR relayer_id = $1)
+            ORDER BY relayer_id, token
+            "#,
+        )
+        .bind(relayer_id)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Returns the API keys of a relayer or of a relayer pool
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_api_keys(
+        &self,
+        owner_id: &str,
+    ) -> eyre::Result<Vec<ApiKeyInfo>> {
+        fetch_api_keys(&self.pool, owner_id).await
+    }
+
+    /// Checks an API key of a relayer or of a relayer pool, returns the key
+    /// if it is valid
+    ///
+    /// Expired keys are rejected, the last use of valid keys is recorded
+    /// unless it was recorded within the last minute.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn use_api_key(
         &self,
         relayer_id: &str,
         api_key_hash: [u8; 32],
-    ) -> eyre::Result<bool> {
-        let (is_valid,): (bool,) = sqlx::query_as(
+    ) -> eyre::Result<Option<ApiKeyInfo>> {
+        Ok(sqlx::query_as(
             r#"
-            SELECT EXISTS (
-                SELECT 1
-                FROM api_keys
-                WHERE relayer_id = $1
-                AND   key_hash = $2
+            WITH valid_key AS (
+                SELECT id, name, scopes, created_at, last_used_at, expires_at
+                FROM   api_keys
+                WHERE  (relayer_id = $1 OR pool_id = $1)
+                AND    key_hash = $2
+                AND    (expires_at IS NULL OR expires_at > now())
+            ), used AS (
+                UPDATE api_keys
+                SET    last_used_at = now()
+                WHERE  id IN (
+                    SELECT id
+                    FROM   valid_key
+                    WHERE  last_used_at IS NULL
+                    OR     now() - last_used_at > $3
+                )
             )
+            SELECT id, name, scopes, created_at, last_used_at, expires_at
+            FROM   valid_key
             "#,
         )
         .bind(relayer_id)
         .bind(api_key_hash)
-        .fetch_one(&self.pool)
+        .bind(API_KEY_LAST_USED_RESOLUTION)
+        .fetch_optional(&self.pool)
+        .await?)
+    }
+
+    /// Audit log entries matching all given filters, newest first
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_audit_entries(
+        &self,
+        actor: Option<&str>,
+        action: Option<&str>,
+        target: Option<&str>,
+        since: Option<DateTime<Utc>>,
+        until: Option<DateTime<Utc>>,
+        limit: u32,
+    ) -> eyre::Result<Vec<AuditLogEntry>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT   id, created_at, actor, action, target, before, after, request_id
+            FROM     audit_log
+            WHERE    ($1::TEXT IS NULL OR actor = $1)
+            AND      ($2::TEXT IS NULL OR action = $2)
+            AND      ($3::TEXT IS NULL OR target = $3)
+            AND      ($4::TIMESTAMPTZ IS NULL OR created_at >= $4)
+            AND      ($5::TIMESTAMPTZ IS NULL OR created_at < $5)
+            ORDER BY id DESC
+            LIMIT    $6
+            "#,
+        )
+        .bind(actor)
+        .bind(action)
+        .bind(target)
+        .bind(since)
+        .bind(until)
+        .bind(limit as i64)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_relayer_pools(&self) -> eyre::Result<Vec<RelayerPool>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT    p.id, p.name, p.chain_id,
+                      COALESCE(array_agg(m.relayer_id::TEXT ORDER BY m.relayer_id) FILTER (WHERE m.relayer_id IS NOT NULL), '{}') AS relayer_ids
+            FROM      relayer_pools p
+            LEFT JOIN relayer_pool_members m ON (p.id = m.pool_id)
+            GROUP BY  p.id
+            ORDER BY  p.created_at
+            "#,
+        )
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_relayer_pool(
+        &self,
+        id: &str,
+    ) -> eyre::Result<Option<RelayerPool>> {
+        fetch_relayer_pool(&self.pool, id).await
+    }
+
+    /// Returns the pool members ordered by their number of unsent and pending txs
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_pool_relayers_by_queue_depth(
+        &self,
+        pool_id: &str,
+    ) -> eyre::Result<Vec<RelayerInfo>> {
+        Ok(sqlx::query_as(
+            r#"
+            SELECT
+                r.id,
+                r.name,
+                r.chain_id,
+                r.key_id,
+                r.address,
+                r.nonce,
+                r.current_nonce,
+                r.max_inflight_txs,
+                r.max_queued_txs,
+                r.gas_price_limits,
+                r.enabled,
+                r.budget,
+                r.policy,
+                r.signing_enabled,
+                r.funding,
+                r.rate_limits
+            FROM       relayer_pool_members m
+            INNER JOIN relayers r ON (m.relayer_id = r.id)
+            WHERE      m.pool_id = $1
+            ORDER BY (
+                SELECT    COUNT(1)
+                FROM      transactions t
+                LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
+                WHERE     t.relayer_id = r.id
+                AND       (s.tx_id IS NULL OR s.status = $2)
+            ) ASC, r.id
+            "#,
+        )
+        .bind(pool_id)
+        .bind(TxStatus::Pending)
+        .fetch_all(&self.pool)
+        .await?)
+    }
+
+    /// Returns the pool member the route key is pinned to
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_pool_route(
+        &self,
+        pool_id: &str,
+        route_key: &str,
+    ) -> eyre::Result<Option<String>> {
+        let relayer_id: Option<(String,)> = sqlx::query_as(
+            r#"
+            SELECT relayer_id
+            FROM   relayer_pool_routes
+            WHERE  pool_id = $1
+            AND    route_key = $2
+            "#,
+        )
+        .bind(pool_id)
+        .bind(route_key)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        Ok(relayer_id.map(|(relayer_id,)| relayer_id))
+    }
+
+    /// Pins the route key to the pool member, unless it's pinned to another
+    /// member than `replaced` already
+    ///
+    /// Returns the member the route key ends up pinned to.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn set_pool_route(
+        &self,
+        pool_id: &str,
+        route_key: &str,
+        relayer_id: &str,
+        replaced: Option<&str>,
+    ) -> eyre::Result<String> {
+        let routed: Option<(String,)> = sqlx::query_as(
+            r#"
+            INSERT INTO relayer_pool_routes (pool_id, route_key, relayer_id)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (pool_id, route_key) DO UPDATE
+            SET    relayer_id = EXCLUDED.relayer_id,
+                   created_at = now()
+            WHERE  relayer_pool_routes.relayer_id = $4
+            RETURNING relayer_id
+            "#,
+        )
+        .bind(pool_id)
+        .bind(route_key)
+        .bind(relayer_id)
+        .bind(replaced)
+        .fetch_optional(&self.pool)
         .await?;
 
-        Ok(is_valid)
+        match routed {
+            Some((relayer_id,)) => Ok(relayer_id),
+            None => self
+                .get_pool_route(pool_id, route_key)
+                .await?
+                .context("Missing pool route"),
+        }
     }
 
     #[instrument(skip(self), level = "debug")]
@@ -1246,69 +1801,719 @@ impl Database {
         .fetch_one(&self.pool)
         .await?;
 
-        let (mined_txs,): (i64,) = sqlx::query_as(
+        let (mined_txs,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(1)
+            FROM transactions t
+            JOIN relayers r ON (t.relayer_id = r.id)
+            LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
+            WHERE s.status = $1
+            AND   r.chain_id = $2
+            "#,
+        )
+        .bind(TxStatus::Mined)
+        .bind(chain_id as i64)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let (finalized_txs,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(1)
+            FROM transactions t
+            JOIN relayers r ON (t.relayer_id = r.id)
+            LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
+            WHERE s.status = $1
+            AND   r.chain_id = $2
+            "#,
+        )
+        .bind(TxStatus::Finalized)
+        .bind(chain_id as i64)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let (total_indexed_blocks,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(1)
+            FROM blocks
+            WHERE chain_id = $1
+            "#,
+        )
+        .bind(chain_id as i64)
+        .fetch_one(&self.pool)
+        .await?;
+
+        let (block_txs,): (i64,) = sqlx::query_as(
+            r#"
+            SELECT COUNT(1)
+            FROM block_txs
+            WHERE chain_id = $1
+            "#,
+        )
+        .bind(chain_id as i64)
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(NetworkStats {
+            pending_txs: pending_txs as u64,
+            mined_txs: mined_txs as u64,
+            finalized_txs: finalized_txs as u64,
+            total_indexed_blocks: total_indexed_blocks as u64,
+            block_txs: block_txs as u64,
+        })
+    }
+}
+
+impl DatabaseTx {
+    pub async fn commit(self) -> eyre::Result<()> {
+        self.tx.commit().await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn create_relayer(
+        &mut self,
+        id: &str,
+        name: &str,
+        chain_id: u64,
+        key_id: &str,
+        address: Address,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO relayers (id, name, chain_id, key_id, address)
+            VALUES ($1, $2, $3, $4, $5)
+            ON CONFLICT DO NOTHING
+        "#,
+        )
+        .bind(id)
+        .bind(name)
+        .bind(chain_id as i64)
+        .bind(key_id)
+        .bind(address.as_bytes())
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Sets both the next and the current nonce of the relayer
+    #[instrument(skip(self), level = "debug")]
+    pub async fn sync_relayer_nonce(
+        &mut self,
+        id: &str,
+        nonce: u64,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            WITH updated AS (
+                UPDATE relayers
+                SET    nonce = $2,
+                       current_nonce = $2,
+                       updated_at = now()
+                WHERE  id = $1
+                RETURNING id
+            )
+            SELECT pg_notify($3, id) FROM updated
+            "#,
+        )
+        .bind(id)
+        .bind(nonce as i64)
+        .bind(UNSENT_TXS_CHANNEL)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Starts a key rotation, returns false if one is already in progress
+    #[instrument(skip(self), level = "debug")]
+    pub async fn create_key_rotation(
+        &mut self,
+        relayer_id: &str,
+        key_id: &str,
+        address: Address,
+    ) -> eyre::Result<bool> {
+        let res = sqlx::query(
+            r#"
+            INSERT INTO relayer_key_rotations (relayer_id, key_id, address)
+            VALUES ($1, $2, $3)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(key_id)
+        .bind(address.as_bytes())
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Switches the relayer to the key of its rotation
+    ///
+    /// The old key is kept in the key history
+    #[instrument(skip(self), level = "debug")]
+    pub async fn complete_key_rotation(
+        &mut self,
+        relayer_id: &str,
+        nonce: u64,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO relayer_keys (relayer_id, key_id, address, sweep_tx_hash)
+            SELECT r.id, r.key_id, r.address, k.sweep_tx_hash
+            FROM   relayers r
+            JOIN   relayer_key_rotations k ON r.id = k.relayer_id
+            WHERE  r.id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE relayers r
+            SET    key_id = k.key_id,
+                   address = k.address,
+                   nonce = $2,
+                   current_nonce = $2,
+                   updated_at = now()
+            FROM   relayer_key_rotations k
+            WHERE  r.id = k.relayer_id
+            AND    r.id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(nonce as i64)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM relayer_key_rotations
+            WHERE  relayer_id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Starts decommissioning a relayer, returns false if it is already
+    /// being decommissioned
+    ///
+    /// The relayer is disabled and leaves its pools right away.
+    #[instrument(skip(self), level = "debug")]
+    pub async fn create_decommission(
+        &mut self,
+        relayer_id: &str,
+        sweep_to: Address,
+    ) -> eyre::Result<bool> {
+        let res = sqlx::query(
+            r#"
+            INSERT INTO relayer_decommissions (relayer_id, sweep_to)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(relayer_id)
+        .bind(sweep_to.as_bytes())
+        .execute(self.tx.as_mut())
+        .await?;
+
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        sqlx::query(
+            r#"
+            UPDATE relayers
+            SET    enabled = FALSE,
+                   updated_at = now()
+            WHERE  id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        sqlx::query(
+            r#"
+            DELETE FROM relayer_pool_members
+            WHERE  relayer_id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(true)
+    }
+
+    /// Revokes the API keys of the relayer and deletes it
+    ///
+    /// The relayer row and its transactions are kept for history
+    #[instrument(skip(self), level = "debug")]
+    pub async fn complete_decommission(
+        &mut self,
+        relayer_id: &str,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            DELETE FROM api_keys
+            WHERE  relayer_id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        sqlx::query(
+            r#"
+            UPDATE relayers
+            SET    enabled = FALSE,
+                   deleted_at = now(),
+                   updated_at = now()
+            WHERE  id = $1
+            "#,
+        )
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn update_relayer(
+        &mut self,
+        id: &str,
+        update: &RelayerUpdateRequest,
+    ) -> eyre::Result<()> {
+        let RelayerUpdateRequest {
+            relayer_name,
+            max_inflight_txs,
+            max_queued_txs,
+            gas_price_limits,
+            enabled,
+            budget,
+            policy,
+            signing_enabled,
+            funding,
+            rate_limits,
+        } = update;
+
+        if let Some(name) = relayer_name {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    name = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(name)
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(max_inflight_txs) = max_inflight_txs {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    max_inflight_txs = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(*max_inflight_txs as i64)
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(max_queued_txs) = max_queued_txs {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    max_queued_txs = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(*max_queued_txs as i64)
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(gas_price_limits) = gas_price_limits {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    gas_price_limits = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(Json(gas_price_limits))
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(enabled) = enabled {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    enabled = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(*enabled)
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(budget) = budget {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    budget = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(Json(budget))
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(policy) = policy {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    policy = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(Json(policy))
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(signing_enabled) = signing_enabled {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    signing_enabled = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(*signing_enabled)
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(funding) = funding {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    funding = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(Json(funding))
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        if let Some(rate_limits) = rate_limits {
+            sqlx::query(
+                r#"
+                UPDATE relayers
+                SET    rate_limits = $2
+                WHERE  id = $1
+                "#,
+            )
+            .bind(id)
+            .bind(Json(rate_limits))
+            .execute(self.tx.as_mut())
+            .await?;
+        }
+
+        // Enabling the relayer or raising its limits may unblock txs
+        notify_unsent_txs(self.tx.as_mut(), id).await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn upsert_network(
+        &mut self,
+        chain_id: u64,
+        name: &str,
+        http_rpc: &str,
+        ws_rpc: &str,
+        low_balance: Option<U256>,
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO networks (chain_id, name, low_balance)
+            VALUES ($1, $2, $3)
+            ON CONFLICT (chain_id) DO UPDATE
+            SET name = EXCLUDED.name,
+                low_balance = EXCLUDED.low_balance
+            "#,
+        )
+        .bind(chain_id as i64)
+        .bind(name)
+        .bind(low_balance.map(DecimalU256Wrapper))
+        .execute(self.tx.as_mut())
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO rpcs (chain_id, url, kind)
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (chain_id, kind) DO UPDATE
+            SET url = EXCLUDED.url
+            "#,
+        )
+        .bind(chain_id as i64)
+        .bind(http_rpc)
+        .bind(RpcKind::Http)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO rpcs (chain_id, url, kind)
+            VALUES
+                ($1, $2, $3)
+            ON CONFLICT (chain_id, kind) DO UPDATE
+            SET url = EXCLUDED.url
+            "#,
+        )
+        .bind(chain_id as i64)
+        .bind(ws_rpc)
+        .bind(RpcKind::Ws)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn upsert_api_key(
+        &mut self,
+        id: &str,
+        relayer_id: &str,
+        name: Option<&str>,
+        scopes: &[ApiKeyScope],
+        expires_at: Option<DateTime<Utc>>,
+        api_key_hash: [u8; 32],
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO api_keys (id, relayer_id, name, scopes, expires_at, key_hash)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(id)
+        .bind(relayer_id)
+        .bind(name)
+        .bind(Json(scopes))
+        .bind(expires_at)
+        .bind(api_key_hash)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn upsert_pool_api_key(
+        &mut self,
+        id: &str,
+        pool_id: &str,
+        name: Option<&str>,
+        scopes: &[ApiKeyScope],
+        expires_at: Option<DateTime<Utc>>,
+        api_key_hash: [u8; 32],
+    ) -> eyre::Result<()> {
+        sqlx::query(
+            r#"
+            INSERT INTO api_keys (id, pool_id, name, scopes, expires_at, key_hash)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            ON CONFLICT DO NOTHING
+            "#,
+        )
+        .bind(id)
+        .bind(pool_id)
+        .bind(name)
+        .bind(Json(scopes))
+        .bind(expires_at)
+        .bind(api_key_hash)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(())
+    }
+
+    /// Revokes an API key of a relayer or of a relayer pool, returns false if
+    /// the key does not exist
+    #[instrument(skip(self), level = "debug")]
+    pub async fn delete_api_key(
+        &mut self,
+        owner_id: &str,
+        id: &str,
+    ) -> eyre::Result<bool> {
+        let res = sqlx::query(
+            r#"
+            DELETE FROM api_keys
+            WHERE  (relayer_id = $1 OR pool_id = $1)
+            AND    id = $2
+            "#,
+        )
+        .bind(owner_id)
+        .bind(id)
+        .execute(self.tx.as_mut())
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn insert_audit_entry(
+        &mut self,
+        actor: &str,
+        action: &str,
+        target: Option<&str>,
+        before: Option<&serde_json::Value>,
+        after: Option<&serde_json::Value>,
+        request_id: Option<&str>,
+    ) -> eyre::Result<AuditLogEntry> {
+        Ok(sqlx::query_as(
+            r#"
+            INSERT INTO audit_log (actor, action, target, before, after, request_id)
+            VALUES      ($1, $2, $3, $4, $5, $6)
+            RETURNING   id, created_at, actor, action, target, before, after, request_id
+            "#,
+        )
+        .bind(actor)
+        .bind(action)
+        .bind(target)
+        .bind(before)
+        .bind(after)
+        .bind(request_id)
+        .fetch_one(self.tx.as_mut())
+        .await?)
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn create_relayer_pool(
+        &mut self,
+        id: &str,
+        name: &str,
+        chain_id: u64,
+    ) -> eyre::Result<()> {
+        sqlx::query(
             r#"
-            SELECT COUNT(1)
-            FROM transactions t
-            JOIN relayers r ON (t.relayer_id = r.id)
-            LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
-            WHERE s.status = $1
-            AND   r.chain_id = $2
+            INSERT INTO relayer_pools (id, name, chain_id)
+            VALUES ($1, $2, $3)
             "#,
         )
-        .bind(TxStatus::Mined)
+        .bind(id)
+        .bind(name)
         .bind(chain_id as i64)
-        .fetch_one(&self.pool)
+        .execute(self.tx.as_mut())
         .await?;
 
-        let (finalized_txs,): (i64,) = sqlx::query_as(
+        Ok(())
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn add_relayer_pool_member(
+        &mut self,
+        pool_id: &str,
+        relayer_id: &str,
+    ) -> eyre::Result<()> {
+        sqlx::query(
             r#"
-            SELECT COUNT(1)
-            FROM transactions t
-            JOIN relayers r ON (t.relayer_id = r.id)
-            LEFT JOIN sent_transactions s ON (t.id = s.tx_id)
-            WHERE s.status = $1
-            AND   r.chain_id = $2
+            INSERT INTO relayer_pool_members (pool_id, relayer_id)
+            VALUES ($1, $2)
+            ON CONFLICT DO NOTHING
             "#,
         )
-        .bind(TxStatus::Finalized)
-        .bind(chain_id as i64)
-        .fetch_one(&self.pool)
+        .bind(pool_id)
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
         .await?;
 
-        let (total_indexed_blocks,): (i64,) = sqlx::query_as(
+        Ok(())
+    }
+
+    /// Returns false if the relayer is not a member of the pool
+    #[instrument(skip(self), level = "debug")]
+    pub async fn remove_relayer_pool_member(
+        &mut self,
+        pool_id: &str,
+        relayer_id: &str,
+    ) -> eyre::Result<bool> {
+        let res = sqlx::query(
             r#"
-            SELECT COUNT(1)
-            FROM blocks
-            WHERE chain_id = $1
+            DELETE FROM relayer_pool_members
+            WHERE pool_id = $1
+            AND   relayer_id = $2
             "#,
         )
-        .bind(chain_id as i64)
-        .fetch_one(&self.pool)
+        .bind(pool_id)
+        .bind(relayer_id)
+        .execute(self.tx.as_mut())
         .await?;
 
-        let (block_txs,): (i64,) = sqlx::query_as(
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(skip(self, payload), level = "debug")]
+    pub async fn save_signature(
+        &mut self,
+        relayer_id: &str,
+        kind: SignatureKind,
+        payload: &[u8],
+        hash: H256,
+        signature: &[u8],
+    ) -> eyre::Result<()> {
+        sqlx::query(
             r#"
-            SELECT COUNT(1)
-            FROM block_txs
-            WHERE chain_id = $1
+            INSERT INTO signatures (relayer_id, kind, payload, hash, signature)
+            VALUES ($1, $2, $3, $4, $5)
             "#,
         )
-        .bind(chain_id as i64)
-        .fetch_one(&self.pool)
+        .bind(relayer_id)
+        .bind(kind)
+        .bind(payload)
+        .bind(hash.as_bytes())
+        .bind(signature)
+        .execute(self.tx.as_mut())
         .await?;
 
-        Ok(NetworkStats {
-            pending_txs: pending_txs as u64,
-            mined_txs: mined_txs as u64,
-            finalized_txs: finalized_txs as u64,
-            total_indexed_blocks: total_indexed_blocks as u64,
-            block_txs: block_txs as u64,
-        })
+        Ok(())
     }
 
     #[instrument(skip(self), level = "debug")]
-    pub async fn purge_unsent_txs(&self, relayer_id: &str) -> eyre::Result<()> {
+    pub async fn purge_unsent_txs(
+        &mut self,
+        relayer_id: &str,
+    ) -> eyre::Result<()> {
         sqlx::query(
             r#"
             UPDATE relayers
@@ -1317,7 +2522,7 @@ impl Database {
             "#,
         )
         .bind(relayer_id)
-        .execute(&self.pool)
+        .execute(self.tx.as_mut())
         .await?;
 
         sqlx::query(
@@ -1330,11 +2535,170 @@ impl Database {
             "#,
         )
         .bind(relayer_id)
-        .execute(&self.pool)
+        .execute(self.tx.as_mut())
         .await?;
 
         Ok(())
     }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_relayer(
+        &mut self,
+        id: &str,
+    ) -> eyre::Result<Option<RelayerInfo>> {
+        fetch_relayer(self.tx.as_mut(), id).await
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_decommission(
+        &mut self,
+        relayer_id: &str,
+    ) -> eyre::Result<Option<RelayerDecommission>> {
+        fetch_decommission(self.tx.as_mut(), relayer_id).await
+    }
+
+    /// Returns the API keys of a relayer or of a relayer pool
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_api_keys(
+        &mut self,
+        owner_id: &str,
+    ) -> eyre::Result<Vec<ApiKeyInfo>> {
+        fetch_api_keys(self.tx.as_mut(), owner_id).await
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_relayer_pool(
+        &mut self,
+        id: &str,
+    ) -> eyre::Result<Option<RelayerPool>> {
+        fetch_relayer_pool(self.tx.as_mut(), id).await
+    }
+
+    #[instrument(skip(self), level = "debug")]
+    pub async fn get_network(
+        &mut self,
+        chain_id: u64,
+    ) -> eyre::Result<Option<NetworkInfo>> {
+        fetch_network(self.tx.as_mut(), chain_id).await
+    }
+}
+
+async fn fetch_relayer(
+    executor: impl PgExecutor<'_>,
+    id: &str,
+) -> eyre::Result<Option<RelayerInfo>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT
+            id,
+            name,
+            chain_id,
+            key_id,
+            address,
+            nonce,
+            current_nonce,
+            max_inflight_txs,
+            max_queued_txs,
+            gas_price_limits,
+            enabled,
+            budget,
+            policy,
+            signing_enabled,
+            funding,
+            rate_limits
+        FROM relayers
+        WHERE id = $1
+        AND deleted_at IS NULL
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await?)
+}
+
+async fn fetch_decommission(
+    executor: impl PgExecutor<'_>,
+    relayer_id: &str,
+) -> eyre::Result<Option<RelayerDecommission>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT d.relayer_id, d.sweep_to, d.sweep_tx_hash, r.deleted_at IS NOT NULL AS deleted
+        FROM   relayer_decommissions d
+        JOIN   relayers r ON d.relayer_id = r.id
+        WHERE  d.relayer_id = $1
+        "#,
+    )
+    .bind(relayer_id)
+    .fetch_optional(executor)
+    .await?)
+}
+
+async fn fetch_api_keys(
+    executor: impl PgExecutor<'_>,
+    owner_id: &str,
+) -> eyre::Result<Vec<ApiKeyInfo>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT   id, name, scopes, created_at, last_used_at, expires_at
+        FROM     api_keys
+        WHERE    (relayer_id = $1 OR pool_id = $1)
+        ORDER BY created_at
+        "#,
+    )
+    .bind(owner_id)
+    .fetch_all(executor)
+    .await?)
+}
+
+async fn fetch_relayer_pool(
+    executor: impl PgExecutor<'_>,
+    id: &str,
+) -> eyre::Result<Option<RelayerPool>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT    p.id, p.name, p.chain_id,
+                  COALESCE(array_agg(m.relayer_id::TEXT ORDER BY m.relayer_id) FILTER (WHERE m.relayer_id IS NOT NULL), '{}') AS relayer_ids
+        FROM      relayer_pools p
+        LEFT JOIN relayer_pool_members m ON (p.id = m.pool_id)
+        WHERE     p.id = $1
+        GROUP BY  p.id
+        "#,
+    )
+    .bind(id)
+    .fetch_optional(executor)
+    .await?)
+}
+
+async fn fetch_network(
+    executor: impl PgExecutor<'_>,
+    chain_id: u64,
+) -> eyre::Result<Option<NetworkInfo>> {
+    Ok(sqlx::query_as(
+        r#"
+        SELECT networks.chain_id, name, http.url as http_rpc, ws.url as ws_rpc, low_balance
+        FROM   networks
+        INNER JOIN rpcs http ON networks.chain_id = http.chain_id AND http.kind = 'http'
+        INNER JOIN rpcs ws ON networks.chain_id = ws.chain_id AND ws.kind = 'ws'
+        WHERE networks.chain_id = $1
+        "#,
+    )
+    .bind(chain_id as i64)
+    .fetch_optional(executor)
+    .await?)
+}
+
+/// Wakes the broadcaster of the relayer once the transaction commits
+async fn notify_unsent_txs(
+    executor: impl PgExecutor<'_>,
+    relayer_id: &str,
+) -> eyre::Result<()> {
+    sqlx::query("SELECT pg_notify($1, $2)")
+        .bind(UNSENT_TXS_CHANNEL)
+        .bind(relayer_id)
+        .execute(executor)
+        .await?;
+
+    Ok(())
 }
 
 #[cfg(test)]
@@ -1344,8 +2708,16 @@ mod tests {
     use postgres_docker_utils::DockerContainerGuard;
 
     use super::*;
-    use crate::db::data::RelayerGasPriceLimit;
-    use crate::types::{RelayerGasPriceLimitResponse, RelayerUpdateRequest};
+    use crate::db::data::{
+        AllowedTarget, RateLimit, RelayerBudget, RelayerFunding,
+        RelayerGasPriceLimit, RelayerPolicy, RelayerRateLimits, TrackedToken,
+    };
+    use crate::types::{
+        AllowedTargetResponse, RateLimitResponse, RelayerBudgetResponse,
+        RelayerFundingResponse, RelayerGasPriceLimitResponse,
+        RelayerPolicyResponse, RelayerRateLimitsResponse, RelayerUpdateRequest,
+        TrackedTokenResponse,
+    };
 
     async fn setup_db() -> eyre::Result<(Database, DockerContainerGuard)> {
         let db_container = postgres_docker_utils::setup().await?;
@@ -1398,7 +2770,7 @@ mod tests {
         let http_rpc = "http_rpc";
         let ws_rpc = "ws_rpc";
 
-        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc)
+        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc, None)
             .await?;
 
         let relayer_id = uuid();
@@ -1410,7 +2782,9 @@ mod tests {
         db.create_relayer(relayer_id, relayer_name, chain_id, key_id, address)
             .await?;
 
-        db.purge_unsent_txs(relayer_id).await?;
+        let mut tx = db.begin().await?;
+        tx.purge_unsent_txs(relayer_id).await?;
+        tx.commit().await?;
 
         Ok(())
     }
@@ -1457,7 +2831,7 @@ mod tests {
         let http_rpc = "http_rpc";
         let ws_rpc = "ws_rpc";
 
-        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc)
+        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc, None)
             .await?;
 
         let relayer_id = uuid();
@@ -1490,8 +2864,14 @@ mod tests {
         assert_eq!(relayer.current_nonce, 0);
         assert_eq!(relayer.max_inflight_txs, 5);
         assert_eq!(relayer.gas_price_limits, vec![]);
-
-        db.update_relayer(
+        assert_eq!(relayer.budget, RelayerBudget::default());
+        assert_eq!(relayer.policy, RelayerPolicy::default());
+        assert!(!relayer.signing_enabled);
+        assert_eq!(relayer.funding, RelayerFunding::default());
+        assert_eq!(relayer.rate_limits, RelayerRateLimits::default());
+
+        let mut tx = db.begin().await?;
+        tx.update_relayer(
             relayer_id,
             &RelayerUpdateRequest {
                 relayer_name: None,
@@ -1502,9 +2882,40 @@ mod tests {
                     value: U256::from(10_123u64).into(),
                 }]),
                 enabled: None,
+                budget: Some(RelayerBudgetResponse {
+                    max_tx_value: Some(U256::from(1_000u64).into()),
+                    max_window_value: None,
+                    max_window_gas_spend: Some(U256::from(2_000u64).into()),
+                    window_seconds: 60,
+                }),
+                policy: Some(RelayerPolicyResponse {
+                    allowed_targets: Some(vec![AllowedTargetResponse {
+                        address: Address::repeat_byte(0x11).into(),
+                        selectors: Some(vec![vec![1, 2, 3, 4].into()]),
+                    }]),
+                    allow_contract_creation: false,
+                }),
+                signing_enabled: Some(true),
+                funding: Some(RelayerFundingResponse {
+                    low_balance: Some(U256::from(100u64).into()),
+                    target_balance: Some(U256::from(500u64).into()),
+                    treasury_relayer_id: None,
+                    tokens: vec![TrackedTokenResponse {
+                        address: Address::from_low_u64_be(1).into(),
+                        low_balance: Some(U256::from(10u64).into()),
+                    }],
+                }),
+                rate_limits: Some(RelayerRateLimitsResponse {
+                    send: Some(RateLimitResponse {
+                        requests_per_minute: 60,
+                        burst: 10,
+                    }),
+                    ..Default::default()
+                }),
             },
         )
         .await?;
+        tx.commit().await?;
 
         let relayer = db
             .get_relayer(relayer_id)
@@ -1527,6 +2938,48 @@ mod tests {
                 value: U256::from(10_123u64).into(),
             }]
         );
+        assert_eq!(
+            relayer.budget,
+            RelayerBudget {
+                max_tx_value: Some(U256::from(1_000u64).into()),
+                max_window_value: None,
+                max_window_gas_spend: Some(U256::from(2_000u64).into()),
+                window_seconds: 60,
+            }
+        );
+        assert_eq!(
+            relayer.policy,
+            RelayerPolicy {
+                allowed_targets: Some(vec![AllowedTarget {
+                    address: Address::repeat_byte(0x11).into(),
+                    selectors: Some(vec![vec![1, 2, 3, 4].into()]),
+                }]),
+                allow_contract_creation: false,
+            }
+        );
+        assert!(relayer.signing_enabled);
+        assert_eq!(
+            relayer.funding,
+            RelayerFunding {
+                low_balance: Some(U256::from(100u64).into()),
+                target_balance: Some(U256::from(500u64).into()),
+                treasury_relayer_id: None,
+                tokens: vec![TrackedToken {
+                    address: Address::from_low_u64_be(1).into(),
+                    low_balance: Some(U256::from(10u64).into()),
+                }],
+            }
+        );
+        assert_eq!(
+            relayer.rate_limits,
+            RelayerRateLimits {
+                send: Some(RateLimit {
+                    requests_per_minute: 60,
+                    burst: 10,
+                }),
+                ..Default::default()
+            }
+        );
 
         Ok(())
     }
@@ -1540,7 +2993,7 @@ mod tests {
         let http_rpc = "http_rpc";
         let ws_rpc = "ws_rpc";
 
-        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc)
+        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc, None)
             .await?;
 
         let relayer_id = uuid();
@@ -1560,7 +3013,7 @@ mod tests {
         .await?;
 
         let tx_id = "tx_id";
-        let to = Address::from_low_u64_be(1);
+        let to = Some(Address::from_low_u64_be(1));
         let data: &[u8] = &[];
         let value = U256::from(0);
         let gas_limit = U256::from(0);
@@ -1571,20 +3024,23 @@ mod tests {
         assert!(tx.is_none(), "Tx has not been sent yet");
 
         db.create_transaction(
-            tx_id, to, data, value, gas_limit, priority, blobs, relayer_id,
+            tx_id, to, data, value, gas_limit, priority, blobs, None,
+            relayer_id, None, None,
         )
         .await?;
 
         let tx = db.read_tx(tx_id).await?.context("Missing tx")?;
 
         assert_eq!(tx.tx_id, tx_id);
-        assert_eq!(tx.to.0, to);
+        assert_eq!(tx.to.map(|to| to.0), to);
         assert_eq!(tx.data, data);
         assert_eq!(tx.value.0, value);
         assert_eq!(tx.gas_limit.0, gas_limit);
         assert_eq!(tx.nonce, 0);
         assert_eq!(tx.tx_hash, None);
         assert_eq!(tx.blobs, None);
+        assert_eq!(tx.call, None);
+        assert_eq!(tx.contract_address, None);
 
         let unsent_txs = db.read_relayer_txs(relayer_id, None).await?;
         assert_eq!(unsent_txs.len(), 1, "1 unsent tx");
@@ -1710,7 +3166,7 @@ mod tests {
         let http_rpc = "http_rpc";
         let ws_rpc = "ws_rpc";
 
-        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc)
+        db.upsert_network(chain_id, network_name, http_rpc, ws_rpc, None)
             .await?;
 
         let relayer_1_id = uuid();
@@ -1743,7 +3199,7 @@ mod tests {
         .await?;
 
         let tx_id = "tx_id";
-        let to = Address::from_low_u64_be(1);
+        let to = Some(Address::from_low_u64_be(1));
         let data: &[u8] = &[];
         let value = U256::from(0);
         let gas_limit = U256::from(0);
@@ -1758,7 +3214,10 @@ mod tests {
             gas_limit,
             priority,
             blobs,
+            None,
             relayer_1_id,
+            None,
+            None,
         )
         .await?;
 
@@ -1847,6 +3306,237 @@ mod tests {
             .and_utc()
     }
 
+    #[tokio::test]
+    async fn unsent_txs_notifications() -> eyre::Result<()> {
+        let (db, _db_container) = setup_db().await?;
+
+        let mut listener =
+            sqlx::postgres::PgListener::connect_with(&db.pool).await?;
+        listener.listen(UNSENT_TXS_CHANNEL).await?;
+
+        let chain_id = 123;
+        db.upsert_network(chain_id, "network_name", "http_rpc", "ws_rpc", None)
+            .await?;
+
+        let relayer_id = uuid();
+        let relayer_id = relayer_id.as_str();
+        let relayer_address = Address::from_low_u64_be(1);
+
+        db.create_relayer(
+            relayer_id,
+            "relayer_name",
+            chain_id,
+            "key_id",
+            relayer_address,
+        )
+        .await?;
+
+        db.create_transaction(
+            "tx_id",
+            Some(Address::from_low_u64_be(2)),
+            &[],
+            U256::zero(),
+            U256::from(21_000),
+            TransactionPriority::Regular,
+            None,
+            None,
+            relayer_id,
+            None,
+            None,
+        )
+        .await?;
+
+        let notification = listener.recv().await?;
+        assert_eq!(notification.payload(), relayer_id);
+
+        let unsent_txs = db.get_relayer_unsent_txs(relayer_id).await?;
+        assert_eq!(unsent_txs.len(), 1);
+        assert!(db.get_relayer_unsent_txs(&uuid()).await?.is_empty());
+
+        db.update_relayer_nonce(chain_id, relayer_address, 1)
+            .await?;
+
+        let notification = listener.recv().await?;
+        assert_eq!(notification.payload(), relayer_id);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn no_txs_during_key_rotation() -> eyre::Result<()> {
+        let (db, _db_container) = setup_db().await?;
+
+        let chain_id = 123;
+        db.upsert_network(chain_id, "network_name", "http_rpc", "ws_rpc", None)
+            .await?;
+
+        let relayer_id = uuid();
+        let relayer_id = relayer_id.as_str();
+
+        db.create_relayer(
+            relayer_id,
+            "relayer_name",
+            chain_id,
+            "key_id",
+            Address::from_low_u64_be(1),
+        )
+        .await?;
+
+        let create_tx = |tx_id: &'static str| {
+            db.create_transaction(
+                tx_id,
+                Some(Address::from_low_u64_be(2)),
+                &[],
+                U256::zero(),
+                U256::from(21_000),
+                TransactionPriority::Regular,
+                None,
+                None,
+                relayer_id,
+                None,
+                None,
+            )
+        };
+
+        let res = create_tx("tx_1").await?;
+        assert!(matches!(res, CreateResult::SUCCESS { nonce: 0 }));
+        assert!(!db.is_relayer_idle(relayer_id).await?);
+
+        let mut tx = db.begin().await?;
+        tx.create_key_rotation(
+            relayer_id,
+            "new_key_id",
+            Address::from_low_u64_be(3),
+        )
+        .await?;
+        tx.commit().await?;
+
+        let res = create_tx("tx_2").await?;
+        assert!(matches!(res, CreateResult::ROTATING));
+        assert!(db.read_tx("tx_2").await?.is_none());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn used_addresses() -> eyre::Result<()> {
+        let (db, _db_container) = setup_db().await?;
+
+        let chain_id = 123;
+        db.upsert_network(chain_id, "network_name", "http_rpc", "ws_rpc", None)
+            .await?;
+
+        let rotated_id = uuid();
+        let deleted_id = uuid();
+
+        let mut tx = db.begin().await?;
+        tx.create_relayer(
+            &rotated_id,
+            "rotated",
+            chain_id,
+            "key_1",
+            Address::from_low_u64_be(1),
+        )
+        .await?;
+        tx.create_relayer(
+            &deleted_id,
+            "deleted",
+            chain_id,
+            "key_2",
+            Address::from_low_u64_be(2),
+        )
+        .await?;
+        tx.create_key_rotation(
+            &rotated_id,
+            "key_3",
+            Address::from_low_u64_be(3),
+        )
+        .await?;
+        tx.commit().await?;
+
+        let mut used_addresses = db.get_used_addresses(Some(chain_id)).await?;
+        used_addresses.sort();
+        assert_eq!(
+            used_addresses,
+            (1..=3).map(Address::from_low_u64_be).collect::<Vec<_>>()
+        );
+
+        let mut tx = db.begin().await?;
+        tx.complete_key_rotation(&rotated_id, 0).await?;
+        tx.create_key_rotation(
+            &rotated_id,
+            "key_4",
+            Address::from_low_u64_be(4),
+        )
+        .await?;
+        tx.create_decommission(&deleted_id, Address::zero()).await?;
+        tx.complete_decommission(&deleted_id).await?;
+        tx.commit().await?;
+
+        // Retired keys and deleted relayers stay in use
+        let mut used_addresses = db.get_used_addresses(Some(chain_id)).await?;
+        used_addresses.sort();
+        assert_eq!(
+            used_addresses,
+            (1..=4).map(Address::from_low_u64_be).collect::<Vec<_>>()
+        );
+        assert_eq!(db.get_used_addresses(None).await?.len(), 4);
+        assert!(db.get_used_addresses(Some(321)).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn pool_routes() -> eyre::Result<()> {
+        let (db, _db_container) = setup_db().await?;
+
+        let chain_id = 123;
+        db.upsert_network(chain_id, "network_name", "http_rpc", "ws_rpc", None)
+            .await?;
+
+        let pool_id = uuid();
+        let relayer_ids = [uuid(), uuid()];
+
+        let mut tx = db.begin().await?;
+        tx.create_relayer_pool(&pool_id, "pool_name", chain_id)
+            .await?;
+        for (idx, relayer_id) in relayer_ids.iter().enumerate() {
+            tx.create_relayer(
+                relayer_id,
+                "relayer_name",
+                chain_id,
+                "key_id",
+                Address::from_low_u64_be(idx as u64 + 1),
+            )
+            .await?;
+            tx.add_relayer_pool_member(&pool_id, relayer_id).await?;
+        }
+        tx.commit().await?;
+        let [first, second] = &relayer_ids;
+
+        assert!(db.get_pool_route(&pool_id, "key").await?.is_none());
+
+        // The first member to claim the key keeps it
+        let routed = db.set_pool_route(&pool_id, "key", first, None).await?;
+        assert_eq!(&routed, first);
+        let routed = db.set_pool_route(&pool_id, "key", second, None).await?;
+        assert_eq!(&routed, first);
+
+        // Unless it's replaced explicitly
+        let routed = db
+            .set_pool_route(&pool_id, "key", second, Some(first))
+            .await?;
+        assert_eq!(&routed, second);
+
+        // Routes leave with their member
+        let mut tx = db.begin().await?;
+        tx.remove_relayer_pool_member(&pool_id, second).await?;
+        tx.commit().await?;
+        assert!(db.get_pool_route(&pool_id, "key").await?.is_none());
+
+        Ok(())
+    }
+
     fn uuid() -> String {
         uuid::Uuid::new_v4().to_string()
     }
@@ -1,19 +1,22 @@
+use chrono::{DateTime, Utc};
 use ethers::types::U256;
 use serde::{Deserialize, Serialize};
 use sqlx::prelude::FromRow;
+use sqlx::types::Json;
 
 use crate::broadcast_utils::gas_estimation::FeesEstimate;
 use crate::db::wrappers::address::AddressWrapper;
 use crate::db::wrappers::decimal_u256::DecimalU256Wrapper;
 use crate::db::wrappers::h256::H256Wrapper;
+use crate::db::wrappers::hex_bytes::HexBytesWrapper;
 use crate::db::wrappers::hex_u256::HexU256Wrapper;
-use crate::types::{TransactionPriority, TxStatus};
+use crate::types::{ApiKeyScope, TransactionPriority, TxStatus};
 
 #[derive(Debug, Clone, FromRow)]
 pub struct UnsentTx {
     pub relayer_id: String,
     pub id: String,
-    pub tx_to: AddressWrapper,
+    pub tx_to: Option<AddressWrapper>,
     pub data: Vec<u8>,
     pub value: HexU256Wrapper,
     pub gas_limit: HexU256Wrapper,
@@ -30,7 +33,7 @@ pub struct UnsentTx {
 pub struct TxForEscalation {
     pub relayer_id: String,
     pub id: String,
-    pub tx_to: AddressWrapper,
+    pub tx_to: Option<AddressWrapper>,
     pub data: Vec<u8>,
     pub value: HexU256Wrapper,
     pub gas_limit: HexU256Wrapper,
@@ -46,10 +49,10 @@ pub struct TxForEscalation {
     pub escalation_count: usize,
 }
 
-#[derive(Debug, Clone, FromRow, PartialEq, Eq)]
+#[derive(Debug, Clone, FromRow, PartialEq)]
 pub struct ReadTxData {
     pub tx_id: String,
-    pub to: AddressWrapper,
+    pub to: Option<AddressWrapper>,
     pub data: Vec<u8>,
     pub value: HexU256Wrapper,
     pub gas_limit: HexU256Wrapper,
@@ -60,6 +63,26 @@ pub struct ReadTxData {
     // Sent tx data
     pub tx_hash: Option<H256Wrapper>,
     pub status: Option<TxStatus>,
+    pub contract_address: Option<AddressWrapper>,
+    pub call: Option<Json<DecodedCall>>,
+}
+
+/// Function call of a tx submitted through its ABI
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedCall {
+    pub function: String,
+    pub signature: String,
+    pub args: Vec<DecodedArg>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedArg {
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub value: serde_json::Value,
 }
 
 #[derive(Debug, Clone)]
@@ -88,6 +111,17 @@ pub enum RpcKind {
     Ws,
 }
 
+#[derive(
+    Debug, Clone, Serialize, Deserialize, Copy, PartialEq, Eq, sqlx::Type,
+)]
+#[sqlx(rename_all = "camelCase")]
+#[sqlx(type_name = "signature_kind")]
+#[serde(rename_all = "camelCase")]
+pub enum SignatureKind {
+    Message,
+    TypedData,
+}
+
 #[derive(Debug, Default, Clone, FromRow)]
 pub struct NetworkInfo {
     #[sqlx(try_from = "i64")]
@@ -95,6 +129,7 @@ pub struct NetworkInfo {
     pub name: String,
     pub http_rpc: String,
     pub ws_rpc: String,
+    pub low_balance: Option<DecimalU256Wrapper>,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -111,6 +146,167 @@ pub struct RelayerGasPriceLimit {
     pub chain_id: i64,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayerBudget {
+    #[serde(default)]
+    pub max_tx_value: Option<DecimalU256Wrapper>,
+    #[serde(default)]
+    pub max_window_value: Option<DecimalU256Wrapper>,
+    #[serde(default)]
+    pub max_window_gas_spend: Option<DecimalU256Wrapper>,
+    #[serde(default = "default_budget_window_seconds")]
+    pub window_seconds: u64,
+}
+
+impl Default for RelayerBudget {
+    fn default() -> Self {
+        Self {
+            max_tx_value: None,
+            max_window_value: None,
+            max_window_gas_spend: None,
+            window_seconds: default_budget_window_seconds(),
+        }
+    }
+}
+
+fn default_budget_window_seconds() -> u64 {
+    60 * 60 * 24
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayerPolicy {
+    /// If set, transactions can only be sent to these addresses
+    #[serde(default)]
+    pub allowed_targets: Option<Vec<AllowedTarget>>,
+    #[serde(default = "default_true")]
+    pub allow_contract_creation: bool,
+}
+
+impl Default for RelayerPolicy {
+    fn default() -> Self {
+        Self {
+            allowed_targets: None,
+            allow_contract_creation: true,
+        }
+    }
+}
+
+/// Low balance watermark of relayers and networks which have none set
+pub const DEFAULT_LOW_BALANCE: u64 = 1_000_000_000_000_000_000; // 1 ETH
+
+/// Balance thresholds of a relayer
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayerFunding {
+    /// Balance below which the relayer is reported and topped up
+    #[serde(default)]
+    pub low_balance: Option<DecimalU256Wrapper>,
+    /// Balance the treasury tops the relayer up to
+    #[serde(default)]
+    pub target_balance: Option<DecimalU256Wrapper>,
+    /// Relayer on the same chain sending the top-ups
+    #[serde(default)]
+    pub treasury_relayer_id: Option<String>,
+    /// ERC-20 tokens whose balances are tracked
+    #[serde(default)]
+    pub tokens: Vec<TrackedToken>,
+}
+
+impl RelayerFunding {
+    /// Falls back to the low balance of the network, then to 1 ETH
+    pub fn effective_low_balance(
+        &self,
+        network_low_balance: Option<U256>,
+    ) -> U256 {
+        self.low_balance
+            .as_ref()
+            .map(|v| v.0)
+            .or(network_low_balance)
+            .unwrap_or_else(|| U256::from(DEFAULT_LOW_BALANCE))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackedToken {
+    pub address: AddressWrapper,
+    /// Token balance in base units below which the relayer is reported
+    #[serde(default)]
+    pub low_balance: Option<DecimalU256Wrapper>,
+}
+
+/// Limits of the API keys of a relayer per route class, the defaults of
+/// `server.rate_limits` apply to classes without a limit
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RelayerRateLimits {
+    #[serde(default)]
+    pub send: Option<RateLimit>,
+    #[serde(default)]
+    pub read: Option<RateLimit>,
+    #[serde(default)]
+    pub rpc: Option<RateLimit>,
+    #[serde(default)]
+    pub sign: Option<RateLimit>,
+}
+
+impl RelayerRateLimits {
+    pub fn get(&self, scope: ApiKeyScope) -> Option<RateLimit> {
+        match scope {
+            ApiKeyScope::Send => self.send,
+            ApiKeyScope::Read => self.read,
+            ApiKeyScope::Rpc => self.rpc,
+            ApiKeyScope::Sign => self.sign,
+        }
+    }
+}
+
+/// Token bucket holding up to `burst` requests, refilled at
+/// `requests_per_minute`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimit {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+}
+
+/// Last observed balance of a relayer
+#[derive(Debug, Clone, FromRow)]
+pub struct RelayerBalance {
+    pub relayer_id: String,
+    /// The zero address for the native balance
+    pub token: AddressWrapper,
+    pub balance: DecimalU256Wrapper,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AllowedTarget {
+    pub address: AddressWrapper,
+    /// If set, calldata must start with one of these 4-byte selectors
+    #[serde(default)]
+    pub selectors: Option<Vec<HexBytesWrapper>>,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Value and gas cost data of a single transaction
+/// used to compute relayer spending within a budget window
+#[derive(Debug, Clone, FromRow)]
+pub struct TxSpending {
+    pub value: HexU256Wrapper,
+    pub gas_limit: HexU256Wrapper,
+    /// Max fee per gas of the currently valid tx hash, if sent
+    pub max_fee_per_gas: Option<HexU256Wrapper>,
+    /// Receipt data, if mined
+    pub gas_used: Option<HexU256Wrapper>,
+    pub effective_gas_price: Option<HexU256Wrapper>,
+}
+
 #[derive(Debug, Clone, FromRow)]
 pub struct RelayerInfo {
     pub id: String,
@@ -130,4 +326,69 @@ pub struct RelayerInfo {
     #[sqlx(json)]
     pub gas_price_limits: Vec<RelayerGasPriceLimit>,
     pub enabled: bool,
+    #[sqlx(json)]
+    pub budget: RelayerBudget,
+    #[sqlx(json)]
+    pub policy: RelayerPolicy,
+    pub signing_enabled: bool,
+    #[sqlx(json)]
+    pub funding: RelayerFunding,
+    #[sqlx(json)]
+    pub rate_limits: RelayerRateLimits,
+}
+
+/// Key rotation in progress, the relayer switches to the new key once its
+/// old address is idle and the remaining balance was swept
+#[derive(Debug, Clone, FromRow)]
+pub struct KeyRotation {
+    pub relayer_id: String,
+    pub key_id: String,
+    pub address: AddressWrapper,
+    pub sweep_tx_hash: Option<H256Wrapper>,
+}
+
+/// API key of a relayer or of a relayer pool, the key itself is not stored
+#[derive(Debug, Clone, FromRow)]
+pub struct ApiKeyInfo {
+    pub id: String,
+    pub name: Option<String>,
+    #[sqlx(json)]
+    pub scopes: Vec<ApiKeyScope>,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+/// Entry of the append-only audit log
+#[derive(Debug, Clone, FromRow)]
+pub struct AuditLogEntry {
+    pub id: i64,
+    pub created_at: DateTime<Utc>,
+    /// Admin or API key that performed the action
+    pub actor: String,
+    pub action: String,
+    pub target: Option<String>,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub request_id: Option<String>,
+}
+
+/// Relayer being drained, swept and deleted
+#[derive(Debug, Clone, FromRow)]
+pub struct RelayerDecommission {
+    pub relayer_id: String,
+    /// Address receiving the remaining balance
+    pub sweep_to: AddressWrapper,
+    pub sweep_tx_hash: Option<H256Wrapper>,
+    pub deleted: bool,
+}
+
+/// Relayers on the same chain sharing API keys
+#[derive(Debug, Clone, FromRow)]
+pub struct RelayerPool {
+    pub id: String,
+    pub name: String,
+    #[sqlx(try_from = "i64")]
+    pub chain_id: u64,
+    pub relayer_ids: Vec<String>,
 }
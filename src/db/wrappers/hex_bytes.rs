@@ -2,7 +2,7 @@ use base_api_types::HexBytes;
 use ethers::types::Bytes;
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct HexBytesWrapper(pub Bytes);
 
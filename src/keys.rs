@@ -1,10 +1,16 @@
+use ethers::core::k256::ecdsa::SigningKey;
+
 pub mod kms_keys;
 pub mod local_keys;
+pub mod remote_keys;
 pub mod universal_signer;
+pub mod vault_keys;
 
 pub use kms_keys::KmsKeys;
 pub use local_keys::LocalKeys;
+pub use remote_keys::RemoteKeys;
 pub use universal_signer::UniversalSigner;
+pub use vault_keys::VaultKeys;
 
 #[async_trait::async_trait]
 pub trait KeysSource: Send + Sync + 'static {
@@ -14,6 +20,12 @@ pub trait KeysSource: Send + Sync + 'static {
         meta_name: &str,
     ) -> eyre::Result<(String, UniversalSigner)>;
 
+    /// Stores an existing private key, returns a key id and signer
+    async fn import_private_key(
+        &self,
+        signing_key: SigningKey,
+    ) -> eyre::Result<(String, UniversalSigner)>;
+
     /// Loads the key using the provided id
     async fn load_signer(&self, id: String) -> eyre::Result<UniversalSigner>;
 }
@@ -1,5 +1,6 @@
 use aws_config::BehaviorVersion;
 use aws_sdk_kms::types::{KeySpec, KeyUsageType, Tag};
+use ethers::core::k256::ecdsa::SigningKey;
 use ethers::signers::Signer;
 use eyre::{Context, ContextCompat};
 
@@ -86,6 +87,13 @@ impl KeysSource for KmsKeys {
         Ok((key_id, UniversalSigner::Aws(signer)))
     }
 
+    async fn import_private_key(
+        &self,
+        _signing_key: SigningKey,
+    ) -> eyre::Result<(String, UniversalSigner)> {
+        eyre::bail!("Importing private keys is not supported with KMS keys")
+    }
+
     async fn load_signer(&self, id: String) -> eyre::Result<UniversalSigner> {
         let signer = AwsSigner::new(
             self.kms_client.clone(),
@@ -1,21 +1,54 @@
+use std::io::Write;
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
 use ethers::core::k256::ecdsa::SigningKey;
-use ethers::signers::Wallet;
+use ethers::signers::{Signer, Wallet};
+use eyre::{Context, ContextCompat};
 
 use super::universal_signer::UniversalSigner;
 use super::KeysSource;
 use crate::config::LocalKeysConfig;
+use crate::db::Database;
+
+const MASTER_KEY_LENGTH: usize = 32;
+const NONCE_LENGTH: usize = 12;
 
+/// Private keys generated and stored by tx-sitter
+///
+/// Keys are sealed with the configured master key and stored in the database,
+/// the key id is an opaque reference to the sealed key.
 pub struct LocalKeys {
     rng: rand::rngs::OsRng,
+    master_key: MasterKey,
+    db: Database,
 }
 
 impl LocalKeys {
-    pub fn new(_config: &LocalKeysConfig) -> Self {
+    pub fn new(config: &LocalKeysConfig, db: Database) -> eyre::Result<Self> {
         tracing::info!("Initializing local keys source");
 
-        Self {
+        let master_key = MasterKey::from_config(config)?;
+
+        Ok(Self {
             rng: rand::rngs::OsRng,
-        }
+            master_key,
+            db,
+        })
+    }
+
+    async fn store_key(
+        &self,
+        signing_key: &SigningKey,
+    ) -> eyre::Result<String> {
+        let key_id = uuid::Uuid::new_v4().to_string();
+
+        let sealed_key =
+            self.master_key.seal(&signing_key.to_bytes(), &key_id)?;
+
+        self.db.insert_local_key(&key_id, &sealed_key).await?;
+
+        Ok(key_id)
     }
 }
 
@@ -27,8 +60,14 @@ impl KeysSource for LocalKeys {
     ) -> eyre::Result<(String, UniversalSigner)> {
         let signing_key = SigningKey::random(&mut self.rng.clone());
 
-        let key_id = signing_key.to_bytes().to_vec();
-        let key_id = hex::encode(key_id);
+        self.import_private_key(signing_key).await
+    }
+
+    async fn import_private_key(
+        &self,
+        signing_key: SigningKey,
+    ) -> eyre::Result<(String, UniversalSigner)> {
+        let key_id = self.store_key(&signing_key).await?;
 
         let signer = Wallet::from(signing_key);
 
@@ -36,7 +75,14 @@ impl KeysSource for LocalKeys {
     }
 
     async fn load_signer(&self, id: String) -> eyre::Result<UniversalSigner> {
-        let signing_key = signing_key_from_hex(&id)?;
+        let sealed_key = self
+            .db
+            .get_local_key(&id)
+            .await?
+            .with_context(|| format!("Missing local key {id}"))?;
+
+        let key = self.master_key.open(&sealed_key, &id)?;
+        let signing_key = SigningKey::from_slice(&key)?;
 
         let signer = Wallet::from(signing_key);
 
@@ -44,6 +90,37 @@ impl KeysSource for LocalKeys {
     }
 }
 
+/// Seals plaintext private keys of existing relayers
+///
+/// Before keys were encrypted at rest the hex encoded private key
+/// was used as the key id.
+pub async fn seal_plaintext_keys(
+    db: &Database,
+    keys_source: &dyn KeysSource,
+) -> eyre::Result<()> {
+    for relayer in db.get_relayers().await? {
+        let Ok(signing_key) = signing_key_from_hex(&relayer.key_id) else {
+            continue;
+        };
+
+        let (key_id, signer) =
+            keys_source.import_private_key(signing_key).await?;
+
+        if signer.address() != relayer.address.0 {
+            eyre::bail!(
+                "Key of relayer {} does not match its address",
+                relayer.id
+            );
+        }
+
+        db.update_relayer_key_id(&relayer.id, &key_id).await?;
+
+        tracing::info!(relayer_id = relayer.id, "Sealed plaintext relayer key");
+    }
+
+    Ok(())
+}
+
 pub fn signing_key_from_hex(s: &str) -> eyre::Result<SigningKey> {
     let key_id = hex::decode(s)?;
     let signing_key = SigningKey::from_slice(key_id.as_slice())?;
@@ -51,24 +128,224 @@ pub fn signing_key_from_hex(s: &str) -> eyre::Result<SigningKey> {
     Ok(signing_key)
 }
 
+/// Decrypts an encrypted JSON keystore
+///
+/// The key derivation of keystores is expensive on purpose, it runs on a
+/// blocking thread.
+pub async fn signing_key_from_keystore(
+    keystore: serde_json::Value,
+    password: String,
+) -> eyre::Result<SigningKey> {
+    tokio::task::spawn_blocking(move || {
+        // The keystore decryption only supports reading from a file, the
+        // temporary file is only readable by the current user
+        let mut file = tempfile::NamedTempFile::new()?;
+        serde_json::to_writer(&mut file, &keystore)?;
+        file.flush()?;
+
+        let wallet =
+            Wallet::<SigningKey>::decrypt_keystore(file.path(), password)?;
+
+        Ok(wallet.signer().clone())
+    })
+    .await?
+}
+
+struct MasterKey(Aes256Gcm);
+
+impl MasterKey {
+    fn from_config(config: &LocalKeysConfig) -> eyre::Result<Self> {
+        let master_key = match (&config.master_key, &config.master_key_file) {
+            (Some(master_key), None) => master_key.expose().to_string(),
+            (None, Some(path)) => {
+                std::fs::read_to_string(path).with_context(|| {
+                    format!("Reading master key file {}", path.display())
+                })?
+            }
+            (Some(_), Some(_)) => eyre::bail!(
+                "Only one of `master_key` or `master_key_file` can be set"
+            ),
+            (None, None) => eyre::bail!(
+                "Local keys require a `master_key` or `master_key_file`"
+            ),
+        };
+
+        let master_key = master_key.trim();
+        let master_key =
+            hex::decode(master_key.strip_prefix("0x").unwrap_or(master_key))
+                .context("Master key must be hex encoded")?;
+
+        if master_key.len() != MASTER_KEY_LENGTH {
+            eyre::bail!("Master key must be {MASTER_KEY_LENGTH} bytes long");
+        }
+
+        let cipher = Aes256Gcm::new_from_slice(&master_key)?;
+
+        Ok(Self(cipher))
+    }
+
+    /// Encrypts the key, binding it to its key id
+    fn seal(&self, key: &[u8], key_id: &str) -> eyre::Result<Vec<u8>> {
+        let nonce = Aes256Gcm::generate_nonce(&mut rand::rngs::OsRng);
+
+        let ciphertext = self
+            .0
+            .encrypt(
+                &nonce,
+                Payload {
+                    msg: key,
+                    aad: key_id.as_bytes(),
+                },
+            )
+            .map_err(|_| eyre::eyre!("Failed to seal key"))?;
+
+        Ok([&nonce[..], &ciphertext].concat())
+    }
+
+    fn open(&self, sealed_key: &[u8], key_id: &str) -> eyre::Result<Vec<u8>> {
+        if sealed_key.len() < NONCE_LENGTH {
+            eyre::bail!("Sealed key {key_id} is malformed");
+        }
+
+        let (nonce, ciphertext) = sealed_key.split_at(NONCE_LENGTH);
+        let nonce: [u8; NONCE_LENGTH] = nonce.try_into()?;
+
+        self.0
+            .decrypt(
+                &Nonce::from(nonce),
+                Payload {
+                    msg: ciphertext,
+                    aad: key_id.as_bytes(),
+                },
+            )
+            .map_err(|_| eyre::eyre!("Failed to open sealed key {key_id}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use ethers::signers::Signer;
+    use std::time::Duration;
+
+    use postgres_docker_utils::DockerContainerGuard;
 
     use super::*;
+    use crate::config::DatabaseConfig;
+    use crate::types::secret_string::SecretString;
+
+    const MASTER_KEY: &str =
+        "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    fn config() -> LocalKeysConfig {
+        LocalKeysConfig {
+            master_key: Some(SecretString::new(MASTER_KEY.to_string())),
+            master_key_file: None,
+        }
+    }
+
+    async fn setup_db() -> eyre::Result<(Database, DockerContainerGuard)> {
+        let db_container = postgres_docker_utils::setup().await?;
+        let url = format!(
+            "postgres://postgres:postgres@{}/database",
+            db_container.address()
+        );
+
+        for _ in 0..5 {
+            match Database::new(&DatabaseConfig::connection_string(&url)).await
+            {
+                Ok(db) => return Ok((db, db_container)),
+                Err(_) => tokio::time::sleep(Duration::from_secs(1)).await,
+            }
+        }
+
+        Err(eyre::eyre!("Failed to connect to the database"))
+    }
 
     #[tokio::test]
     async fn local_roundtrip() -> eyre::Result<()> {
-        let keys_source = LocalKeys::new(&LocalKeysConfig::default());
+        let (db, _db_container) = setup_db().await?;
+        let keys_source = LocalKeys::new(&config(), db.clone())?;
 
         let (id, signer) = keys_source.new_signer("meta name").await?;
 
         let address = signer.address();
 
-        let signer = keys_source.load_signer(id).await?;
+        let signer = keys_source.load_signer(id.clone()).await?;
 
         assert_eq!(address, signer.address());
 
+        let sealed_key = db.get_local_key(&id).await?.context("Missing key")?;
+        let plaintext_key = signer_key(&signer).to_bytes();
+        assert!(!sealed_key
+            .windows(plaintext_key.len())
+            .any(|w| w == &plaintext_key[..]));
+
+        Ok(())
+    }
+
+    fn signer_key(signer: &UniversalSigner) -> SigningKey {
+        let UniversalSigner::Local(wallet) = signer else {
+            panic!("Expected a local signer");
+        };
+
+        wallet.signer().clone()
+    }
+
+    #[test]
+    fn seal_and_open() -> eyre::Result<()> {
+        let master_key = MasterKey::from_config(&config())?;
+
+        let sealed = master_key.seal(b"secret", "key_id")?;
+        assert_eq!(master_key.open(&sealed, "key_id")?, b"secret");
+
+        // Sealed keys are bound to their key id
+        assert!(master_key.open(&sealed, "other_key_id").is_err());
+
+        let other_master_key = MasterKey::from_config(&LocalKeysConfig {
+            master_key: Some(SecretString::new(format!(
+                "0x{}",
+                "ff".repeat(32)
+            ))),
+            master_key_file: None,
+        })?;
+        assert!(other_master_key.open(&sealed, "key_id").is_err());
+
         Ok(())
     }
+
+    #[tokio::test]
+    async fn decrypt_keystore() -> eyre::Result<()> {
+        let dir = std::env::temp_dir();
+        let (wallet, name) = Wallet::<SigningKey>::new_keystore(
+            &dir,
+            &mut rand::rngs::OsRng,
+            "password",
+            None,
+        )?;
+
+        let path = dir.join(name);
+        let keystore: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&path)?)?;
+        std::fs::remove_file(&path)?;
+
+        let signing_key =
+            signing_key_from_keystore(keystore.clone(), "password".to_string())
+                .await?;
+        assert_eq!(Wallet::from(signing_key).address(), wallet.address());
+
+        assert!(signing_key_from_keystore(keystore, "wrong".to_string())
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_master_key() {
+        assert!(MasterKey::from_config(&LocalKeysConfig::default()).is_err());
+        assert!(MasterKey::from_config(&LocalKeysConfig {
+            master_key: Some(SecretString::new("abcd".to_string())),
+            master_key_file: None,
+        })
+        .is_err());
+    }
 }
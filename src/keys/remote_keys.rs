@@ -0,0 +1,130 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use ethers::core::k256::ecdsa::SigningKey;
+use ethers::providers::{Authorization, Http, Middleware, Provider};
+use ethers::types::Address;
+use eyre::Context;
+
+use super::{KeysSource, UniversalSigner};
+use crate::config::RemoteKeysConfig;
+use crate::db::Database;
+use crate::remote::ethers_signer::RemoteSigner;
+
+/// How long listed accounts are trusted before loading a signer lists them
+/// again
+const ACCOUNTS_TTL: Duration = Duration::from_secs(60);
+
+/// Keys held by a remote Web3Signer compatible signer
+///
+/// Keys must be provisioned in the remote signer, new relayers are assigned
+/// the first listed address not yet used by another relayer. The key id is
+/// the address of the key.
+pub struct RemoteKeys {
+    provider: Arc<Provider<Http>>,
+    db: Database,
+    /// Last listed accounts and when they were listed
+    accounts: Mutex<Option<(Instant, Vec<Address>)>>,
+}
+
+impl RemoteKeys {
+    pub fn new(config: &RemoteKeysConfig, db: Database) -> eyre::Result<Self> {
+        tracing::info!(url = config.url, "Initializing remote keys source");
+
+        let url: url::Url =
+            config.url.parse().context("Invalid remote signer url")?;
+
+        let http = match &config.auth_token {
+            Some(token) => {
+                Http::new_with_auth(url, Authorization::bearer(token.expose()))?
+            }
+            None => Http::new(url),
+        };
+
+        Ok(Self {
+            provider: Arc::new(Provider::new(http)),
+            db,
+            accounts: Mutex::default(),
+        })
+    }
+
+    /// Lists the addresses of keys held by the remote signer
+    pub async fn accounts(&self) -> eyre::Result<Vec<Address>> {
+        let accounts = self
+            .provider
+            .get_accounts()
+            .await
+            .context("Listing remote signer accounts")?;
+
+        *self.accounts.lock().unwrap() =
+            Some((Instant::now(), accounts.clone()));
+
+        Ok(accounts)
+    }
+
+    /// Checks that the remote signer holds a key for the address
+    ///
+    /// Recently listed accounts are trusted, unknown addresses are looked up
+    /// again in case their key was provisioned since.
+    async fn has_account(&self, address: Address) -> eyre::Result<bool> {
+        let cached = match &*self.accounts.lock().unwrap() {
+            Some((listed_at, accounts))
+                if listed_at.elapsed() < ACCOUNTS_TTL =>
+            {
+                accounts.contains(&address)
+            }
+            _ => false,
+        };
+
+        Ok(cached || self.accounts().await?.contains(&address))
+    }
+}
+
+#[async_trait::async_trait]
+impl KeysSource for RemoteKeys {
+    async fn new_signer(
+        &self,
+        meta_name: &str,
+    ) -> eyre::Result<(String, UniversalSigner)> {
+        // Addresses of deleted relayers and retired keys are never reused,
+        // their nonces may already be taken on chain
+        let used_addresses = self.db.get_used_addresses(None).await?;
+
+        let address = self
+            .accounts()
+            .await?
+            .into_iter()
+            .find(|address| !used_addresses.contains(address))
+            .ok_or_else(|| eyre::eyre!("No unused keys in remote signer"))?;
+
+        tracing::info!(?address, meta_name, "Assigned remote key");
+
+        let signer = RemoteSigner::new(self.provider.clone(), address, 1);
+
+        Ok((format!("{address:?}"), UniversalSigner::Remote(signer)))
+    }
+
+    async fn import_private_key(
+        &self,
+        _signing_key: SigningKey,
+    ) -> eyre::Result<(String, UniversalSigner)> {
+        eyre::bail!("Importing private keys is not supported with remote keys")
+    }
+
+    async fn load_signer(&self, id: String) -> eyre::Result<UniversalSigner> {
+        let address: Address =
+            id.parse().context("Remote key id must be an address")?;
+
+        if !self.has_account(address).await? {
+            eyre::bail!("Remote signer has no key for {address:?}");
+        }
+
+        let signer = RemoteSigner::new(
+            self.provider.clone(),
+            address,
+            1, // The chain id is set by the caller
+        );
+
+        Ok(UniversalSigner::Remote(signer))
+    }
+}
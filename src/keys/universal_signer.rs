@@ -1,17 +1,21 @@
 use ethers::core::k256::ecdsa::SigningKey;
 use ethers::core::types::transaction::eip2718::TypedTransaction;
-use ethers::core::types::transaction::eip712::Eip712;
+use ethers::core::types::transaction::eip712::{Eip712, TypedData};
 use ethers::core::types::{Address, Signature as EthSig};
 use ethers::signers::{Signer, Wallet, WalletError};
 use ethers::types::Bytes;
 use thiserror::Error;
 
 use crate::aws::ethers_signer::AwsSigner;
+use crate::remote::ethers_signer::{RemoteSigner, RemoteSignerError};
+use crate::vault::ethers_signer::{VaultSigner, VaultSignerError};
 
 #[derive(Debug)]
 pub enum UniversalSigner {
     Aws(AwsSigner),
     Local(Wallet<SigningKey>),
+    Vault(VaultSigner),
+    Remote(RemoteSigner),
 }
 
 impl UniversalSigner {
@@ -22,10 +26,26 @@ impl UniversalSigner {
         let signature = match self {
             Self::Aws(signer) => signer.sign_transaction(tx).await?,
             Self::Local(signer) => signer.sign_transaction(tx).await?,
+            Self::Vault(signer) => signer.sign_transaction(tx).await?,
+            Self::Remote(signer) => signer.sign_transaction(tx).await?,
         };
 
         Ok(tx.rlp_signed(&signature))
     }
+
+    /// Signs JSON typed data, unlike `sign_typed_data` this is supported by
+    /// remote signers
+    pub async fn sign_typed_data_json(
+        &self,
+        typed_data: &TypedData,
+    ) -> Result<EthSig, UniversalError> {
+        match self {
+            Self::Remote(signer) => {
+                Ok(signer.sign_typed_data_json(typed_data).await?)
+            }
+            _ => self.sign_typed_data(typed_data).await,
+        }
+    }
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -35,6 +55,10 @@ pub enum UniversalError {
     Aws(<AwsSigner as Signer>::Error),
     #[error("Local Signer Error: {0}")]
     Local(#[from] WalletError),
+    #[error("Vault Signer Error: {0}")]
+    Vault(#[from] VaultSignerError),
+    #[error("Remote Signer Error: {0}")]
+    Remote(#[from] RemoteSignerError),
 }
 
 impl From<<AwsSigner as Signer>::Error> for UniversalError {
@@ -54,6 +78,8 @@ impl Signer for UniversalSigner {
         Ok(match self {
             Self::Aws(signer) => signer.sign_message(message).await?,
             Self::Local(signer) => signer.sign_message(message).await?,
+            Self::Vault(signer) => signer.sign_message(message).await?,
+            Self::Remote(signer) => signer.sign_message(message).await?,
         })
     }
 
@@ -64,6 +90,8 @@ impl Signer for UniversalSigner {
         Ok(match self {
             Self::Aws(signer) => signer.sign_transaction(tx).await?,
             Self::Local(signer) => signer.sign_transaction(tx).await?,
+            Self::Vault(signer) => signer.sign_transaction(tx).await?,
+            Self::Remote(signer) => signer.sign_transaction(tx).await?,
         })
     }
 
@@ -74,6 +102,8 @@ impl Signer for UniversalSigner {
         Ok(match self {
             Self::Aws(signer) => signer.sign_typed_data(payload).await?,
             Self::Local(signer) => signer.sign_typed_data(payload).await?,
+            Self::Vault(signer) => signer.sign_typed_data(payload).await?,
+            Self::Remote(signer) => signer.sign_typed_data(payload).await?,
         })
     }
 
@@ -81,6 +111,8 @@ impl Signer for UniversalSigner {
         match self {
             Self::Aws(signer) => signer.address(),
             Self::Local(signer) => signer.address(),
+            Self::Vault(signer) => signer.address(),
+            Self::Remote(signer) => signer.address(),
         }
     }
 
@@ -89,6 +121,8 @@ impl Signer for UniversalSigner {
         match self {
             Self::Aws(signer) => signer.chain_id(),
             Self::Local(signer) => signer.chain_id(),
+            Self::Vault(signer) => signer.chain_id(),
+            Self::Remote(signer) => signer.chain_id(),
         }
     }
 
@@ -97,6 +131,10 @@ impl Signer for UniversalSigner {
         match self {
             Self::Aws(signer) => Self::Aws(signer.with_chain_id(chain_id)),
             Self::Local(signer) => Self::Local(signer.with_chain_id(chain_id)),
+            Self::Vault(signer) => Self::Vault(signer.with_chain_id(chain_id)),
+            Self::Remote(signer) => {
+                Self::Remote(signer.with_chain_id(chain_id))
+            }
         }
     }
 }
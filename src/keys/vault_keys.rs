@@ -0,0 +1,358 @@
+use std::sync::Arc;
+
+use ethers::core::k256::ecdsa::SigningKey;
+
+use super::{KeysSource, UniversalSigner};
+use crate::config::VaultKeysConfig;
+use crate::vault::client::VaultClient;
+use crate::vault::ethers_signer::VaultSigner;
+
+/// Keys created and held by the transit engine of a HashiCorp Vault
+///
+/// The key id is the name of the key within the transit mount.
+pub struct VaultKeys {
+    client: Arc<VaultClient>,
+}
+
+impl VaultKeys {
+    pub async fn new(config: &VaultKeysConfig) -> eyre::Result<Self> {
+        tracing::info!(address = config.address, "Initializing Vault client");
+
+        let client = VaultClient::new(config).await?;
+
+        Ok(Self {
+            client: Arc::new(client),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl KeysSource for VaultKeys {
+    async fn new_signer(
+        &self,
+        meta_name: &str,
+    ) -> eyre::Result<(String, UniversalSigner)> {
+        let key_id = format!("tx-sitter-{}", uuid::Uuid::new_v4());
+
+        self.client.create_key(&key_id).await?;
+
+        tracing::info!(key_id, meta_name, "Created Vault key");
+
+        let signer = self.load_signer(key_id.clone()).await?;
+
+        Ok((key_id, signer))
+    }
+
+    async fn import_private_key(
+        &self,
+        _signing_key: SigningKey,
+    ) -> eyre::Result<(String, UniversalSigner)> {
+        eyre::bail!("Importing private keys is not supported with Vault keys")
+    }
+
+    async fn load_signer(&self, id: String) -> eyre::Result<UniversalSigner> {
+        let signer = VaultSigner::new(
+            self.client.clone(),
+            &id,
+            1, // The chain id is set by the caller
+        )
+        .await?;
+
+        Ok(UniversalSigner::Vault(signer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use base64::Engine;
+    use ethers::signers::Signer;
+    use ethers::types::{Eip1559TransactionRequest, H160};
+    use poem::http::StatusCode;
+    use poem::listener::{Acceptor, Listener, TcpListener};
+    use poem::web::{Data, Json, Path};
+    use poem::{handler, EndpointExt, Request, Response, Route};
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::config::VaultAuthConfig;
+    use crate::types::secret_string::SecretString;
+
+    const ROOT_TOKEN: &str = "root-token";
+    const ROLE_ID: &str = "role-id";
+    const SECRET_ID: &str = "secret-id";
+
+    /// DER prefix of a secp256k1 SubjectPublicKeyInfo with an uncompressed key
+    const SPKI_PREFIX: [u8; 23] =
+        hex_literal::hex!("3056301006072a8648ce3d020106052b8104000a034200");
+
+    #[derive(Default)]
+    struct MockVault {
+        keys: Mutex<HashMap<String, SigningKey>>,
+        tokens: Mutex<Vec<String>>,
+        logins: Mutex<usize>,
+    }
+
+    impl MockVault {
+        fn authorize(&self, req: &Request) -> Result<(), Response> {
+            let token = req
+                .headers()
+                .get("X-Vault-Token")
+                .and_then(|token| token.to_str().ok())
+                .unwrap_or_default();
+
+            if token == ROOT_TOKEN
+                || self.tokens.lock().unwrap().iter().any(|t| t == token)
+            {
+                return Ok(());
+            }
+
+            Err(error(StatusCode::FORBIDDEN, "permission denied"))
+        }
+    }
+
+    fn error(status: StatusCode, message: &str) -> Response {
+        Response::builder()
+            .status(status)
+            .content_type("application/json")
+            .body(json!({ "errors": [message] }).to_string())
+    }
+
+    fn data(data: Value) -> Response {
+        Response::builder()
+            .content_type("application/json")
+            .body(json!({ "data": data }).to_string())
+    }
+
+    #[handler]
+    fn login(
+        Json(body): Json<Value>,
+        Data(vault): Data<&Arc<MockVault>>,
+    ) -> Response {
+        if body["role_id"] != ROLE_ID || body["secret_id"] != SECRET_ID {
+            return error(StatusCode::BAD_REQUEST, "invalid role or secret ID");
+        }
+
+        *vault.logins.lock().unwrap() += 1;
+        let token = uuid::Uuid::new_v4().to_string();
+        vault.tokens.lock().unwrap().push(token.clone());
+
+        Response::builder()
+            .content_type("application/json")
+            .body(json!({ "auth": { "client_token": token } }).to_string())
+    }
+
+    #[handler]
+    fn create_key(
+        req: &Request,
+        Path(name): Path<String>,
+        Json(body): Json<Value>,
+        Data(vault): Data<&Arc<MockVault>>,
+    ) -> Response {
+        if let Err(res) = vault.authorize(req) {
+            return res;
+        }
+
+        if body["type"] != "ecdsa-secp256k1" {
+            return error(StatusCode::BAD_REQUEST, "unsupported key type");
+        }
+
+        let signing_key = SigningKey::random(&mut rand::rngs::OsRng);
+        vault.keys.lock().unwrap().insert(name, signing_key);
+
+        Response::builder().status(StatusCode::NO_CONTENT).finish()
+    }
+
+    #[handler]
+    fn read_key(
+        req: &Request,
+        Path(name): Path<String>,
+        Data(vault): Data<&Arc<MockVault>>,
+    ) -> Response {
+        if let Err(res) = vault.authorize(req) {
+            return res;
+        }
+
+        let keys = vault.keys.lock().unwrap();
+        let Some(signing_key) = keys.get(&name) else {
+            return error(StatusCode::NOT_FOUND, "key not found");
+        };
+
+        let public_key = signing_key
+            .verifying_key()
+            .to_encoded_point(false)
+            .to_bytes();
+        let der = [&SPKI_PREFIX[..], &public_key].concat();
+        let pem = format!(
+            "-----BEGIN PUBLIC KEY-----\n{}\n-----END PUBLIC KEY-----\n",
+            base64::prelude::BASE64_STANDARD.encode(der)
+        );
+
+        data(json!({
+            "name": name,
+            "latest_version": 1,
+            "keys": { "1": { "public_key": pem } },
+        }))
+    }
+
+    #[handler]
+    fn sign(
+        req: &Request,
+        Path(name): Path<String>,
+        Json(body): Json<Value>,
+        Data(vault): Data<&Arc<MockVault>>,
+    ) -> Response {
+        if let Err(res) = vault.authorize(req) {
+            return res;
+        }
+
+        let keys = vault.keys.lock().unwrap();
+        let Some(signing_key) = keys.get(&name) else {
+            return error(StatusCode::NOT_FOUND, "key not found");
+        };
+
+        let digest = base64::prelude::BASE64_STANDARD
+            .decode(body["input"].as_str().unwrap_or_default())
+            .unwrap();
+        let (signature, _) =
+            signing_key.sign_prehash_recoverable(&digest).unwrap();
+
+        data(json!({
+            "signature": format!(
+                "vault:v1:{}",
+                base64::prelude::BASE64_STANDARD.encode(signature.to_der())
+            ),
+            "key_version": 1,
+        }))
+    }
+
+    async fn spawn_mock_vault(vault: Arc<MockVault>) -> eyre::Result<String> {
+        let router = Route::new()
+            .at("/v1/auth/approle/login", poem::post(login))
+            .at(
+                "/v1/transit/keys/:name",
+                poem::post(create_key).get(read_key),
+            )
+            .at("/v1/transit/sign/:name", poem::post(sign))
+            .data(vault);
+
+        let acceptor = TcpListener::bind("127.0.0.1:0").into_acceptor().await?;
+        let addr = acceptor.local_addr()[0]
+            .as_socket_addr()
+            .cloned()
+            .ok_or_else(|| eyre::eyre!("Missing socket address"))?;
+
+        tokio::spawn(poem::Server::new_with_acceptor(acceptor).run(router));
+
+        Ok(format!("http://{addr}"))
+    }
+
+    fn config(address: String, auth: VaultAuthConfig) -> VaultKeysConfig {
+        VaultKeysConfig {
+            address,
+            mount: "transit".to_string(),
+            namespace: None,
+            key_type: "ecdsa-secp256k1".to_string(),
+            auth,
+        }
+    }
+
+    fn token_auth(token: &str) -> VaultAuthConfig {
+        VaultAuthConfig::Token {
+            token: SecretString::new(token.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn vault_roundtrip() -> eyre::Result<()> {
+        let vault = Arc::new(MockVault::default());
+        let address = spawn_mock_vault(vault.clone()).await?;
+
+        let keys_source =
+            VaultKeys::new(&config(address, token_auth(ROOT_TOKEN))).await?;
+
+        let (id, signer) = keys_source.new_signer("meta name").await?;
+        let signer = signer.with_chain_id(31337u64);
+
+        let loaded = keys_source.load_signer(id.clone()).await?;
+        assert_eq!(signer.address(), loaded.address());
+
+        let expected_address = ethers::utils::secret_key_to_address(
+            &vault.keys.lock().unwrap()[&id],
+        );
+        assert_eq!(signer.address(), expected_address);
+
+        let message = b"hello vault";
+        let signature = signer.sign_message(message).await?;
+        signature.verify(&message[..], signer.address())?;
+
+        let tx = Eip1559TransactionRequest::new()
+            .to(H160::zero())
+            .value(1)
+            .nonce(0)
+            .chain_id(31337u64)
+            .into();
+        let signature = signer.sign_transaction(&tx).await?;
+        assert_eq!(signature.recover(tx.sighash())?, signer.address());
+
+        assert!(keys_source
+            .import_private_key(SigningKey::random(&mut rand::rngs::OsRng))
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn approle_login() -> eyre::Result<()> {
+        let vault = Arc::new(MockVault::default());
+        let address = spawn_mock_vault(vault.clone()).await?;
+
+        let keys_source = VaultKeys::new(&config(
+            address.clone(),
+            VaultAuthConfig::AppRole {
+                role_id: ROLE_ID.to_string(),
+                secret_id: SecretString::new(SECRET_ID.to_string()),
+                mount: "approle".to_string(),
+            },
+        ))
+        .await?;
+        assert_eq!(*vault.logins.lock().unwrap(), 1);
+
+        let (id, _signer) = keys_source.new_signer("meta name").await?;
+
+        // Expired tokens are replaced by logging in again
+        vault.tokens.lock().unwrap().clear();
+        keys_source.load_signer(id).await?;
+        assert_eq!(*vault.logins.lock().unwrap(), 2);
+
+        let res = VaultKeys::new(&config(
+            address,
+            VaultAuthConfig::AppRole {
+                role_id: ROLE_ID.to_string(),
+                secret_id: SecretString::new("wrong".to_string()),
+                mount: "approle".to_string(),
+            },
+        ))
+        .await;
+        assert!(res.is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn invalid_token() -> eyre::Result<()> {
+        let vault = Arc::new(MockVault::default());
+        let address = spawn_mock_vault(vault).await?;
+
+        let keys_source =
+            VaultKeys::new(&config(address, token_auth("wrong"))).await?;
+
+        let err = keys_source.new_signer("meta name").await.unwrap_err();
+        assert!(err.to_string().contains("permission denied"));
+
+        Ok(())
+    }
+}
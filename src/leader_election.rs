@@ -0,0 +1,353 @@
+use std::collections::btree_map::Entry;
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use eyre::ContextCompat;
+use sqlx::{Connection, PgConnection};
+use tokio::sync::watch;
+
+use crate::config::LeaderElectionConfig;
+use crate::db::Database;
+use crate::shutdown::Shutdown;
+use crate::types::TaskStatus;
+
+/// Prefix of the advisory lock keys, keeps them apart from the locks of other
+/// applications sharing the database
+const LOCK_NAMESPACE: &str = "tx-sitter:";
+
+/// Elects a leader per task group among the instances sharing the database
+///
+/// Leading a group means holding a session level Postgres advisory lock on a
+/// dedicated connection. The locks are released when that connection closes,
+/// e.g. when the instance stops or loses the database, and followers take
+/// them over on their next attempt.
+///
+/// Without a config this instance leads every group.
+pub struct LeaderElection {
+    instance_id: String,
+    config: Option<LeaderElectionConfig>,
+    state: Arc<State>,
+}
+
+/// Groups and session, shared with the leaderships
+#[derive(Default)]
+struct State {
+    groups: Mutex<BTreeMap<String, watch::Sender<bool>>>,
+    session: tokio::sync::Mutex<Option<PgConnection>>,
+}
+
+/// Leadership of this instance over a task group
+#[derive(Clone)]
+pub struct Leadership {
+    group: String,
+    /// Missing when leader election is disabled
+    state: Option<Arc<State>>,
+    receiver: watch::Receiver<bool>,
+}
+
+/// Run of a leader task, checked by the task before each iteration
+///
+/// Tasks stop at iteration boundaries once shutdown is triggered or the
+/// leadership is lost, so they aren't dropped halfway through a write.
+#[derive(Clone)]
+pub struct Lease {
+    leadership: Leadership,
+    shutdown: Shutdown,
+}
+
+impl LeaderElection {
+    pub fn new(config: Option<LeaderElectionConfig>) -> Self {
+        let instance_id = config
+            .as_ref()
+            .and_then(|config| config.instance_id.clone())
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        Self {
+            instance_id,
+            config,
+            state: Arc::default(),
+        }
+    }
+
+    pub fn instance_id(&self) -> &str {
+        &self.instance_id
+    }
+
+    pub fn config(&self) -> Option<&LeaderElectionConfig> {
+        self.config.as_ref()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.config.is_some()
+    }
+
+    /// Registers a task group, returns None if it's already registered on
+    /// this instance
+    pub fn register(&self, group: impl Into<String>) -> Option<Leadership> {
+        let mut groups = self.state.groups.lock().unwrap();
+
+        match groups.entry(group.into()) {
+            Entry::Occupied(_) => None,
+            Entry::Vacant(entry) => {
+                let (sender, receiver) = watch::channel(!self.is_enabled());
+                let group = entry.key().clone();
+                entry.insert(sender);
+
+                Some(Leadership {
+                    group,
+                    state: self.is_enabled().then(|| self.state.clone()),
+                    receiver,
+                })
+            }
+        }
+    }
+
+    pub fn tasks(&self) -> Vec<TaskStatus> {
+        self.state
+            .groups
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(group, sender)| TaskStatus {
+                group: group.clone(),
+                leader: *sender.borrow(),
+            })
+            .collect()
+    }
+
+    /// Checks that the session holding the locks is alive and tries to
+    /// acquire the locks of the groups this instance follows
+    pub async fn renew(&self, db: &Database) -> eyre::Result<()> {
+        let mut session = self.state.session.lock().await;
+
+        let connection = match session.as_mut() {
+            Some(connection) => connection,
+            // Detached so the session outlives the pool's idle timeouts
+            None => session.insert(db.pool.acquire().await?.detach()),
+        };
+
+        sqlx::query("SELECT 1").execute(&mut *connection).await?;
+
+        let followed: Vec<String> = self
+            .state
+            .groups
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|(_, sender)| !*sender.borrow())
+            .map(|(group, _)| group.clone())
+            .collect();
+
+        for group in followed {
+            let acquired: bool = sqlx::query_scalar(
+                "SELECT pg_try_advisory_lock(hashtextextended($1, 0))",
+            )
+            .bind(format!("{LOCK_NAMESPACE}{group}"))
+            .fetch_one(&mut *connection)
+            .await?;
+
+            if acquired {
+                tracing::info!(group, "Acquired leadership");
+                self.state.set_leader(&group, true);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Stops the tasks of all groups and drops the session
+    pub async fn step_down(&self) {
+        self.state.step_down().await;
+    }
+
+    /// Releases the locks of this instance, its tasks must have stopped
+    /// already
+    pub async fn release(&self) {
+        let Some(connection) = self.state.session.lock().await.take() else {
+            return;
+        };
+
+        if let Err(err) = connection.close().await {
+            tracing::warn!(error = ?err, "Failed to release leadership");
+        }
+    }
+}
+
+impl State {
+    fn set_leader(&self, group: &str, leader: bool) {
+        if let Some(sender) = self.groups.lock().unwrap().get(group) {
+            sender.send_replace(leader);
+        }
+    }
+
+    async fn step_down(&self) {
+        for (group, sender) in self.groups.lock().unwrap().iter() {
+            if sender.send_replace(false) {
+                tracing::warn!(group, "Lost leadership");
+            }
+        }
+
+        self.session.lock().await.take();
+    }
+
+    /// Checks on the session that it still holds the lock of the group
+    async fn holds_lock(&self, group: &str) -> eyre::Result<bool> {
+        let mut session = self.session.lock().await;
+        let connection = session.as_mut().context("Missing session")?;
+
+        // Advisory locks on a bigint key keep its high half in `classid`
+        // and its low half in `objid`
+        let held = sqlx::query_scalar(
+            r#"
+            SELECT EXISTS (
+                SELECT 1
+                FROM pg_locks
+                WHERE locktype = 'advisory'
+                AND pid = pg_backend_pid()
+                AND granted
+                AND objsubid = 1
+                AND ((classid::INT8 << 32) | objid::INT8)
+                    = hashtextextended($1, 0)
+            )
+            "#,
+        )
+        .bind(format!("{LOCK_NAMESPACE}{group}"))
+        .fetch_one(&mut *connection)
+        .await?;
+
+        Ok(held)
+    }
+}
+
+impl Leadership {
+    pub fn is_leader(&self) -> bool {
+        *self.receiver.borrow()
+    }
+
+    /// Completes once this instance leads the group
+    pub async fn acquired(&mut self) {
+        // Senders live as long as the election
+        let _ = self.receiver.wait_for(|leader| *leader).await;
+    }
+
+    /// Completes once this instance no longer leads the group
+    pub async fn lost(&mut self) {
+        let _ = self.receiver.wait_for(|leader| !*leader).await;
+    }
+
+    /// Checks that this instance still leads the group
+    ///
+    /// Unlike `is_leader`, which only changes on renewals, this asks the
+    /// database whether the session still holds the lock. Failing that, the
+    /// instance steps down from all groups.
+    pub async fn verify(&self) -> bool {
+        if !self.is_leader() {
+            return false;
+        }
+
+        let Some(state) = &self.state else {
+            return true;
+        };
+
+        match state.holds_lock(&self.group).await {
+            Ok(true) => return true,
+            Ok(false) => {
+                tracing::warn!(group = self.group, "Lock no longer held");
+            }
+            Err(err) => {
+                tracing::error!(
+                    group = self.group,
+                    error = ?err,
+                    "Failed to verify leadership"
+                );
+            }
+        }
+
+        state.step_down().await;
+
+        false
+    }
+}
+
+impl Lease {
+    pub fn new(leadership: Leadership, shutdown: Shutdown) -> Self {
+        Self {
+            leadership,
+            shutdown,
+        }
+    }
+
+    /// Checks that the task may run its next iteration
+    pub async fn is_valid(&self) -> bool {
+        !self.shutdown.is_triggered() && self.leadership.verify().await
+    }
+
+    /// Completes once shutdown is triggered or the leadership is lost
+    pub async fn stopped(&self) {
+        let mut leadership = self.leadership.clone();
+
+        tokio::select! {
+            _ = self.shutdown.triggered() => {}
+            _ = leadership.lost() => {}
+        }
+    }
+
+    /// Sleeps for `duration`, returns false if the task was stopped in the
+    /// meantime
+    pub async fn sleep(&self, duration: Duration) -> bool {
+        tokio::select! {
+            _ = tokio::time::sleep(duration) => true,
+            _ = self.stopped() => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leads_every_group_when_disabled() {
+        let election = LeaderElection::new(None);
+
+        let leadership = election.register("broadcast_txs").unwrap();
+        assert!(leadership.is_leader());
+
+        assert!(election.register("broadcast_txs").is_none());
+    }
+
+    #[tokio::test]
+    async fn tracks_leadership_changes() {
+        let election = LeaderElection::new(Some(LeaderElectionConfig {
+            instance_id: Some("replica-1".to_string()),
+            lease_interval: Duration::from_secs(1),
+        }));
+        assert_eq!(election.instance_id(), "replica-1");
+
+        let mut leadership = election.register("index_chain:1").unwrap();
+        assert!(!leadership.is_leader());
+
+        election.state.set_leader("index_chain:1", true);
+        leadership.acquired().await;
+
+        let tasks = election.tasks();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0].group, "index_chain:1");
+        assert!(tasks[0].leader);
+
+        let lease = Lease::new(leadership.clone(), Shutdown::new());
+        let sleep = tokio::spawn({
+            let lease = lease.clone();
+            async move { lease.sleep(Duration::from_secs(60)).await }
+        });
+
+        election.step_down().await;
+        leadership.lost().await;
+        assert!(!election.tasks()[0].leader);
+
+        // Running tasks are stopped at their next iteration
+        assert!(!sleep.await.unwrap());
+        assert!(!lease.is_valid().await);
+    }
+}
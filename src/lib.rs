@@ -1,12 +1,21 @@
 #![allow(clippy::result_large_err)]
 
+pub mod admin_auth;
 pub mod api_key;
 pub mod app;
+pub mod audit;
 pub mod aws;
 pub mod broadcast_utils;
+pub mod budget;
+pub mod calldata;
 pub mod config;
 pub mod db;
 pub mod keys;
+pub mod leader_election;
+pub mod policy;
+pub mod rate_limit;
+pub mod remote;
+pub mod rpc_proxy;
 pub mod serde_utils;
 pub mod server;
 pub mod service;
@@ -14,3 +23,4 @@ pub mod shutdown;
 pub mod task_runner;
 pub mod tasks;
 pub mod types;
+pub mod vault;
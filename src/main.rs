@@ -80,10 +80,11 @@ async fn main() -> eyre::Result<()> {
             TracingShutdownHandle
         };
 
-    spawn_await_shutdown_task();
-
     tracing::info!(?config, "Starting service");
     let service = Service::new(config).await?;
+
+    spawn_await_shutdown_task(service.shutdown_handle());
+
     service.wait().await?;
 
     Ok(())
@@ -0,0 +1,133 @@
+use ethers::types::Address;
+use thiserror::Error;
+
+use crate::db::data::RelayerPolicy;
+
+pub const SELECTOR_LENGTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+pub enum PolicyViolation {
+    #[error("Destination address {0:?} is not allowed for this relayer")]
+    Destination(Address),
+    #[error("Function selector is not allowed for destination {0:?}")]
+    Selector(Address),
+    #[error("Contract creation is not allowed for this relayer")]
+    ContractCreation,
+}
+
+impl PolicyViolation {
+    pub fn error_id(&self) -> &'static str {
+        match self {
+            Self::Destination(_) => "destination_not_allowed",
+            Self::Selector(_) => "selector_not_allowed",
+            Self::ContractCreation => "contract_creation_not_allowed",
+        }
+    }
+}
+
+impl RelayerPolicy {
+    /// Checks whether a tx to `to` (or a contract creation if `None`)
+    /// with the given calldata is allowed
+    pub fn check_tx(
+        &self,
+        to: Option<Address>,
+        data: &[u8],
+    ) -> Result<(), PolicyViolation> {
+        let Some(to) = to else {
+            return if self.allow_contract_creation {
+                Ok(())
+            } else {
+                Err(PolicyViolation::ContractCreation)
+            };
+        };
+
+        let Some(allowed_targets) = &self.allowed_targets else {
+            return Ok(());
+        };
+
+        let target = allowed_targets
+            .iter()
+            .find(|target| target.address.0 == to)
+            .ok_or(PolicyViolation::Destination(to))?;
+
+        let Some(selectors) = &target.selectors else {
+            return Ok(());
+        };
+
+        let selector = data.get(..SELECTOR_LENGTH);
+        if selectors.iter().any(|s| Some(&s.0[..]) == selector) {
+            Ok(())
+        } else {
+            Err(PolicyViolation::Selector(to))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use hex_literal::hex;
+
+    use super::*;
+    use crate::db::data::AllowedTarget;
+
+    const TOKEN: Address = Address::repeat_byte(0x11);
+    const OTHER: Address = Address::repeat_byte(0x22);
+    const TRANSFER: [u8; 4] = hex!("a9059cbb");
+
+    fn policy() -> RelayerPolicy {
+        RelayerPolicy {
+            allowed_targets: Some(vec![
+                AllowedTarget {
+                    address: TOKEN.into(),
+                    selectors: Some(vec![TRANSFER.to_vec().into()]),
+                },
+                AllowedTarget {
+                    address: OTHER.into(),
+                    selectors: None,
+                },
+            ]),
+            allow_contract_creation: false,
+        }
+    }
+
+    #[test]
+    fn default_policy_allows_everything() {
+        let policy = RelayerPolicy::default();
+
+        assert!(policy.check_tx(Some(TOKEN), &[]).is_ok());
+        assert!(policy.check_tx(None, &[0xff]).is_ok());
+    }
+
+    #[test]
+    fn check_destination() {
+        let policy = policy();
+
+        assert!(policy.check_tx(Some(OTHER), &[0xff; 8]).is_ok());
+        assert_eq!(
+            policy.check_tx(Some(Address::zero()), &[]),
+            Err(PolicyViolation::Destination(Address::zero()))
+        );
+        assert_eq!(
+            policy.check_tx(None, &[]),
+            Err(PolicyViolation::ContractCreation)
+        );
+    }
+
+    #[test]
+    fn check_selector() {
+        let policy = policy();
+
+        let mut calldata = TRANSFER.to_vec();
+        calldata.extend_from_slice(&[0; 64]);
+
+        assert!(policy.check_tx(Some(TOKEN), &calldata).is_ok());
+        assert_eq!(
+            policy.check_tx(Some(TOKEN), &hex!("095ea7b3")),
+            Err(PolicyViolation::Selector(TOKEN))
+        );
+        assert_eq!(
+            policy.check_tx(Some(TOKEN), &TRANSFER[..3]),
+            Err(PolicyViolation::Selector(TOKEN))
+        );
+    }
+}
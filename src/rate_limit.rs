@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use poem::http::{header, StatusCode};
+use poem::{IntoResponse, Response, Result};
+
+use crate::api_key::ApiKey;
+use crate::app::App;
+use crate::config::RateLimitsConfig;
+use crate::db::data::{RateLimit, RelayerRateLimits};
+use crate::types::{ApiKeyScope, ErrorResponse};
+
+/// How long the limits of a relayer are cached
+const RELAYER_LIMITS_TTL: Duration = Duration::from_secs(10);
+
+/// Minimum time between sweeps of idle buckets and expired relayer limits
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Maximum number of buckets and of cached relayer limits
+const MAX_ENTRIES: usize = 100_000;
+
+/// Checks that a limit can ever let a request through
+pub fn validate_rate_limit(limit: &RateLimit) -> Result<(), &'static str> {
+    if limit.requests_per_minute == 0 || limit.burst == 0 {
+        return Err("requests per minute and burst must be at least 1");
+    }
+
+    Ok(())
+}
+
+/// Token bucket rate limiting of the relayer API per API key and route class
+///
+/// Limits are applied once the API key is validated, so only keys of the
+/// database end up in the buckets.
+pub struct RateLimiter {
+    defaults: RateLimitsConfig,
+    buckets: Mutex<Buckets>,
+    relayer_limits: Mutex<RelayerLimits>,
+}
+
+type BucketKey = ([u8; 32], ApiKeyScope);
+
+struct Buckets {
+    buckets: HashMap<BucketKey, Bucket>,
+    pruned_at: Instant,
+}
+
+struct RelayerLimits {
+    limits: HashMap<String, (Instant, RelayerRateLimits)>,
+    pruned_at: Instant,
+}
+
+impl RateLimiter {
+    pub fn new(defaults: Option<RateLimitsConfig>) -> eyre::Result<Self> {
+        let defaults = defaults.unwrap_or_default();
+
+        for scope in ApiKeyScope::ALL {
+            if let Some(limit) = defaults.get(scope) {
+                validate_rate_limit(&limit).map_err(|err| {
+                    eyre::eyre!(
+                        "Invalid server.rate_limits.{}: {err}",
+                        scope.as_str()
+                    )
+                })?;
+            }
+        }
+
+        Ok(Self {
+            defaults,
+            buckets: Mutex::new(Buckets {
+                buckets: HashMap::new(),
+                pruned_at: Instant::now(),
+            }),
+            relayer_limits: Mutex::new(RelayerLimits {
+                limits: HashMap::new(),
+                pruned_at: Instant::now(),
+            }),
+        })
+    }
+
+    /// Takes a token for the request of a validated API key, responds with
+    /// 429 once its bucket is empty
+    pub async fn check(
+        &self,
+        app: &App,
+        api_key: &ApiKey,
+        scope: ApiKeyScope,
+    ) -> Result<()> {
+        let owner_id = api_key.relayer_id().to_string();
+
+        let Some(limit) = self.limit(app, &owner_id, scope).await? else {
+            return Ok(());
+        };
+
+        let labels = [
+            ("owner_id", owner_id.clone()),
+            ("class", scope.as_str().to_string()),
+        ];
+
+        let key = (api_key.api_key_secret_hash(), scope);
+        if let Err(retry_after) = self.take(key, limit) {
+            tracing::warn!(
+                owner_id,
+                class = scope.as_str(),
+                ?retry_after,
+                "API key rate limited"
+            );
+            metrics::counter!("api_key_rate_limited", &labels).increment(1);
+
+            return Err(poem::error::Error::from_response(
+                rate_limited_response(retry_after),
+            ));
+        }
+
+        metrics::counter!("api_key_requests", &labels).increment(1);
+
+        Ok(())
+    }
+
+    /// Limit of the route class, the limits of the relayer take precedence
+    /// over the defaults
+    async fn limit(
+        &self,
+        app: &App,
+        owner_id: &str,
+        scope: ApiKeyScope,
+    ) -> Result<Option<RateLimit>> {
+        let cached = self
+            .relayer_limits
+            .lock()
+            .expect("Poisoned lock")
+            .limits
+            .get(owner_id)
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < RELAYER_LIMITS_TTL)
+            .map(|(_, limits)| limits.clone());
+
+        let relayer_limits = match cached {
+            Some(limits) => limits,
+            None => {
+                // Pool API keys have no relayer and use the defaults
+                let limits = app
+                    .db
+                    .get_relayer(owner_id)
+                    .await?
+                    .map(|relayer| relayer.rate_limits)
+                    .unwrap_or_default();
+
+                let now = Instant::now();
+                let mut relayer_limits =
+                    self.relayer_limits.lock().expect("Poisoned lock");
+
+                if now.duration_since(relayer_limits.pruned_at) > PRUNE_INTERVAL
+                    || relayer_limits.limits.len() >= MAX_ENTRIES
+                {
+                    relayer_limits.limits.retain(|_, (fetched_at, _)| {
+                        now.duration_since(*fetched_at) < RELAYER_LIMITS_TTL
+                    });
+                    relayer_limits.pruned_at = now;
+                }
+
+                if relayer_limits.limits.len() < MAX_ENTRIES {
+                    relayer_limits
+                        .limits
+                        .insert(owner_id.to_string(), (now, limits.clone()));
+                }
+
+                limits
+            }
+        };
+
+        Ok(relayer_limits
+            .get(scope)
+            .or_else(|| self.defaults.get(scope)))
+    }
+
+    /// Takes a token from the bucket of the key, returns the time until the
+    /// next token otherwise
+    fn take(&self, key: BucketKey, limit: RateLimit) -> Result<(), Duration> {
+        let now = Instant::now();
+
+        let mut buckets = self.buckets.lock().expect("Poisoned lock");
+
+        if now.duration_since(buckets.pruned_at) > PRUNE_INTERVAL
+            || buckets.buckets.len() >= MAX_ENTRIES
+        {
+            buckets.buckets.retain(|_, bucket| !bucket.is_full(now));
+            buckets.pruned_at = now;
+        }
+
+        // Evicts the least recently used bucket, it's the one most likely
+        // to be full again
+        if buckets.buckets.len() >= MAX_ENTRIES
+            && !buckets.buckets.contains_key(&key)
+        {
+            let oldest = buckets
+                .buckets
+                .iter()
+                .min_by_key(|(_, bucket)| bucket.updated_at)
+                .map(|(key, _)| *key);
+
+            if let Some(oldest) = oldest {
+                buckets.buckets.remove(&oldest);
+            }
+        }
+
+        buckets
+            .buckets
+            .entry(key)
+            .or_insert_with(|| Bucket::new(limit, now))
+            .take(limit, now)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Bucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated_at: Instant,
+}
+
+impl Bucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.burst as f64,
+            updated_at: now,
+        }
+    }
+
+    fn refill_rate(limit: &RateLimit) -> f64 {
+        limit.requests_per_minute as f64 / 60.0
+    }
+
+    fn tokens_at(&self, now: Instant) -> f64 {
+        let elapsed = now.duration_since(self.updated_at).as_secs_f64();
+
+        (self.tokens + elapsed * Self::refill_rate(&self.limit))
+            .min(self.limit.burst as f64)
+    }
+
+    fn is_full(&self, now: Instant) -> bool {
+        self.tokens_at(now) >= self.limit.burst as f64
+    }
+
+    fn take(&mut self, limit: RateLimit, now: Instant) -> Result<(), Duration> {
+        self.tokens = self.tokens_at(now);
+        self.updated_at = now;
+
+        // Limits changed by an admin apply from now on
+        if self.limit != limit {
+            self.limit = limit;
+            self.tokens = self.tokens.min(limit.burst as f64);
+        }
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            return Ok(());
+        }
+
+        Err(Duration::from_secs_f64(
+            (1.0 - self.tokens) / Self::refill_rate(&limit),
+        ))
+    }
+}
+
+fn rate_limited_response(retry_after: Duration) -> Response {
+    let retry_after_secs = retry_after.as_secs_f64().ceil().max(1.0) as u64;
+
+    let mut response = ErrorResponse::new(
+        StatusCode::TOO_MANY_REQUESTS,
+        "rate_limited",
+        "Too many requests with this API key.",
+    )
+    .into_response();
+
+    response
+        .headers_mut()
+        .insert(header::RETRY_AFTER, retry_after_secs.into());
+
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIMIT: RateLimit = RateLimit {
+        requests_per_minute: 60,
+        burst: 2,
+    };
+
+    #[test]
+    fn bucket_allows_burst_then_refills() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(LIMIT, start);
+
+        assert!(bucket.take(LIMIT, start).is_ok());
+        assert!(bucket.take(LIMIT, start).is_ok());
+
+        let retry_after = bucket.take(LIMIT, start).unwrap_err();
+        assert_eq!(retry_after, Duration::from_secs(1));
+
+        let later = start + Duration::from_secs(1);
+        assert!(bucket.take(LIMIT, later).is_ok());
+        assert!(bucket.take(LIMIT, later).is_err());
+
+        assert!(bucket.is_full(later + Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn lowered_limit_caps_tokens() {
+        let start = Instant::now();
+        let mut bucket = Bucket::new(
+            RateLimit {
+                requests_per_minute: 60,
+                burst: 10,
+            },
+            start,
+        );
+
+        assert!(bucket.take(LIMIT, start).is_ok());
+        assert!(bucket.take(LIMIT, start).is_ok());
+        assert!(bucket.take(LIMIT, start).is_err());
+    }
+}
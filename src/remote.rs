@@ -0,0 +1 @@
+pub mod ethers_signer;
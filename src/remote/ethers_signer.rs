@@ -0,0 +1,171 @@
+//! Signer delegating to a remote Web3Signer compatible JSON-RPC signer
+
+use std::sync::Arc;
+
+use ethers::core::types::transaction::eip2718::{
+    TypedTransaction, TypedTransactionError,
+};
+use ethers::core::types::transaction::eip712::{Eip712, TypedData};
+use ethers::core::types::{
+    Address, Bytes, Signature as EthSig, SignatureError, H256, U64,
+};
+use ethers::providers::{Http, Middleware, Provider, ProviderError};
+use ethers::utils::rlp::Rlp;
+use tracing::instrument;
+
+/// An ethers Signer backed by a remote signer such as Web3Signer.
+///
+/// Keys never leave the remote signer, they are identified by their address.
+/// Every signature returned by the remote signer is checked to recover to
+/// the signer's address.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    provider: Arc<Provider<Http>>,
+    chain_id: u64,
+    address: Address,
+}
+
+/// Errors produced by the RemoteSigner
+#[derive(thiserror::Error, Debug)]
+pub enum RemoteSignerError {
+    #[error("{0}")]
+    Provider(#[from] ProviderError),
+    #[error("{0}")]
+    Signature(#[from] SignatureError),
+    #[error("{0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Invalid signed transaction: {0}")]
+    SignedTransaction(#[from] TypedTransactionError),
+    #[error("Remote signature recovers to {actual:?} instead of {expected:?}")]
+    AddressMismatch { expected: Address, actual: Address },
+    #[error("Remote signers only support signing JSON typed data")]
+    UnsupportedTypedData,
+    /// Error type from Eip712Error message
+    #[error("error encoding eip712 struct: {0:?}")]
+    Eip712Error(String),
+}
+
+impl RemoteSigner {
+    pub fn new(
+        provider: Arc<Provider<Http>>,
+        address: Address,
+        chain_id: u64,
+    ) -> Self {
+        Self {
+            provider,
+            chain_id,
+            address,
+        }
+    }
+
+    /// Signs EIP-712 typed data with `eth_signTypedData`
+    #[instrument(err, skip(typed_data))]
+    pub async fn sign_typed_data_json(
+        &self,
+        typed_data: &TypedData,
+    ) -> Result<EthSig, RemoteSignerError> {
+        let digest = typed_data
+            .encode_eip712()
+            .map_err(|e| RemoteSignerError::Eip712Error(e.to_string()))?;
+
+        let signature: String = self
+            .provider
+            .request("eth_signTypedData", (self.address, typed_data))
+            .await?;
+        let signature: EthSig = signature.parse()?;
+
+        self.check_signature(&signature, H256(digest))?;
+
+        Ok(signature)
+    }
+
+    fn check_signature(
+        &self,
+        signature: &EthSig,
+        hash: H256,
+    ) -> Result<(), RemoteSignerError> {
+        let actual = signature.recover(hash)?;
+
+        if actual != self.address {
+            return Err(RemoteSignerError::AddressMismatch {
+                expected: self.address,
+                actual,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ethers::signers::Signer for RemoteSigner {
+    type Error = RemoteSignerError;
+
+    #[instrument(err, skip(message))]
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<EthSig, Self::Error> {
+        let message = message.as_ref();
+
+        let signature = self
+            .provider
+            .sign(Bytes::from(message.to_vec()), &self.address)
+            .await?;
+
+        self.check_signature(&signature, ethers::utils::hash_message(message))?;
+
+        Ok(signature)
+    }
+
+    #[instrument(err)]
+    async fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+    ) -> Result<EthSig, Self::Error> {
+        let mut tx = tx.clone();
+        let chain_id =
+            tx.chain_id().map(|id| id.as_u64()).unwrap_or(self.chain_id);
+        tx.set_chain_id(chain_id);
+        tx.set_from(self.address);
+
+        // Transaction requests skip the chain id when serialized
+        let mut request = serde_json::to_value(&tx)?;
+        request["chainId"] = serde_json::to_value(U64::from(chain_id))?;
+
+        let signed_tx: Bytes = self
+            .provider
+            .request("eth_signTransaction", [request])
+            .await?;
+
+        let (_, signature) =
+            TypedTransaction::decode_signed(&Rlp::new(&signed_tx))?;
+
+        // Guards against the remote signer altering the transaction
+        self.check_signature(&signature, tx.sighash())?;
+
+        Ok(signature)
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        _payload: &T,
+    ) -> Result<EthSig, Self::Error> {
+        Err(RemoteSignerError::UnsupportedTypedData)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the signer's chain id
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Sets the signer's chain id
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+}
@@ -0,0 +1,360 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use serde_json::{json, Value};
+
+use crate::config::RpcProxyConfig;
+use crate::types::RpcRequest;
+
+/// JSON-RPC error code of methods that are not allowed
+pub const METHOD_NOT_ALLOWED_CODE: i64 = -32601;
+
+/// Minimum time between sweeps of expired cache entries
+const PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Forwards JSON-RPC requests of relayers to the node of their network
+///
+/// Requests share a pooled HTTP client, methods are checked against the
+/// configured allowlist and denylist and idempotent reads are cached briefly.
+pub struct RpcProxy {
+    config: RpcProxyConfig,
+    client: reqwest::Client,
+    cache: Mutex<Cache>,
+}
+
+type CacheKey = (u64, String, String);
+
+struct Cache {
+    entries: HashMap<CacheKey, (Instant, Value)>,
+    /// Latest finalized block number seen per chain
+    finalized: HashMap<u64, u64>,
+    pruned_at: Instant,
+}
+
+impl RpcProxy {
+    pub fn new(config: RpcProxyConfig) -> eyre::Result<Self> {
+        let client =
+            reqwest::Client::builder().timeout(config.timeout).build()?;
+
+        Ok(Self {
+            config,
+            client,
+            cache: Mutex::new(Cache {
+                entries: HashMap::new(),
+                finalized: HashMap::new(),
+                pruned_at: Instant::now(),
+            }),
+        })
+    }
+
+    pub fn max_batch_size(&self) -> usize {
+        self.config.max_batch_size
+    }
+
+    pub fn is_allowed(&self, method: &str) -> bool {
+        let allowed = self.config.allowed_methods.is_empty()
+            || matches_any(&self.config.allowed_methods, method);
+
+        allowed && !matches_any(&self.config.denied_methods, method)
+    }
+
+    /// Calls the node at `url`, returns the responses in the order of the
+    /// requests
+    ///
+    /// Requests which are neither denied nor cached are sent as a single
+    /// batch.
+    pub async fn call(
+        &self,
+        chain_id: u64,
+        url: &str,
+        requests: &[RpcRequest],
+    ) -> eyre::Result<Vec<Value>> {
+        let mut responses = vec![Value::Null; requests.len()];
+        let mut forwarded = vec![];
+
+        for (idx, req) in requests.iter().enumerate() {
+            if !self.is_allowed(&req.method) {
+                tracing::warn!(method = req.method, "RPC method not allowed");
+                metrics::counter!("rpc_proxy_denied").increment(1);
+
+                let message = format!("Method {} is not allowed", req.method);
+                responses[idx] = json!({
+                    "jsonrpc": "2.0",
+                    "id": req.id,
+                    "error": {
+                        "code": METHOD_NOT_ALLOWED_CODE,
+                        "message": message,
+                    },
+                });
+            } else if let Some(result) = self.cached(chain_id, req) {
+                let method = req.method.clone();
+                metrics::counter!("rpc_proxy_cache_hits", "method" => method)
+                    .increment(1);
+
+                responses[idx] = json!({
+                    "jsonrpc": "2.0",
+                    "id": req.id,
+                    "result": result,
+                });
+            } else {
+                forwarded.push(idx);
+            }
+        }
+
+        if forwarded.is_empty() {
+            return Ok(responses);
+        }
+
+        // Ids are replaced with the index of the request so responses can be
+        // matched even if the client reused ids
+        let batch: Vec<_> = forwarded
+            .iter()
+            .map(|&idx| {
+                let req = &requests[idx];
+
+                json!({
+                    "jsonrpc": "2.0",
+                    "id": idx,
+                    "method": req.method,
+                    "params": req.params,
+                })
+            })
+            .collect();
+
+        let body = if batch.len() == 1 {
+            batch[0].clone()
+        } else {
+            Value::Array(batch)
+        };
+
+        let response: Value = self
+            .client
+            .post(url)
+            .json(&body)
+            .send()
+            .await
+            .map_err(|err| {
+                eyre::eyre!("Error sending request to {}: {}", url, err)
+            })?
+            .json()
+            .await
+            .map_err(|err| {
+                eyre::eyre!("Error parsing response from {}: {}", url, err)
+            })?;
+
+        let node_responses = match response {
+            Value::Array(node_responses) => node_responses,
+            response => vec![response],
+        };
+
+        for mut response in node_responses {
+            let idx = response
+                .get("id")
+                .and_then(Value::as_u64)
+                .map(|idx| idx as usize)
+                .filter(|idx| forwarded.contains(idx));
+
+            let Some(idx) = idx else {
+                // Errors of the whole batch, e.g. nodes without batch support
+                for &idx in &forwarded {
+                    if responses[idx].is_null() {
+                        let mut response = response.clone();
+                        response["id"] = json!(requests[idx].id);
+                        responses[idx] = response;
+                    }
+                }
+
+                continue;
+            };
+
+            let req = &requests[idx];
+
+            if let Some(result) = response.get("result") {
+                self.store(chain_id, req, result);
+            }
+
+            response["id"] = json!(req.id);
+            responses[idx] = response;
+        }
+
+        for &idx in &forwarded {
+            if responses[idx].is_null() {
+                eyre::bail!(
+                    "Missing response to {} from {}",
+                    requests[idx].method,
+                    url
+                );
+            }
+        }
+
+        Ok(responses)
+    }
+
+    fn cached(&self, chain_id: u64, req: &RpcRequest) -> Option<Value> {
+        let cache = self.cache.lock().expect("Poisoned lock");
+
+        if !is_cacheable(req, cache.finalized.get(&chain_id).copied()) {
+            return None;
+        }
+
+        cache
+            .entries
+            .get(&cache_key(chain_id, req))
+            .filter(|(stored_at, _)| {
+                stored_at.elapsed() < self.config.cache_ttl
+            })
+            .map(|(_, result)| result.clone())
+    }
+
+    fn store(&self, chain_id: u64, req: &RpcRequest, result: &Value) {
+        let now = Instant::now();
+
+        let mut cache = self.cache.lock().expect("Poisoned lock");
+
+        if block_tag(req) == Some("finalized") {
+            if let Some(number) = result.get("number").and_then(parse_hex_u64) {
+                let finalized = cache.finalized.entry(chain_id).or_default();
+                *finalized = (*finalized).max(number);
+            }
+        }
+
+        if !is_cacheable(req, cache.finalized.get(&chain_id).copied()) {
+            return;
+        }
+
+        if now.duration_since(cache.pruned_at) > PRUNE_INTERVAL {
+            let ttl = self.config.cache_ttl;
+            cache.entries.retain(|_, (stored_at, _)| {
+                now.duration_since(*stored_at) < ttl
+            });
+            cache.pruned_at = now;
+        }
+
+        cache
+            .entries
+            .insert(cache_key(chain_id, req), (now, result.clone()));
+    }
+}
+
+fn matches_any(patterns: &[String], method: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => method.starts_with(prefix),
+            None => method == pattern,
+        })
+}
+
+fn cache_key(chain_id: u64, req: &RpcRequest) -> CacheKey {
+    (chain_id, req.method.clone(), req.params.to_string())
+}
+
+/// Whether the result of the request may be served from the cache
+///
+/// Blocks by number are only cached once finalized, the finalized block
+/// number is learned from `finalized` block requests passing the proxy.
+fn is_cacheable(req: &RpcRequest, finalized: Option<u64>) -> bool {
+    match req.method.as_str() {
+        "eth_chainId" | "net_version" | "eth_blockNumber" => true,
+        "eth_getBlockByNumber" => match block_tag(req) {
+            Some("finalized" | "earliest") => true,
+            Some(tag) => parse_hex_u64(&json!(tag))
+                .zip(finalized)
+                .is_some_and(|(number, finalized)| number <= finalized),
+            None => false,
+        },
+        _ => false,
+    }
+}
+
+/// Block number or tag of an `eth_getBlockByNumber` request
+fn block_tag(req: &RpcRequest) -> Option<&str> {
+    if req.method != "eth_getBlockByNumber" {
+        return None;
+    }
+
+    req.params.get(0)?.as_str()
+}
+
+fn parse_hex_u64(value: &Value) -> Option<u64> {
+    let hex = value.as_str()?.strip_prefix("0x")?;
+
+    u64::from_str_radix(hex, 16).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::JsonRpcVersion;
+
+    fn request(method: &str, params: Value) -> RpcRequest {
+        RpcRequest {
+            id: 1,
+            method: method.to_string(),
+            params,
+            jsonrpc: JsonRpcVersion::V2,
+        }
+    }
+
+    #[test]
+    fn method_allowlist_and_denylist() {
+        let proxy = RpcProxy::new(RpcProxyConfig::default()).unwrap();
+
+        assert!(proxy.is_allowed("eth_call"));
+        assert!(!proxy.is_allowed("debug_traceTransaction"));
+        assert!(!proxy.is_allowed("admin_peers"));
+
+        let proxy = RpcProxy::new(RpcProxyConfig {
+            allowed_methods: vec![
+                "eth_*".to_string(),
+                "net_version".to_string(),
+            ],
+            denied_methods: vec!["eth_sign".to_string()],
+            ..Default::default()
+        })
+        .unwrap();
+
+        assert!(proxy.is_allowed("eth_call"));
+        assert!(proxy.is_allowed("net_version"));
+        assert!(!proxy.is_allowed("net_peerCount"));
+        assert!(!proxy.is_allowed("eth_sign"));
+    }
+
+    #[test]
+    fn caches_finalized_blocks_only() {
+        let by_number = |block: &str| {
+            request("eth_getBlockByNumber", json!([block, false]))
+        };
+
+        assert!(is_cacheable(&request("eth_chainId", json!([])), None));
+        assert!(is_cacheable(&by_number("finalized"), None));
+        assert!(!is_cacheable(&by_number("latest"), Some(10)));
+        assert!(!is_cacheable(&by_number("0xa"), None));
+        assert!(is_cacheable(&by_number("0xa"), Some(10)));
+        assert!(!is_cacheable(&by_number("0xb"), Some(10)));
+        assert!(!is_cacheable(&request("eth_call", json!([])), None));
+    }
+
+    #[test]
+    fn learns_finalized_block_number() {
+        let proxy = RpcProxy::new(RpcProxyConfig::default()).unwrap();
+        let old_block = request("eth_getBlockByNumber", json!(["0x5", false]));
+
+        proxy.store(1, &old_block, &json!({ "number": "0x5" }));
+        assert!(proxy.cached(1, &old_block).is_none());
+
+        proxy.store(
+            1,
+            &request("eth_getBlockByNumber", json!(["finalized", false])),
+            &json!({ "number": "0x8" }),
+        );
+        proxy.store(1, &old_block, &json!({ "number": "0x5" }));
+
+        assert_eq!(
+            proxy.cached(1, &old_block),
+            Some(json!({ "number": "0x5" }))
+        );
+        assert!(proxy.cached(2, &old_block).is_none());
+    }
+}
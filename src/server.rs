@@ -1,9 +1,14 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use chrono::{DateTime, Utc};
+use ethers::core::k256::ecdsa::SigningKey;
 use ethers::middleware::Middleware;
 use ethers::providers::{Http, Provider};
-use ethers::signers::Signer;
+use ethers::signers::{Signer, Wallet};
+use ethers::types::transaction::eip712::{Eip712, TypedData};
+use ethers::types::{Address, U256};
+use ethers::utils::{get_contract_address, hash_message};
 use eyre::ContextCompat;
 use poem::http::StatusCode;
 use poem::listener::{Acceptor, Listener, TcpListener};
@@ -13,23 +18,43 @@ use poem::{EndpointExt, IntoResponse, Result, Route};
 use poem_openapi::param::{Path, Query};
 use poem_openapi::payload::Json;
 use poem_openapi::{ApiResponse, OpenApi, OpenApiService, Tags};
-use security::BasicAuth;
-use serde_json::Value;
+use security::{AdminAuth, BearerAuth};
+use serde_json::{json, Value};
 use url::Url;
 
+use crate::admin_auth::AdminRole;
 use crate::api_key::ApiKey;
 use crate::app::App;
-use crate::db::CreateResult;
+use crate::audit::{AuditAction, AuditEvent};
+use crate::broadcast_utils::{check_new_tx, TxRejection};
+use crate::budget::BudgetViolation;
+use crate::calldata;
+use crate::db::data::{
+    ApiKeyInfo, RateLimit, RelayerInfo, RelayerPool, RpcKind, SignatureKind,
+    DEFAULT_LOW_BALANCE,
+};
+use crate::db::{CreateResult, DatabaseTx};
+use crate::keys::local_keys::{
+    signing_key_from_hex, signing_key_from_keystore,
+};
+use crate::policy::{PolicyViolation, SELECTOR_LENGTH};
 use crate::service::Service;
 use crate::task_runner::TaskRunner;
 use crate::types::{
-    CreateApiKeyResponse, CreateNetworkRequest, CreateRelayerRequest,
-    CreateRelayerResponse, ErrorResponse, GetTxResponse, NetworkResponse,
-    RelayerResponse, RelayerUpdateRequest, RpcRequest, SendTxRequest,
-    SendTxResponse, TxStatus,
+    ApiKeyResponse, ApiKeyScope, AuditLogEntryResponse, CreateApiKeyResponse,
+    CreateNetworkRequest, CreateRelayerPoolRequest, CreateRelayerRequest,
+    CreateRelayerResponse, DecommissionRelayerRequest, ErrorResponse,
+    GetTxResponse, ImportRelayerRequest, NetworkResponse,
+    RelayerDecommissionResponse, RelayerFundingResponse, RelayerPoolResponse,
+    RelayerRateLimitsResponse, RelayerResponse, RelayerUpdateRequest,
+    RotateRelayerKeyResponse, RpcPayload, SendTxRequest, SendTxResponse,
+    ServiceStatus, SignMessageRequest, SignTypedDataRequest, SignatureResponse,
+    TxStatus,
 };
 
+mod routing;
 mod security;
+mod signer_rpc;
 mod trace_middleware;
 
 #[derive(Tags)]
@@ -52,11 +77,11 @@ impl AdminApi {
     )]
     async fn create_relayer(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
         Json(req): Json<CreateRelayerRequest>,
     ) -> Result<Json<CreateRelayerResponse>> {
-        basic_auth.validate(app).await?;
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
 
         let (key_id, signer) = app.keys_source.new_signer(&req.name).await?;
 
@@ -65,15 +90,135 @@ impl AdminApi {
         let relayer_id = uuid::Uuid::new_v4();
         let relayer_id = relayer_id.to_string();
 
-        app.db
-            .create_relayer(
-                &relayer_id,
-                &req.name,
-                req.chain_id,
-                &key_id,
-                address,
-            )
-            .await?;
+        let mut tx = app.db.begin().await?;
+
+        tx.create_relayer(
+            &relayer_id,
+            &req.name,
+            req.chain_id,
+            &key_id,
+            address,
+        )
+        .await?;
+
+        let relayer = get_relayer_state(&mut tx, &relayer_id).await?;
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::CreateRelayer, &relayer_id)
+                .after(relayer),
+        )
+        .await?;
+
+        Ok(Json(CreateRelayerResponse {
+            relayer_id,
+            address: address.into(),
+        }))
+    }
+
+    /// Import Relayer
+    ///
+    /// Creates a relayer from an existing key, its nonce is synced from the chain
+    #[oai(
+        path = "/relayer/import",
+        method = "post",
+        operation_id = "import_relayer",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn import_relayer(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Json(req): Json<ImportRelayerRequest>,
+    ) -> Result<Json<CreateRelayerResponse>> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let (key_id, address) = match (
+            &req.key_id,
+            &req.keystore,
+            &req.private_key,
+        ) {
+            (Some(key_id), None, None) => {
+                let signer =
+                    app.keys_source.load_signer(key_id.clone()).await.map_err(
+                        |err| {
+                            invalid_key_error(format!("Invalid key id: {err}"))
+                        },
+                    )?;
+
+                let address = signer.address();
+                check_imported_address(app, &req, address).await?;
+
+                // Remote keys are identified by their address, which is
+                // stored in the same format as for assigned keys
+                let key_id = if app.config.keys.is_remote() {
+                    format!("{address:?}")
+                } else {
+                    key_id.clone()
+                };
+
+                (key_id, address)
+            }
+            (None, Some(keystore), None) => {
+                let signing_key = signing_key_from_keystore(
+                    keystore.clone(),
+                    req.keystore_password.clone().unwrap_or_default(),
+                )
+                .await
+                .map_err(|err| {
+                    invalid_key_error(format!("Invalid keystore: {err}"))
+                })?;
+
+                import_signing_key(app, &req, signing_key).await?
+            }
+            (None, None, Some(private_key)) => {
+                let signing_key = signing_key_from_hex(
+                    private_key.strip_prefix("0x").unwrap_or(private_key),
+                )
+                .map_err(|_| invalid_key_error("Invalid private key"))?;
+
+                import_signing_key(app, &req, signing_key).await?
+            }
+            _ => {
+                return Err(invalid_key_error(
+                    "Exactly one of `keyId`, `keystore` or `privateKey` must be provided",
+                ));
+            }
+        };
+
+        let nonce = app
+            .http_provider(req.chain_id)
+            .await?
+            .get_transaction_count(address, None)
+            .await
+            .map_err(|err| eyre::eyre!("Error fetching nonce: {err}"))?;
+
+        let relayer_id = uuid::Uuid::new_v4().to_string();
+
+        let mut tx = app.db.begin().await?;
+
+        tx.create_relayer(
+            &relayer_id,
+            &req.name,
+            req.chain_id,
+            &key_id,
+            address,
+        )
+        .await?;
+        tx.sync_relayer_nonce(&relayer_id, nonce.as_u64()).await?;
+
+        let relayer = get_relayer_state(&mut tx, &relayer_id).await?;
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::ImportRelayer, &relayer_id)
+                .after(relayer),
+        )
+        .await?;
+
+        tracing::info!(relayer_id, ?address, %nonce, "Relayer imported");
 
         Ok(Json(CreateRelayerResponse {
             relayer_id,
@@ -90,14 +235,30 @@ impl AdminApi {
     )]
     async fn get_relayers(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
     ) -> Result<Json<Vec<RelayerResponse>>> {
-        basic_auth.validate(app).await?;
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
 
         let relayer_info = app.db.get_relayers().await?;
-
-        Ok(Json(relayer_info.into_iter().map(|v| v.into()).collect()))
+        let balances = app.db.get_relayer_balances(None).await?;
+
+        Ok(Json(
+            relayer_info
+                .into_iter()
+                .map(|relayer_info| {
+                    let mut relayer = RelayerResponse::from(relayer_info);
+
+                    relayer.balances = balances
+                        .iter()
+                        .filter(|balance| balance.relayer_id == relayer.id)
+                        .map(|balance| balance.clone().into())
+                        .collect();
+
+                    relayer
+                })
+                .collect(),
+        ))
     }
 
     /// Get Relayer
@@ -109,21 +270,29 @@ impl AdminApi {
     )]
     async fn get_relayer(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
         Path(relayer_id): Path<String>,
     ) -> Result<Json<RelayerResponse>> {
-        basic_auth.validate(app).await?;
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
 
-        let relayer_info = app.db.get_relayer(&relayer_id).await?;
-
-        match relayer_info {
-            Some(relayer_info) => Ok(Json(relayer_info.into())),
-            None => Err(poem::error::Error::from_string(
+        let Some(relayer_info) = app.db.get_relayer(&relayer_id).await? else {
+            return Err(poem::error::Error::from_string(
                 "Relayer not found".to_string(),
                 StatusCode::NOT_FOUND,
-            )),
-        }
+            ));
+        };
+
+        let mut relayer = RelayerResponse::from(relayer_info);
+        relayer.balances = app
+            .db
+            .get_relayer_balances(Some(&relayer_id))
+            .await?
+            .into_iter()
+            .map(|v| v.into())
+            .collect();
+
+        Ok(Json(relayer))
     }
 
     /// Update Relayer
@@ -135,14 +304,61 @@ impl AdminApi {
     )]
     async fn update_relayer(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
         Path(relayer_id): Path<String>,
         Json(req): Json<RelayerUpdateRequest>,
     ) -> Result<()> {
-        basic_auth.validate(app).await?;
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let invalid_selector = req
+            .policy
+            .iter()
+            .flat_map(|policy| policy.allowed_targets.iter().flatten())
+            .flat_map(|target| target.selectors.iter().flatten())
+            .any(|selector| selector.0.len() != SELECTOR_LENGTH);
+
+        if invalid_selector {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_selector",
+                    "Function selectors must be exactly 4 bytes long.",
+                )
+                .into_response(),
+            ));
+        }
+
+        if let Some(funding) = &req.funding {
+            check_funding(app, &relayer_id, funding).await?;
+        }
+
+        if let Some(rate_limits) = &req.rate_limits {
+            check_rate_limits(rate_limits)?;
+        }
+
+        if req.enabled == Some(true)
+            && app.db.get_decommission(&relayer_id).await?.is_some()
+        {
+            return Err(relayer_decommissioning_error());
+        }
 
-        app.db.update_relayer(&relayer_id, &req).await?;
+        let mut tx = app.db.begin().await?;
+
+        let before = get_relayer_state(&mut tx, &relayer_id).await?;
+
+        tx.update_relayer(&relayer_id, &req).await?;
+
+        let after = get_relayer_state(&mut tx, &relayer_id).await?;
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::UpdateRelayer, &relayer_id)
+                .before(before)
+                .after(after),
+        )
+        .await?;
 
         Ok(())
     }
@@ -158,360 +374,1768 @@ impl AdminApi {
     )]
     async fn purge_unsent_txs(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
         Path(relayer_id): Path<String>,
     ) -> Result<()> {
-        basic_auth.validate(app).await?;
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let mut tx = app.db.begin().await?;
 
-        app.db.purge_unsent_txs(&relayer_id).await?;
+        tx.purge_unsent_txs(&relayer_id).await?;
+
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::ResetRelayer, &relayer_id),
+        )
+        .await?;
 
         Ok(())
     }
 
-    /// Create Relayer API Key
+    /// Rotate Relayer Key
+    ///
+    /// Creates a new key for the relayer. Once all transactions of the current
+    /// key are mined its remaining balance is swept to the new key and the
+    /// relayer switches to it, new transactions are rejected until then.
     #[oai(
-        path = "/relayer/:relayer_id/key",
+        path = "/relayer/:relayer_id/rotate-key",
         method = "post",
-        operation_id = "relayer_create_api_key",
+        operation_id = "rotate_relayer_key",
         tag = "OpenAPITags::AdminV1"
     )]
-    async fn create_relayer_api_key(
+    async fn rotate_relayer_key(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
         Path(relayer_id): Path<String>,
-    ) -> Result<Json<CreateApiKeyResponse>> {
-        basic_auth.validate(app).await?;
+    ) -> Result<Json<RotateRelayerKeyResponse>> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
 
-        let api_key = ApiKey::random(&relayer_id);
+        let Some(relayer) = app.db.get_relayer(&relayer_id).await? else {
+            return Err(poem::error::Error::from_string(
+                "Relayer not found".to_string(),
+                StatusCode::NOT_FOUND,
+            ));
+        };
 
-        app.db
-            .upsert_api_key(&relayer_id, api_key.api_key_secret_hash())
-            .await?;
+        if app.db.get_key_rotation(&relayer_id).await?.is_some() {
+            return Err(key_rotation_in_progress_error());
+        }
+
+        if app.db.get_decommission(&relayer_id).await?.is_some() {
+            return Err(relayer_decommissioning_error());
+        }
+
+        let (key_id, signer) =
+            app.keys_source.new_signer(&relayer.name).await?;
+        let address = signer.address();
+
+        let mut tx = app.db.begin().await?;
+
+        if !tx
+            .create_key_rotation(&relayer_id, &key_id, address)
+            .await?
+        {
+            return Err(key_rotation_in_progress_error());
+        }
+
+        let response = RotateRelayerKeyResponse {
+            previous_address: relayer.address.0.into(),
+            address: address.into(),
+        };
+
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::RotateRelayerKey, &relayer_id)
+                .before(json!({ "address": response.previous_address }))
+                .after(json!({ "address": response.address })),
+        )
+        .await?;
+
+        tracing::info!(relayer_id, ?address, "Relayer key rotation started");
 
-        Ok(Json(CreateApiKeyResponse { api_key }))
+        Ok(Json(response))
     }
 
-    /// Create Network
+    /// Decommission Relayer
+    ///
+    /// The relayer leaves its pools and rejects new transactions. Once its
+    /// transactions are mined the remaining balance is swept to `sweepTo`,
+    /// its API keys are revoked and it is deleted. Transactions of deleted
+    /// relayers are kept.
     #[oai(
-        path = "/network/:chain_id",
+        path = "/relayer/:relayer_id/decommission",
         method = "post",
-        operation_id = "create_network",
+        operation_id = "decommission_relayer",
         tag = "OpenAPITags::AdminV1"
     )]
-    async fn create_network(
+    async fn decommission_relayer(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
-        Path(chain_id): Path<u64>,
-        Json(network): Json<CreateNetworkRequest>,
-    ) -> Result<()> {
-        basic_auth.validate(app).await?;
+        Path(relayer_id): Path<String>,
+        Json(req): Json<DecommissionRelayerRequest>,
+    ) -> Result<Json<RelayerDecommissionResponse>> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
 
-        let http_url: Url = network
-            .http_rpc
-            .parse::<Url>()
-            .map_err(poem::error::BadRequest)?;
+        if app.db.get_relayer(&relayer_id).await?.is_none() {
+            return Err(poem::error::Error::from_string(
+                "Relayer not found".to_string(),
+                StatusCode::NOT_FOUND,
+            ));
+        }
 
-        let ws_url: Url = network
-            .ws_rpc
-            .parse::<Url>()
-            .map_err(poem::error::BadRequest)?;
+        if app.db.get_key_rotation(&relayer_id).await?.is_some() {
+            return Err(key_rotation_in_progress_error());
+        }
 
-        app.db
-            .upsert_network(
-                chain_id,
-                &network.name,
-                http_url.as_str(),
-                ws_url.as_str(),
-            )
-            .await?;
+        let mut tx = app.db.begin().await?;
 
-        let task_runner = TaskRunner::new(app.clone());
-        Service::spawn_chain_tasks(&task_runner, chain_id)?;
+        if !tx.create_decommission(&relayer_id, req.sweep_to.0).await? {
+            return Err(relayer_decommissioning_error());
+        }
 
-        Ok(())
+        if req.cancel_unsent {
+            tx.purge_unsent_txs(&relayer_id).await?;
+        }
+
+        let decommission: RelayerDecommissionResponse = tx
+            .get_decommission(&relayer_id)
+            .await?
+            .context("Missing decommission")?
+            .into();
+
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::DecommissionRelayer, &relayer_id)
+                .after(&decommission),
+        )
+        .await?;
+
+        tracing::info!(
+            relayer_id,
+            sweep_to = ?req.sweep_to.0,
+            "Relayer decommission started"
+        );
+
+        Ok(Json(decommission))
     }
 
-    /// Get Networks
+    /// Get Relayer Decommission
     #[oai(
-        path = "/networks",
+        path = "/relayer/:relayer_id/decommission",
         method = "get",
-        operation_id = "get_networks",
+        operation_id = "get_relayer_decommission",
         tag = "OpenAPITags::AdminV1"
     )]
-    async fn list_networks(
+    async fn get_relayer_decommission(
         &self,
-        basic_auth: BasicAuth,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
-    ) -> Result<Json<Vec<NetworkResponse>>> {
-        basic_auth.validate(app).await?;
+        Path(relayer_id): Path<String>,
+    ) -> Result<Json<RelayerDecommissionResponse>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
 
-        let networks = app.db.get_networks().await?;
+        let Some(decommission) = app.db.get_decommission(&relayer_id).await?
+        else {
+            return Err(poem::error::Error::from_string(
+                "Relayer decommission not found".to_string(),
+                StatusCode::NOT_FOUND,
+            ));
+        };
 
-        Ok(Json(networks.into_iter().map(|v| v.into()).collect()))
+        Ok(Json(decommission.into()))
     }
-}
-
-struct RelayerApi;
 
-#[OpenApi(prefix_path = "/1/api/")]
-impl RelayerApi {
-    /// Send Transaction
+    /// Create Relayer API Key
     #[oai(
-        path = "/:api_token/tx",
+        path = "/relayer/:relayer_id/key",
         method = "post",
-        operation_id = "create_transaction",
-        tag = "OpenAPITags::RelayerV1"
+        operation_id = "relayer_create_api_key",
+        tag = "OpenAPITags::AdminV1"
     )]
-    async fn send_tx(
+    async fn create_relayer_api_key(
         &self,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
-        Path(api_token): Path<ApiKey>,
-        Json(req): Json<SendTxRequest>,
-    ) -> Result<Json<SendTxResponse>> {
-        api_token.validate(app).await?;
-
-        tracing::info!(?req, "Send tx");
-
-        let tx_id = if let Some(id) = req.tx_id {
-            id
-        } else {
-            uuid::Uuid::new_v4().to_string()
-        };
+        Path(relayer_id): Path<String>,
+        /// Label of the key
+        Query(name): Query<Option<String>>,
+        /// Operations allowed with the key, defaults to all of them
+        Query(scopes): Query<Option<Vec<ApiKeyScope>>>,
+        /// Unix timestamp in seconds after which the key is rejected
+        #[oai(name = "expiresAt")]
+        Query(expires_at): Query<Option<i64>>,
+    ) -> Result<Json<CreateApiKeyResponse>> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
 
-        let relayer = app
-            .db
-            .get_relayer(api_token.relayer_id())
-            .await?
-            .context("Missing relayer")?;
+        let scopes = parse_key_scopes(scopes)?;
+        let expires_at = parse_key_expiry(expires_at)?;
 
-        if !relayer.enabled {
+        if app.db.get_relayer(&relayer_id).await?.is_none() {
             return Err(poem::error::Error::from_string(
-                "Relayer is disabled".to_string(),
-                StatusCode::FORBIDDEN,
+                "Relayer not found".to_string(),
+                StatusCode::NOT_FOUND,
             ));
         }
 
-        let relayer_queued_tx_count = app
-            .db
-            .get_relayer_pending_txs(api_token.relayer_id())
-            .await?;
-
-        if relayer_queued_tx_count > relayer.max_queued_txs as usize {
-            return Err(poem::error::Error::from_string(
-                "Relayer queue is full".to_string(),
-                StatusCode::TOO_MANY_REQUESTS,
-            ));
+        if app.db.get_decommission(&relayer_id).await?.is_some() {
+            return Err(relayer_decommissioning_error());
         }
 
-        let relayer_queued_tx_gas_limit_sum = app
-            .db
-            .get_relayer_pending_txs_gas_limit_sum(api_token.relayer_id())
-            .await?;
-
-        let block_fees = app
-            .db
-            .get_latest_block_fees_by_chain_id(relayer.chain_id)
-            .await?;
-        if let Some(block_fees) = block_fees {
-            let gas_limit = relayer_queued_tx_gas_limit_sum + req.gas_limit.0;
-            let estimated_transactions_cost = block_fees.gas_price * gas_limit;
-
-            // TODO: Cache?
-            let http_provider: Provider<Http> =
-                app.http_provider(relayer.chain_id).await?;
-
-            let balance = http_provider
-                .get_balance(relayer.address.0, None)
-                .await
-                .map_err(|err| {
-                    eyre::eyre!("Error checking balance: {}", err)
-                })?;
-
-            if balance < estimated_transactions_cost {
-                return Err(poem::error::Error::from_string(
-                    "Relayer funds are insufficient for transaction to be mined.".to_string(),
-                    StatusCode::UNPROCESSABLE_ENTITY,
-                ));
-            }
-        }
+        let id = uuid::Uuid::new_v4().to_string();
+        let api_key = ApiKey::random(&relayer_id);
 
-        let res = app
-            .db
-            .create_transaction(
-                &tx_id,
-                req.to.0,
-                req.data.as_ref().map(|d| &d.0[..]).unwrap_or(&[]),
-                req.value.0,
-                req.gas_limit.0,
-                req.priority,
-                req.blobs,
-                api_token.relayer_id(),
-            )
-            .await?;
+        let mut tx = app.db.begin().await?;
 
-        if let CreateResult::CONFLICT = res {
-            return Err(poem::error::Error::from_response(
-                ErrorResponse::new(
-                    StatusCode::CONFLICT,
-                    "transaction_already_exists",
-                    "Transaction with same id already exists.",
-                )
-                .into_response(),
-            ));
-        }
+        tx.upsert_api_key(
+            &id,
+            &relayer_id,
+            name.as_deref(),
+            &scopes,
+            expires_at,
+            api_key.api_key_secret_hash(),
+        )
+        .await?;
 
-        tracing::info!(tx_id, "Transaction created");
+        audit_api_key_created(app, tx, &admin.name, &relayer_id, &id).await?;
 
-        Ok(Json(SendTxResponse { tx_id }))
+        Ok(Json(CreateApiKeyResponse { id, api_key }))
     }
 
-    /// Get Transaction
+    /// Get Relayer API Keys
     #[oai(
-        path = "/:api_token/tx/:tx_id",
+        path = "/relayer/:relayer_id/keys",
         method = "get",
-        operation_id = "get_transaction",
-        tag = "OpenAPITags::RelayerV1"
+        operation_id = "get_relayer_api_keys",
+        tag = "OpenAPITags::AdminV1"
     )]
-    async fn get_tx(
+    async fn get_relayer_api_keys(
         &self,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
-        Path(api_token): Path<ApiKey>,
-        Path(tx_id): Path<String>,
-    ) -> Result<Json<GetTxResponse>> {
-        api_token.validate(app).await?;
+        Path(relayer_id): Path<String>,
+    ) -> Result<Json<Vec<ApiKeyResponse>>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
 
-        let relayer_id = api_token.relayer_id();
+        let api_keys = app.db.get_api_keys(&relayer_id).await?;
 
-        let tx = app
-            .db
-            .read_relayer_tx(relayer_id, &tx_id)
-            .await?
-            .ok_or_else(|| {
-                poem::error::Error::from_string(
-                    "Transaction not found".to_string(),
-                    StatusCode::NOT_FOUND,
-                )
-            })?;
+        Ok(Json(api_keys.into_iter().map(|v| v.into()).collect()))
+    }
 
-        let get_tx_response = GetTxResponse {
-            tx_id: tx.tx_id,
-            to: tx.to.into(),
-            data: if tx.data.is_empty() {
-                None
-            } else {
-                Some(tx.data.into())
-            },
-            value: tx.value.into(),
-            gas_limit: tx.gas_limit.into(),
-            nonce: tx.nonce,
-            tx_hash: tx.tx_hash.map(|v| v.into()),
-            status: tx.status,
-        };
+    /// Revoke Relayer API Key
+    #[oai(
+        path = "/relayer/:relayer_id/key/:key_id",
+        method = "delete",
+        operation_id = "revoke_relayer_api_key",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn revoke_relayer_api_key(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(relayer_id): Path<String>,
+        Path(key_id): Path<String>,
+    ) -> Result<()> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
 
-        Ok(Json(get_tx_response))
+        revoke_api_key(app, &admin.name, &relayer_id, &key_id).await
     }
 
-    /// Get Transactions
+    /// Create Relayer Pool
+    ///
+    /// Groups relayers on the same chain under shared API keys
     #[oai(
-        path = "/:api_token/txs",
-        method = "get",
-        operation_id = "get_transactions",
-        tag = "OpenAPITags::RelayerV1"
+        path = "/pool",
+        method = "post",
+        operation_id = "create_relayer_pool",
+        tag = "OpenAPITags::AdminV1"
     )]
-    async fn get_txs(
+    async fn create_relayer_pool(
         &self,
+        admin_auth: AdminAuth,
         Data(app): Data<&Arc<App>>,
-        Path(api_token): Path<ApiKey>,
-        /// Optional tx status to filter by
-        Query(status): Query<Option<TxStatus>>,
-        /// Fetch unsent txs, overrides the status query
+        Json(req): Json<CreateRelayerPoolRequest>,
+    ) -> Result<Json<RelayerPoolResponse>> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        for relayer_id in &req.relayer_ids {
+            check_pool_member(app, req.chain_id, relayer_id).await?;
+        }
+
+        let pool_id = uuid::Uuid::new_v4().to_string();
+
+        let mut tx = app.db.begin().await?;
+
+        tx.create_relayer_pool(&pool_id, &req.name, req.chain_id)
+            .await?;
+
+        for relayer_id in &req.relayer_ids {
+            tx.add_relayer_pool_member(&pool_id, relayer_id).await?;
+        }
+
+        let pool: RelayerPoolResponse = tx
+            .get_relayer_pool(&pool_id)
+            .await?
+            .context("Missing pool")?
+            .into();
+
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::CreateRelayerPool, &pool_id)
+                .after(&pool),
+        )
+        .await?;
+
+        Ok(Json(pool))
+    }
+
+    /// Get Relayer Pools
+    #[oai(
+        path = "/pools",
+        method = "get",
+        operation_id = "get_relayer_pools",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn get_relayer_pools(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+    ) -> Result<Json<Vec<RelayerPoolResponse>>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
+
+        let pools = app.db.get_relayer_pools().await?;
+
+        Ok(Json(pools.into_iter().map(|v| v.into()).collect()))
+    }
+
+    /// Get Relayer Pool
+    #[oai(
+        path = "/pool/:pool_id",
+        method = "get",
+        operation_id = "get_relayer_pool",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn get_relayer_pool(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(pool_id): Path<String>,
+    ) -> Result<Json<RelayerPoolResponse>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
+
+        let pool = get_pool(app, &pool_id).await?;
+
+        Ok(Json(pool.into()))
+    }
+
+    /// Add Relayer Pool Member
+    #[oai(
+        path = "/pool/:pool_id/relayer/:relayer_id",
+        method = "put",
+        operation_id = "add_relayer_pool_member",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn add_relayer_pool_member(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(pool_id): Path<String>,
+        Path(relayer_id): Path<String>,
+    ) -> Result<()> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let pool = get_pool(app, &pool_id).await?;
+        check_pool_member(app, pool.chain_id, &relayer_id).await?;
+
+        let mut tx = app.db.begin().await?;
+
+        tx.add_relayer_pool_member(&pool_id, &relayer_id).await?;
+
+        let after = tx
+            .get_relayer_pool(&pool_id)
+            .await?
+            .context("Missing pool")?;
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::AddRelayerPoolMember, &pool_id)
+                .before(RelayerPoolResponse::from(pool))
+                .after(RelayerPoolResponse::from(after)),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Remove Relayer Pool Member
+    ///
+    /// Queued txs of the relayer are still sent, txs with route keys pinned
+    /// to it are routed to other members
+    #[oai(
+        path = "/pool/:pool_id/relayer/:relayer_id",
+        method = "delete",
+        operation_id = "remove_relayer_pool_member",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn remove_relayer_pool_member(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(pool_id): Path<String>,
+        Path(relayer_id): Path<String>,
+    ) -> Result<()> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let before = get_pool(app, &pool_id).await?;
+
+        let mut tx = app.db.begin().await?;
+
+        if !tx.remove_relayer_pool_member(&pool_id, &relayer_id).await? {
+            return Err(poem::error::Error::from_string(
+                "Relayer is not a member of the pool".to_string(),
+                StatusCode::NOT_FOUND,
+            ));
+        }
+
+        let after = tx
+            .get_relayer_pool(&pool_id)
+            .await?
+            .context("Missing pool")?;
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::RemoveRelayerPoolMember, &pool_id)
+                .before(RelayerPoolResponse::from(before))
+                .after(RelayerPoolResponse::from(after)),
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    /// Create Relayer Pool API Key
+    ///
+    /// Transactions sent with pool API keys are routed to pool members
+    #[oai(
+        path = "/pool/:pool_id/key",
+        method = "post",
+        operation_id = "create_relayer_pool_api_key",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn create_relayer_pool_api_key(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(pool_id): Path<String>,
+        /// Label of the key
+        Query(name): Query<Option<String>>,
+        /// Operations allowed with the key, defaults to all of them
+        Query(scopes): Query<Option<Vec<ApiKeyScope>>>,
+        /// Unix timestamp in seconds after which the key is rejected
+        #[oai(name = "expiresAt")]
+        Query(expires_at): Query<Option<i64>>,
+    ) -> Result<Json<CreateApiKeyResponse>> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let scopes = parse_key_scopes(scopes)?;
+        let expires_at = parse_key_expiry(expires_at)?;
+
+        get_pool(app, &pool_id).await?;
+
+        let id = uuid::Uuid::new_v4().to_string();
+        let api_key = ApiKey::random(&pool_id);
+
+        let mut tx = app.db.begin().await?;
+
+        tx.upsert_pool_api_key(
+            &id,
+            &pool_id,
+            name.as_deref(),
+            &scopes,
+            expires_at,
+            api_key.api_key_secret_hash(),
+        )
+        .await?;
+
+        audit_api_key_created(app, tx, &admin.name, &pool_id, &id).await?;
+
+        Ok(Json(CreateApiKeyResponse { id, api_key }))
+    }
+
+    /// Get Relayer Pool API Keys
+    #[oai(
+        path = "/pool/:pool_id/keys",
+        method = "get",
+        operation_id = "get_relayer_pool_api_keys",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn get_relayer_pool_api_keys(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(pool_id): Path<String>,
+    ) -> Result<Json<Vec<ApiKeyResponse>>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
+
+        let api_keys = app.db.get_api_keys(&pool_id).await?;
+
+        Ok(Json(api_keys.into_iter().map(|v| v.into()).collect()))
+    }
+
+    /// Revoke Relayer Pool API Key
+    #[oai(
+        path = "/pool/:pool_id/key/:key_id",
+        method = "delete",
+        operation_id = "revoke_relayer_pool_api_key",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn revoke_relayer_pool_api_key(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(pool_id): Path<String>,
+        Path(key_id): Path<String>,
+    ) -> Result<()> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        revoke_api_key(app, &admin.name, &pool_id, &key_id).await
+    }
+
+    /// Create Network
+    #[oai(
+        path = "/network/:chain_id",
+        method = "post",
+        operation_id = "create_network",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn create_network(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        Path(chain_id): Path<u64>,
+        Json(network): Json<CreateNetworkRequest>,
+    ) -> Result<()> {
+        let admin = admin_auth.validate(app, AdminRole::Operator).await?;
+
+        let http_url: Url = network
+            .http_rpc
+            .parse::<Url>()
+            .map_err(poem::error::BadRequest)?;
+
+        let ws_url: Url = network
+            .ws_rpc
+            .parse::<Url>()
+            .map_err(poem::error::BadRequest)?;
+
+        let mut tx = app.db.begin().await?;
+
+        let before = tx.get_network(chain_id).await?;
+
+        tx.upsert_network(
+            chain_id,
+            &network.name,
+            http_url.as_str(),
+            ws_url.as_str(),
+            network.low_balance.map(|v| v.0),
+        )
+        .await?;
+
+        let after = tx.get_network(chain_id).await?;
+        audit(
+            app,
+            tx,
+            &admin.name,
+            AuditEvent::new(AuditAction::CreateNetwork, chain_id)
+                .before(before.map(NetworkResponse::from))
+                .after(after.map(NetworkResponse::from)),
+        )
+        .await?;
+
+        let task_runner = TaskRunner::new(app.clone(), app.shutdown.clone());
+        Service::spawn_chain_tasks(app, &task_runner, chain_id)?;
+
+        Ok(())
+    }
+
+    /// Get Networks
+    #[oai(
+        path = "/networks",
+        method = "get",
+        operation_id = "get_networks",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    async fn list_networks(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+    ) -> Result<Json<Vec<NetworkResponse>>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
+
+        let networks = app.db.get_networks().await?;
+
+        Ok(Json(networks.into_iter().map(|v| v.into()).collect()))
+    }
+
+    /// Get Audit Log
+    ///
+    /// Admin and key material operations, newest first
+    #[oai(
+        path = "/audit-log",
+        method = "get",
+        operation_id = "get_audit_log",
+        tag = "OpenAPITags::AdminV1"
+    )]
+    #[allow(clippy::too_many_arguments)]
+    async fn get_audit_log(
+        &self,
+        admin_auth: AdminAuth,
+        Data(app): Data<&Arc<App>>,
+        /// Admin name or `api_key:<id>`
+        Query(actor): Query<Option<String>>,
+        /// Action, e.g. `relayer.update`
+        Query(action): Query<Option<String>>,
+        /// Id of the relayer, pool, API key or network
+        Query(target): Query<Option<String>>,
+        /// Unix timestamp in seconds of the oldest entry
+        Query(since): Query<Option<i64>>,
+        /// Unix timestamp in seconds after the newest entry
+        Query(until): Query<Option<i64>>,
+        /// Maximum number of entries, defaults to 100
+        #[oai(default = "default_audit_log_limit")]
+        Query(limit): Query<u32>,
+    ) -> Result<Json<Vec<AuditLogEntryResponse>>> {
+        admin_auth.validate(app, AdminRole::ReadOnly).await?;
+
+        let entries = app
+            .db
+            .get_audit_entries(
+                actor.as_deref(),
+                action.as_deref(),
+                target.as_deref(),
+                parse_audit_timestamp(since)?,
+                parse_audit_timestamp(until)?,
+                limit.min(MAX_AUDIT_LOG_LIMIT),
+            )
+            .await?;
+
+        Ok(Json(entries.into_iter().map(|v| v.into()).collect()))
+    }
+}
+
+struct RelayerApi;
+
+#[OpenApi(prefix_path = "/1/api/")]
+impl RelayerApi {
+    /// Send Transaction
+    #[oai(
+        path = "/tx",
+        method = "post",
+        operation_id = "create_transaction",
+        tag = "OpenAPITags::RelayerV1"
+    )]
+    async fn send_tx(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        BearerAuth(api_token): BearerAuth,
+        Json(req): Json<SendTxRequest>,
+    ) -> Result<Json<SendTxResponse>> {
+        create_tx(app, &api_token, req, None).await
+    }
+
+    /// Get Transaction
+    #[oai(
+        path = "/tx/:tx_id",
+        method = "get",
+        operation_id = "get_transaction",
+        tag = "OpenAPITags::RelayerV1"
+    )]
+    async fn get_tx(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        BearerAuth(api_token): BearerAuth,
+        Path(tx_id): Path<String>,
+    ) -> Result<Json<GetTxResponse>> {
+        api_token.validate(app, ApiKeyScope::Read).await?;
+
+        let relayer_id = api_token.relayer_id();
+
+        let tx = app
+            .db
+            .read_relayer_tx(relayer_id, &tx_id)
+            .await?
+            .ok_or_else(|| {
+                poem::error::Error::from_string(
+                    "Transaction not found".to_string(),
+                    StatusCode::NOT_FOUND,
+                )
+            })?;
+
+        let get_tx_response = GetTxResponse {
+            tx_id: tx.tx_id,
+            to: tx.to.map(|v| v.into()),
+            data: if tx.data.is_empty() {
+                None
+            } else {
+                Some(tx.data.into())
+            },
+            value: tx.value.into(),
+            gas_limit: tx.gas_limit.into(),
+            nonce: tx.nonce,
+            tx_hash: tx.tx_hash.map(|v| v.into()),
+            status: tx.status,
+            contract_address: tx.contract_address.map(|v| v.into()),
+            call: tx.call.map(|call| call.0.into()),
+        };
+
+        Ok(Json(get_tx_response))
+    }
+
+    /// Get Transactions
+    #[oai(
+        path = "/txs",
+        method = "get",
+        operation_id = "get_transactions",
+        tag = "OpenAPITags::RelayerV1"
+    )]
+    async fn get_txs(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        BearerAuth(api_token): BearerAuth,
+        /// Optional tx status to filter by
+        Query(status): Query<Option<TxStatus>>,
+        /// Fetch unsent txs, overrides the status query
         #[oai(default = "default_false")]
         Query(unsent): Query<bool>,
     ) -> Result<Json<Vec<GetTxResponse>>> {
-        api_token.validate(app).await?;
+        api_token.validate(app, ApiKeyScope::Read).await?;
+
+        let txs = if unsent {
+            app.db
+                .read_relayer_txs(api_token.relayer_id(), Some(None))
+                .await?
+        } else if let Some(status) = status {
+            app.db
+                .read_relayer_txs(api_token.relayer_id(), Some(Some(status)))
+                .await?
+        } else {
+            app.db
+                .read_relayer_txs(api_token.relayer_id(), None)
+                .await?
+        };
+
+        let txs = txs
+            .into_iter()
+            .map(|tx| GetTxResponse {
+                tx_id: tx.tx_id,
+                to: tx.to.map(|v| v.into()),
+                data: if tx.data.is_empty() {
+                    None
+                } else {
+                    Some(tx.data.into())
+                },
+                value: tx.value.into(),
+                gas_limit: tx.gas_limit.into(),
+                nonce: tx.nonce,
+                tx_hash: tx.tx_hash.map(|v| v.into()),
+                status: tx.status,
+                contract_address: tx.contract_address.map(|v| v.into()),
+                call: tx.call.map(|call| call.0.into()),
+            })
+            .collect();
+
+        Ok(Json(txs))
+    }
+
+    /// Sign Message
+    ///
+    /// Signs an EIP-191 personal message, signing must be enabled for the relayer
+    #[oai(
+        path = "/sign/message",
+        method = "post",
+        operation_id = "sign_message",
+        tag = "OpenAPITags::RelayerV1"
+    )]
+    async fn sign_message(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        BearerAuth(api_token): BearerAuth,
+        Json(req): Json<SignMessageRequest>,
+    ) -> Result<Json<SignatureResponse>> {
+        let key_info = api_token.validate(app, ApiKeyScope::Sign).await?;
+
+        let relayer = get_signing_relayer(app, api_token.relayer_id()).await?;
+
+        let signer = app.keys_source.load_signer(relayer.key_id).await?;
+
+        let message = &req.message.0[..];
+        let hash = hash_message(message);
+        let signature = signer
+            .sign_message(message)
+            .await
+            .map_err(|err| eyre::eyre!("Error signing message: {}", err))?;
+
+        let signature = signature.to_vec();
+
+        let mut tx = app.db.begin().await?;
+
+        tx.save_signature(
+            &relayer.id,
+            SignatureKind::Message,
+            message,
+            hash,
+            &signature,
+        )
+        .await?;
+
+        audit(
+            app,
+            tx,
+            &api_key_actor(&key_info),
+            AuditEvent::new(AuditAction::SignMessage, &relayer.id)
+                .after(json!({ "hash": hash })),
+        )
+        .await?;
+
+        tracing::info!(relayer_id = relayer.id, ?hash, "Message signed");
+
+        Ok(Json(SignatureResponse {
+            hash: base_api_types::H256(hash),
+            signature: signature.into(),
+        }))
+    }
+
+    /// Sign Typed Data
+    ///
+    /// Signs EIP-712 typed data, signing must be enabled for the relayer
+    #[oai(
+        path = "/sign/typed-data",
+        method = "post",
+        operation_id = "sign_typed_data",
+        tag = "OpenAPITags::RelayerV1"
+    )]
+    async fn sign_typed_data(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        BearerAuth(api_token): BearerAuth,
+        Json(req): Json<SignTypedDataRequest>,
+    ) -> Result<Json<SignatureResponse>> {
+        let key_info = api_token.validate(app, ApiKeyScope::Sign).await?;
+
+        let relayer = get_signing_relayer(app, api_token.relayer_id()).await?;
+
+        let invalid_typed_data = |err: String| {
+            poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_typed_data",
+                    format!("Invalid typed data: {err}"),
+                )
+                .into_response(),
+            )
+        };
+
+        let typed_data: TypedData =
+            serde_json::from_value(req.typed_data.clone())
+                .map_err(|err| invalid_typed_data(err.to_string()))?;
+        let hash = typed_data
+            .encode_eip712()
+            .map_err(|err| invalid_typed_data(err.to_string()))?;
+
+        let signer = app.keys_source.load_signer(relayer.key_id).await?;
+
+        let signature = signer
+            .sign_typed_data_json(&typed_data)
+            .await
+            .map_err(|err| eyre::eyre!("Error signing typed data: {}", err))?;
+
+        let signature = signature.to_vec();
+        let hash = ethers::types::H256(hash);
+
+        let payload = serde_json::to_vec(&req.typed_data).map_err(|err| {
+            eyre::eyre!("Error serializing typed data: {}", err)
+        })?;
+
+        let mut tx = app.db.begin().await?;
+
+        tx.save_signature(
+            &relayer.id,
+            SignatureKind::TypedData,
+            &payload,
+            hash,
+            &signature,
+        )
+        .await?;
+
+        audit(
+            app,
+            tx,
+            &api_key_actor(&key_info),
+            AuditEvent::new(AuditAction::SignTypedData, &relayer.id)
+                .after(json!({ "hash": hash })),
+        )
+        .await?;
+
+        tracing::info!(relayer_id = relayer.id, ?hash, "Typed data signed");
+
+        Ok(Json(SignatureResponse {
+            hash: base_api_types::H256(hash),
+            signature: signature.into(),
+        }))
+    }
+
+    /// Relayer RPC
+    #[oai(
+        path = "/rpc",
+        method = "post",
+        operation_id = "call_rpc",
+        tag = "OpenAPITags::RelayerV1"
+    )]
+    async fn relayer_rpc(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        BearerAuth(api_token): BearerAuth,
+        Json(req): Json<RpcPayload>,
+    ) -> Result<Json<Value>> {
+        api_token.validate(app, ApiKeyScope::Rpc).await?;
+
+        let chain_id = match app.db.get_relayer(api_token.relayer_id()).await? {
+            Some(relayer) => relayer.chain_id,
+            None => {
+                app.db
+                    .get_relayer_pool(api_token.relayer_id())
+                    .await?
+                    .context("Missing relayer")?
+                    .chain_id
+            }
+        };
+
+        let requests = match &req {
+            RpcPayload::Single(req) => std::slice::from_ref(req),
+            RpcPayload::Batch(requests) => requests.as_slice(),
+        };
+
+        let max_batch_size = app.rpc_proxy.max_batch_size();
+        if requests.is_empty() || requests.len() > max_batch_size {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_batch",
+                    format!(
+                        "Batches must hold between 1 and {max_batch_size} requests"
+                    ),
+                )
+                .into_response(),
+            ));
+        }
+
+        // Signer methods are served by tx-sitter, unless denied
+        let mut responses = Vec::with_capacity(requests.len());
+        let mut forwarded = vec![];
+        for (idx, req) in requests.iter().enumerate() {
+            if !app.rpc_proxy.is_allowed(&req.method) {
+                responses.push(Value::Null);
+                forwarded.push((idx, req.clone()));
+                continue;
+            }
+
+            let call =
+                signer_rpc::call(app, &api_token, chain_id, req.clone()).await;
+
+            match call {
+                Ok(signer_rpc::SignerCall::Handled(response)) => {
+                    responses.push(response)
+                }
+                Ok(signer_rpc::SignerCall::Forward(req)) => {
+                    responses.push(Value::Null);
+                    forwarded.push((idx, req));
+                }
+                Err(err) => responses
+                    .push(signer_rpc::error_response(req.id, err).await),
+            }
+        }
+
+        if !forwarded.is_empty() {
+            let (indices, forwarded): (Vec<_>, Vec<_>) =
+                forwarded.into_iter().unzip();
+
+            let url = app.db.get_network_rpc(chain_id, RpcKind::Http).await?;
+            let proxied =
+                app.rpc_proxy.call(chain_id, &url, &forwarded).await?;
+
+            for (idx, response) in indices.into_iter().zip(proxied) {
+                responses[idx] = response;
+            }
+        }
+
+        let response = match req {
+            RpcPayload::Single(_) => responses.remove(0),
+            RpcPayload::Batch(_) => Value::Array(responses),
+        };
+
+        Ok(Json(response))
+    }
+}
+
+/// Relayer API with the API key in the path
+///
+/// Kept for compatibility with clients predating the `Authorization` header,
+/// served only while `server.path_api_keys` is enabled.
+struct PathRelayerApi;
+
+#[OpenApi(prefix_path = "/1/api/")]
+impl PathRelayerApi {
+    #[oai(path = "/:api_token/tx", method = "post", hidden)]
+    async fn send_tx(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        Path(api_token): Path<ApiKey>,
+        req: Json<SendTxRequest>,
+    ) -> Result<Json<SendTxResponse>> {
+        check_path_api_keys(app)?;
+
+        RelayerApi
+            .send_tx(Data(app), BearerAuth(api_token), req)
+            .await
+    }
+
+    #[oai(path = "/:api_token/tx/:tx_id", method = "get", hidden)]
+    async fn get_tx(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        Path(api_token): Path<ApiKey>,
+        tx_id: Path<String>,
+    ) -> Result<Json<GetTxResponse>> {
+        check_path_api_keys(app)?;
+
+        RelayerApi
+            .get_tx(Data(app), BearerAuth(api_token), tx_id)
+            .await
+    }
+
+    #[oai(path = "/:api_token/txs", method = "get", hidden)]
+    async fn get_txs(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        Path(api_token): Path<ApiKey>,
+        status: Query<Option<TxStatus>>,
+        #[oai(default = "default_false")] unsent: Query<bool>,
+    ) -> Result<Json<Vec<GetTxResponse>>> {
+        check_path_api_keys(app)?;
 
-        let txs = if unsent {
-            app.db
-                .read_relayer_txs(api_token.relayer_id(), Some(None))
-                .await?
-        } else if let Some(status) = status {
-            app.db
-                .read_relayer_txs(api_token.relayer_id(), Some(Some(status)))
-                .await?
-        } else {
-            app.db
-                .read_relayer_txs(api_token.relayer_id(), None)
-                .await?
-        };
+        RelayerApi
+            .get_txs(Data(app), BearerAuth(api_token), status, unsent)
+            .await
+    }
 
-        let txs = txs
-            .into_iter()
-            .map(|tx| GetTxResponse {
-                tx_id: tx.tx_id,
-                to: tx.to.into(),
-                data: if tx.data.is_empty() {
-                    None
-                } else {
-                    Some(tx.data.into())
-                },
-                value: tx.value.into(),
-                gas_limit: tx.gas_limit.into(),
-                nonce: tx.nonce,
-                tx_hash: tx.tx_hash.map(|v| v.into()),
-                status: tx.status,
-            })
-            .collect();
+    #[oai(path = "/:api_token/sign/message", method = "post", hidden)]
+    async fn sign_message(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        Path(api_token): Path<ApiKey>,
+        req: Json<SignMessageRequest>,
+    ) -> Result<Json<SignatureResponse>> {
+        check_path_api_keys(app)?;
 
-        Ok(Json(txs))
+        RelayerApi
+            .sign_message(Data(app), BearerAuth(api_token), req)
+            .await
     }
 
-    /// Relayer RPC
-    #[oai(
-        path = "/:api_token/rpc",
-        method = "post",
-        operation_id = "call_rpc",
-        tag = "OpenAPITags::RelayerV1"
-    )]
+    #[oai(path = "/:api_token/sign/typed-data", method = "post", hidden)]
+    async fn sign_typed_data(
+        &self,
+        Data(app): Data<&Arc<App>>,
+        Path(api_token): Path<ApiKey>,
+        req: Json<SignTypedDataRequest>,
+    ) -> Result<Json<SignatureResponse>> {
+        check_path_api_keys(app)?;
+
+        RelayerApi
+            .sign_typed_data(Data(app), BearerAuth(api_token), req)
+            .await
+    }
+
+    #[oai(path = "/:api_token/rpc", method = "post", hidden)]
     async fn relayer_rpc(
         &self,
         Data(app): Data<&Arc<App>>,
         Path(api_token): Path<ApiKey>,
-        Json(req): Json<RpcRequest>,
+        req: Json<RpcPayload>,
     ) -> Result<Json<Value>> {
-        api_token.validate(app).await?;
+        check_path_api_keys(app)?;
+
+        RelayerApi
+            .relayer_rpc(Data(app), BearerAuth(api_token), req)
+            .await
+    }
+}
+
+fn check_path_api_keys(app: &App) -> Result<()> {
+    if !app.config.server.path_api_keys {
+        return Err(poem::error::NotFoundError.into());
+    }
+
+    Ok(())
+}
+
+fn default_false() -> bool {
+    false
+}
+
+const MAX_AUDIT_LOG_LIMIT: u32 = 1000;
+
+fn default_audit_log_limit() -> u32 {
+    100
+}
+
+fn parse_audit_timestamp(
+    timestamp: Option<i64>,
+) -> Result<Option<DateTime<Utc>>> {
+    timestamp
+        .map(|timestamp| {
+            DateTime::from_timestamp(timestamp, 0).ok_or_else(|| {
+                poem::error::Error::from_string(
+                    "Invalid timestamp".to_string(),
+                    StatusCode::BAD_REQUEST,
+                )
+            })
+        })
+        .transpose()
+}
 
-        let relayer_info = app
+/// Queues a tx of the relayer or pool of the API key
+///
+/// `from` pins the tx of a pool API key to the member with that address.
+async fn create_tx(
+    app: &Arc<App>,
+    api_token: &ApiKey,
+    req: SendTxRequest,
+    from: Option<Address>,
+) -> Result<Json<SendTxResponse>> {
+    api_token.validate(app, ApiKeyScope::Send).await?;
+
+    tracing::info!(?req, "Send tx");
+
+    let tx_id = if let Some(id) = req.tx_id.clone() {
+        id
+    } else {
+        uuid::Uuid::new_v4().to_string()
+    };
+
+    let pool = app.db.get_relayer_pool(api_token.relayer_id()).await?;
+
+    let relayer = match &pool {
+        Some(pool) => routing::route_tx(app, pool, &req, from).await?,
+        None => app
             .db
             .get_relayer(api_token.relayer_id())
             .await?
-            .context("Missing relayer")?;
+            .context("Missing relayer")?,
+    };
+
+    // Decommissioned relayers are disabled as well
+    if app.db.get_decommission(&relayer.id).await?.is_some() {
+        return Err(relayer_decommissioning_error());
+    }
+
+    if !relayer.enabled {
+        return Err(poem::error::Error::from_string(
+            "Relayer is disabled".to_string(),
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    if app.db.get_key_rotation(&relayer.id).await?.is_some() {
+        return Err(key_rotation_in_progress_error());
+    }
+
+    if req.to.is_none() && req.blobs.is_some() {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "invalid_transaction",
+                "Blob transactions cannot create contracts.",
+            )
+            .into_response(),
+        ));
+    }
+
+    let (data, call) = match &req.call {
+        Some(_) if req.data.is_some() => {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_call",
+                    "Only one of `data` or `call` can be provided.",
+                )
+                .into_response(),
+            ));
+        }
+        Some(call) => {
+            let (data, call) = calldata::encode_call(call).map_err(|err| {
+                poem::error::Error::from_response(
+                    ErrorResponse::new(
+                        StatusCode::BAD_REQUEST,
+                        "invalid_call",
+                        err.to_string(),
+                    )
+                    .into_response(),
+                )
+            })?;
+
+            (data, Some(call))
+        }
+        None => (
+            req.data.as_ref().map(|d| d.0.to_vec()).unwrap_or_default(),
+            None,
+        ),
+    };
+
+    let block_fees = app
+        .db
+        .get_latest_block_fees_by_chain_id(relayer.chain_id)
+        .await?;
+
+    check_new_tx(
+        app,
+        &relayer,
+        req.to.as_ref().map(|to| to.0),
+        &data,
+        req.value.0,
+        req.gas_limit.0,
+        block_fees
+            .as_ref()
+            .map(|fees| fees.gas_price)
+            .unwrap_or_default(),
+    )
+    .await
+    .map_err(tx_rejection_error)?;
+
+    let relayer_queued_tx_gas_limit_sum = app
+        .db
+        .get_relayer_pending_txs_gas_limit_sum(&relayer.id)
+        .await?;
+
+    if let Some(block_fees) = block_fees {
+        let gas_limit = relayer_queued_tx_gas_limit_sum + req.gas_limit.0;
+        let estimated_transactions_cost = block_fees.gas_price * gas_limit;
 
         // TODO: Cache?
-        let http_provider = app.http_provider(relayer_info.chain_id).await?;
-        let url = http_provider.url();
+        let http_provider: Provider<Http> =
+            app.http_provider(relayer.chain_id).await?;
 
-        let response = reqwest::Client::new()
-            .post(url.clone())
-            .json(&req)
-            .send()
+        let balance = http_provider
+            .get_balance(relayer.address.0, None)
             .await
-            .map_err(|err| {
-                eyre::eyre!("Error sending request to {}: {}", url, err)
-            })?;
+            .map_err(|err| eyre::eyre!("Error checking balance: {}", err))?;
+
+        if balance < estimated_transactions_cost {
+            return Err(poem::error::Error::from_string(
+                "Relayer funds are insufficient for transaction to be mined."
+                    .to_string(),
+                StatusCode::UNPROCESSABLE_ENTITY,
+            ));
+        }
+    }
+
+    let res = app
+        .db
+        .create_transaction(
+            &tx_id,
+            req.to.as_ref().map(|to| to.0),
+            &data,
+            req.value.0,
+            req.gas_limit.0,
+            req.priority,
+            req.blobs,
+            call.as_ref(),
+            &relayer.id,
+            pool.as_ref().map(|pool| pool.id.as_str()),
+            pool.as_ref().and(req.route_key.as_deref()),
+        )
+        .await?;
+
+    let nonce = match res {
+        CreateResult::SUCCESS { nonce } => nonce,
+        CreateResult::CONFLICT => {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::CONFLICT,
+                    "transaction_already_exists",
+                    "Transaction with same id already exists.",
+                )
+                .into_response(),
+            ));
+        }
+        CreateResult::ROTATING => return Err(key_rotation_in_progress_error()),
+        CreateResult::DECOMMISSIONING => {
+            return Err(relayer_decommissioning_error())
+        }
+    };
+
+    tracing::info!(tx_id, "Transaction created");
+
+    let contract_address = if req.to.is_none() {
+        Some(get_contract_address(relayer.address.0, nonce).into())
+    } else {
+        None
+    };
+
+    Ok(Json(SendTxResponse {
+        tx_id,
+        contract_address,
+        relayer_id: pool.map(|_| relayer.id),
+    }))
+}
+
+async fn get_signing_relayer(
+    app: &App,
+    relayer_id: &str,
+) -> Result<RelayerInfo> {
+    let Some(relayer) = app.db.get_relayer(relayer_id).await? else {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                "signing_disabled",
+                "Signing is not supported with pool API keys.",
+            )
+            .into_response(),
+        ));
+    };
+
+    if app.db.get_decommission(&relayer.id).await?.is_some() {
+        return Err(relayer_decommissioning_error());
+    }
+
+    if !relayer.enabled {
+        return Err(poem::error::Error::from_string(
+            "Relayer is disabled".to_string(),
+            StatusCode::FORBIDDEN,
+        ));
+    }
+
+    if !relayer.signing_enabled {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::FORBIDDEN,
+                "signing_disabled",
+                "Signing is not enabled for this relayer.",
+            )
+            .into_response(),
+        ));
+    }
+
+    Ok(relayer)
+}
+
+/// Checks the balance thresholds and treasury of a relayer
+async fn check_funding(
+    app: &App,
+    relayer_id: &str,
+    funding: &RelayerFundingResponse,
+) -> Result<()> {
+    let invalid_funding = |message: &str| {
+        poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "invalid_funding",
+                message,
+            )
+            .into_response(),
+        )
+    };
+
+    let relayer = app.db.get_relayer(relayer_id).await?.ok_or_else(|| {
+        poem::error::Error::from_string(
+            "Relayer not found".to_string(),
+            StatusCode::NOT_FOUND,
+        )
+    })?;
+
+    let network_low_balance = app
+        .db
+        .get_network(relayer.chain_id)
+        .await?
+        .and_then(|network| network.low_balance)
+        .map(|v| v.0);
+
+    let low_balance = funding
+        .low_balance
+        .as_ref()
+        .map(|v| v.0)
+        .or(network_low_balance)
+        .unwrap_or_else(|| U256::from(DEFAULT_LOW_BALANCE));
+
+    if let Some(target_balance) = &funding.target_balance {
+        if target_balance.0 <= low_balance {
+            return Err(invalid_funding(
+                "The target balance must be above the low balance.",
+            ));
+        }
+    }
+
+    let Some(treasury_relayer_id) = &funding.treasury_relayer_id else {
+        return Ok(());
+    };
+
+    if treasury_relayer_id == relayer_id {
+        return Err(invalid_funding("A relayer cannot be its own treasury."));
+    }
+
+    if funding.target_balance.is_none() {
+        return Err(invalid_funding(
+            "A target balance is required for top-ups.",
+        ));
+    }
+
+    let Some(treasury) = app.db.get_relayer(treasury_relayer_id).await? else {
+        return Err(invalid_funding("Treasury relayer not found."));
+    };
+
+    if treasury.chain_id != relayer.chain_id {
+        return Err(invalid_funding(
+            "The treasury relayer must be on the chain of the relayer.",
+        ));
+    }
+
+    Ok(())
+}
+
+async fn get_pool(app: &App, pool_id: &str) -> Result<RelayerPool> {
+    app.db.get_relayer_pool(pool_id).await?.ok_or_else(|| {
+        poem::error::Error::from_string(
+            "Relayer pool not found".to_string(),
+            StatusCode::NOT_FOUND,
+        )
+    })
+}
+
+/// Checks that a relayer can join a pool on the given chain
+async fn check_pool_member(
+    app: &App,
+    chain_id: u64,
+    relayer_id: &str,
+) -> Result<()> {
+    let Some(relayer) = app.db.get_relayer(relayer_id).await? else {
+        return Err(poem::error::Error::from_string(
+            "Relayer not found".to_string(),
+            StatusCode::NOT_FOUND,
+        ));
+    };
+
+    if relayer.chain_id != chain_id {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "chain_mismatch",
+                "Pool members must be on the chain of the pool.",
+            )
+            .into_response(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn invalid_key_error(message: impl Into<String>) -> poem::Error {
+    poem::error::Error::from_response(
+        ErrorResponse::new(StatusCode::BAD_REQUEST, "invalid_key", message)
+            .into_response(),
+    )
+}
+
+/// Stores an imported private key, only supported with local keys
+async fn import_signing_key(
+    app: &App,
+    req: &ImportRelayerRequest,
+    signing_key: SigningKey,
+) -> Result<(String, Address)> {
+    if !app.config.keys.is_local() {
+        return Err(invalid_key_error(
+            "Private keys can only be imported with local keys",
+        ));
+    }
+
+    // Validate the address before the key gets stored
+    let address = Wallet::from(signing_key.clone()).address();
+    check_imported_address(app, req, address).await?;
+
+    let (key_id, _) = app.keys_source.import_private_key(signing_key).await?;
+
+    Ok((key_id, address))
+}
+
+/// Checks the address of an imported key against the expected address
+/// and existing relayers
+async fn check_imported_address(
+    app: &App,
+    req: &ImportRelayerRequest,
+    address: Address,
+) -> Result<()> {
+    if let Some(expected) = &req.address {
+        if expected.0 != address {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "address_mismatch",
+                    format!(
+                        "Key address {address:?} does not match the expected address {:?}",
+                        expected.0
+                    ),
+                )
+                .into_response(),
+            ));
+        }
+    }
+
+    // Deleted relayers, pending rotations and retired keys may still own
+    // nonces of the address on this chain
+    let used_addresses = app.db.get_used_addresses(Some(req.chain_id)).await?;
+
+    if used_addresses.contains(&address) {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::CONFLICT,
+                "relayer_already_exists",
+                format!("Address {address:?} is already used by a relayer"),
+            )
+            .into_response(),
+        ));
+    }
 
-        let response: Value = response.json().await.map_err(|err| {
-            eyre::eyre!("Error parsing response from {}: {}", url, err)
+    Ok(())
+}
+
+fn budget_violation_error(violation: BudgetViolation) -> poem::Error {
+    poem::error::Error::from_response(
+        ErrorResponse::new(
+            violation.status(),
+            violation.error_id(),
+            violation.to_string(),
+        )
+        .into_response(),
+    )
+}
+
+fn tx_rejection_error(rejection: TxRejection) -> poem::Error {
+    match rejection {
+        TxRejection::Policy(violation) => policy_violation_error(violation),
+        TxRejection::QueueFull => poem::error::Error::from_string(
+            rejection.to_string(),
+            StatusCode::TOO_MANY_REQUESTS,
+        ),
+        TxRejection::Budget(violation) => budget_violation_error(violation),
+        TxRejection::Other(err) => err.into(),
+    }
+}
+
+fn key_rotation_in_progress_error() -> poem::Error {
+    poem::error::Error::from_response(
+        ErrorResponse::new(
+            StatusCode::CONFLICT,
+            "key_rotation_in_progress",
+            "The relayer key is being rotated.",
+        )
+        .into_response(),
+    )
+}
+
+fn parse_key_scopes(
+    scopes: Option<Vec<ApiKeyScope>>,
+) -> Result<Vec<ApiKeyScope>> {
+    let Some(scopes) = scopes else {
+        return Ok(ApiKeyScope::ALL.to_vec());
+    };
+
+    if scopes.is_empty() {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "invalid_scopes",
+                "An API key needs at least one scope.",
+            )
+            .into_response(),
+        ));
+    }
+
+    Ok(scopes)
+}
+
+/// Checks that the expiry of a new API key is in the future
+fn parse_key_expiry(expires_at: Option<i64>) -> Result<Option<DateTime<Utc>>> {
+    let Some(expires_at) = expires_at else {
+        return Ok(None);
+    };
+
+    let expires_at = DateTime::from_timestamp(expires_at, 0)
+        .filter(|expires_at| *expires_at > Utc::now())
+        .ok_or_else(|| {
+            poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_expiry",
+                    "The key expiry must be in the future.",
+                )
+                .into_response(),
+            )
         })?;
 
-        Ok(Json(response))
+    Ok(Some(expires_at))
+}
+
+async fn revoke_api_key(
+    app: &App,
+    admin: &str,
+    owner_id: &str,
+    key_id: &str,
+) -> Result<()> {
+    let mut tx = app.db.begin().await?;
+
+    let before = tx
+        .get_api_keys(owner_id)
+        .await?
+        .into_iter()
+        .find(|key| key.id == key_id);
+
+    if !tx.delete_api_key(owner_id, key_id).await? {
+        return Err(poem::error::Error::from_string(
+            "API key not found".to_string(),
+            StatusCode::NOT_FOUND,
+        ));
     }
+
+    audit(
+        app,
+        tx,
+        admin,
+        AuditEvent::new(AuditAction::RevokeApiKey, key_id)
+            .before(before.map(|key| api_key_state(owner_id, key))),
+    )
+    .await?;
+
+    tracing::info!(owner_id, key_id, "API key revoked");
+
+    Ok(())
 }
 
-fn default_false() -> bool {
-    false
+/// Records an audit log entry of the current request in the transaction of
+/// the change and commits it
+async fn audit(
+    app: &App,
+    tx: DatabaseTx,
+    actor: &str,
+    event: AuditEvent,
+) -> Result<()> {
+    app.audit_log
+        .record(tx, actor, trace_middleware::request_id().as_deref(), event)
+        .await?;
+
+    Ok(())
+}
+
+fn api_key_actor(key_info: &ApiKeyInfo) -> String {
+    format!("api_key:{}", key_info.id)
+}
+
+/// Audited state of an API key, the key itself is never part of it
+fn api_key_state(owner_id: &str, key_info: ApiKeyInfo) -> Value {
+    json!({
+        "ownerId": owner_id,
+        "key": ApiKeyResponse::from(key_info),
+    })
+}
+
+async fn audit_api_key_created(
+    app: &App,
+    mut tx: DatabaseTx,
+    admin: &str,
+    owner_id: &str,
+    key_id: &str,
+) -> Result<()> {
+    let after = tx
+        .get_api_keys(owner_id)
+        .await?
+        .into_iter()
+        .find(|key| key.id == key_id)
+        .map(|key| api_key_state(owner_id, key));
+
+    audit(
+        app,
+        tx,
+        admin,
+        AuditEvent::new(AuditAction::CreateApiKey, key_id).after(after),
+    )
+    .await
+}
+
+/// Relayer as returned by the API, without balances
+async fn get_relayer_state(
+    tx: &mut DatabaseTx,
+    relayer_id: &str,
+) -> Result<Option<RelayerResponse>> {
+    Ok(tx.get_relayer(relayer_id).await?.map(RelayerResponse::from))
+}
+
+fn check_rate_limits(rate_limits: &RelayerRateLimitsResponse) -> Result<()> {
+    let limits = [
+        rate_limits.send,
+        rate_limits.read,
+        rate_limits.rpc,
+        rate_limits.sign,
+    ];
+
+    for limit in limits.into_iter().flatten() {
+        crate::rate_limit::validate_rate_limit(&RateLimit {
+            requests_per_minute: limit.requests_per_minute,
+            burst: limit.burst,
+        })
+        .map_err(|err| {
+            poem::error::Error::from_response(
+                ErrorResponse::new(
+                    StatusCode::BAD_REQUEST,
+                    "invalid_rate_limit",
+                    format!("Invalid rate limit: {err}."),
+                )
+                .into_response(),
+            )
+        })?;
+    }
+
+    Ok(())
+}
+
+fn relayer_decommissioning_error() -> poem::Error {
+    poem::error::Error::from_response(
+        ErrorResponse::new(
+            StatusCode::CONFLICT,
+            "relayer_decommissioning",
+            "The relayer is being decommissioned.",
+        )
+        .into_response(),
+    )
+}
+
+fn policy_violation_error(violation: PolicyViolation) -> poem::Error {
+    poem::error::Error::from_response(
+        ErrorResponse::new(
+            StatusCode::FORBIDDEN,
+            violation.error_id(),
+            violation.to_string(),
+        )
+        .into_response(),
+    )
 }
 
 struct ServiceApi;
@@ -534,6 +2158,23 @@ impl ServiceApi {
     async fn health(&self) -> ServiceResponse {
         ServiceResponse::Healthy
     }
+
+    /// Status
+    ///
+    /// Tasks this instance runs as the leader when running multiple instances
+    #[oai(
+        path = "/status",
+        method = "get",
+        operation_id = "status",
+        tag = "OpenAPITags::Service"
+    )]
+    async fn status(&self, Data(app): Data<&Arc<App>>) -> Json<ServiceStatus> {
+        Json(ServiceStatus {
+            instance_id: app.leader_election.instance_id().to_string(),
+            leader_election: app.leader_election.is_enabled(),
+            tasks: app.leader_election.tasks(),
+        })
+    }
 }
 
 pub struct ServerHandle {
@@ -580,8 +2221,16 @@ pub async fn spawn_server(app: Arc<App>) -> eyre::Result<ServerHandle> {
 
     let server = poem::Server::new_with_acceptor(acceptor);
 
+    let shutdown = app.shutdown.clone();
+    let shutdown_timeout = app.config.service.shutdown_timeout;
     let server_handle = tokio::spawn(async move {
-        server.run(router).await?;
+        server
+            .run_with_graceful_shutdown(
+                router,
+                async move { shutdown.triggered().await },
+                Some(shutdown_timeout),
+            )
+            .await?;
         Ok(())
     });
 
@@ -593,9 +2242,9 @@ pub async fn spawn_server(app: Arc<App>) -> eyre::Result<ServerHandle> {
 
 fn create_api_service(
     server_address: Option<&String>,
-) -> OpenApiService<(AdminApi, RelayerApi, ServiceApi), ()> {
+) -> OpenApiService<(AdminApi, RelayerApi, PathRelayerApi, ServiceApi), ()> {
     let mut api_service = OpenApiService::new(
-        (AdminApi, RelayerApi, ServiceApi),
+        (AdminApi, RelayerApi, PathRelayerApi, ServiceApi),
         "Tx Sitter",
         version::version!(),
     )
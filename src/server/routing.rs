@@ -0,0 +1,148 @@
+use ethers::providers::Middleware;
+use ethers::types::Address;
+use eyre::ContextCompat;
+use poem::http::StatusCode;
+use poem::{IntoResponse, Result};
+
+use crate::app::App;
+use crate::db::data::{RelayerInfo, RelayerPool};
+use crate::types::{ErrorResponse, SendTxRequest};
+
+/// Selects the pool member sending a tx
+///
+/// Txs with a route key go to the member the key is pinned to, keys are
+/// pinned to the member picked for their first tx. Other txs go to the
+/// enabled member with the fewest queued txs which has room in its queue and
+/// enough balance for the tx. Keys pinned to a disabled or rotating member
+/// without pending txs are moved to another member.
+///
+/// Txs from a given address only go to that member, if it can accept them.
+pub async fn route_tx(
+    app: &App,
+    pool: &RelayerPool,
+    req: &SendTxRequest,
+    from: Option<Address>,
+) -> Result<RelayerInfo> {
+    let route_key = req.route_key.as_deref().filter(|_| from.is_none());
+
+    let mut replaced = None;
+    if let Some(route_key) = route_key {
+        if let Some(relayer_id) =
+            app.db.get_pool_route(&pool.id, route_key).await?
+        {
+            let relayer = app
+                .db
+                .get_relayer(&relayer_id)
+                .await?
+                .context("Missing relayer")?;
+
+            // Moving a key with pending txs would break their order
+            if is_eligible(app, &relayer).await?
+                || !app.db.is_relayer_idle(&relayer.id).await?
+            {
+                return Ok(relayer);
+            }
+
+            tracing::info!(
+                pool_id = pool.id,
+                route_key,
+                relayer_id,
+                "Moving route off ineligible relayer"
+            );
+            replaced = Some(relayer.id);
+        }
+    }
+
+    let relayer = pick_relayer(app, pool, req, from).await?;
+
+    let Some(route_key) = route_key else {
+        return Ok(relayer);
+    };
+
+    let routed_id = app
+        .db
+        .set_pool_route(&pool.id, route_key, &relayer.id, replaced.as_deref())
+        .await?;
+
+    if routed_id == relayer.id {
+        return Ok(relayer);
+    }
+
+    // Pinned by a concurrent tx with the same key
+    Ok(app
+        .db
+        .get_relayer(&routed_id)
+        .await?
+        .context("Missing relayer")?)
+}
+
+async fn pick_relayer(
+    app: &App,
+    pool: &RelayerPool,
+    req: &SendTxRequest,
+    from: Option<Address>,
+) -> Result<RelayerInfo> {
+    let block_fees = app
+        .db
+        .get_latest_block_fees_by_chain_id(pool.chain_id)
+        .await?;
+
+    let http_provider = app.http_provider(pool.chain_id).await?;
+
+    for relayer in app.db.get_pool_relayers_by_queue_depth(&pool.id).await? {
+        if from.is_some_and(|from| relayer.address.0 != from) {
+            continue;
+        }
+
+        if !is_eligible(app, &relayer).await? {
+            continue;
+        }
+        let queued_tx_count =
+            app.db.get_relayer_pending_txs(&relayer.id).await?;
+
+        if queued_tx_count > relayer.max_queued_txs as usize {
+            continue;
+        }
+
+        if let Some(block_fees) = &block_fees {
+            let gas_limit = app
+                .db
+                .get_relayer_pending_txs_gas_limit_sum(&relayer.id)
+                .await?
+                + req.gas_limit.0;
+            let estimated_cost = block_fees.gas_price * gas_limit + req.value.0;
+
+            let balance = http_provider
+                .get_balance(relayer.address.0, None)
+                .await
+                .map_err(|err| {
+                    eyre::eyre!("Error checking balance: {}", err)
+                })?;
+
+            if balance < estimated_cost {
+                continue;
+            }
+        }
+
+        tracing::info!(pool_id = pool.id, relayer_id = relayer.id, "Routed tx");
+
+        return Ok(relayer);
+    }
+
+    Err(poem::error::Error::from_response(
+        ErrorResponse::new(
+            StatusCode::SERVICE_UNAVAILABLE,
+            "no_relayer_available",
+            "No relayer in the pool can accept the transaction.",
+        )
+        .into_response(),
+    ))
+}
+
+/// Checks that the member is enabled and not rotating its key
+async fn is_eligible(app: &App, relayer: &RelayerInfo) -> eyre::Result<bool> {
+    Ok(
+        relayer.enabled
+            && app.db.get_key_rotation(&relayer.id).await?.is_none(),
+    )
+}
@@ -1,97 +1,133 @@
-use poem::Result;
+use poem::{IntoResponse, Request, Result};
 use poem_openapi::{auth, SecurityScheme};
 
+use crate::admin_auth::{AdminIdentity, AdminRole};
 use crate::api_key::ApiKey;
 use crate::app::App;
+use crate::db::data::ApiKeyInfo;
+use crate::types::{ApiKeyScope, ErrorResponse};
 
 #[derive(SecurityScheme)]
 #[oai(ty = "basic")]
 pub struct BasicAuth(auth::Basic);
 
-impl BasicAuth {
-    pub async fn validate(&self, app: impl AsRef<App>) -> Result<()> {
-        let credentials = app.as_ref().config.server.credentials();
-        Self::check_credentials(credentials, &self.0.username, &self.0.password)
-    }
+/// JWT issued by the identity provider configured in `server.jwt`
+#[derive(SecurityScheme)]
+#[oai(ty = "bearer", bearer_format = "JWT")]
+pub struct JwtAuth(auth::Bearer);
+
+/// Credentials of an admin API call
+#[derive(SecurityScheme)]
+pub enum AdminAuth {
+    Basic(BasicAuth),
+    Jwt(JwtAuth),
+}
+
+/// Relayer API key sent as `Authorization: Bearer <api_key>`
+#[derive(SecurityScheme)]
+#[oai(ty = "bearer", checker = "parse_api_key")]
+pub struct BearerAuth(pub ApiKey);
+
+async fn parse_api_key(_req: &Request, bearer: auth::Bearer) -> Option<ApiKey> {
+    bearer.token.parse().ok()
+}
+
+impl AdminAuth {
+    /// Checks the credentials and that the admin has the role
+    ///
+    /// The admin is recorded on the request span.
+    pub async fn validate(
+        &self,
+        app: impl AsRef<App>,
+        role: AdminRole,
+    ) -> Result<AdminIdentity> {
+        let authenticator = &app.as_ref().admin_auth;
 
-    fn check_credentials(
-        expected: Option<(&str, &str)>,
-        username: &str,
-        password: &str,
-    ) -> Result<()> {
-        if let Some((expected_user, expected_pass)) = expected {
-            if expected_user != username || expected_pass != password {
-                return Err(poem::error::Error::from_string(
-                    "Unauthorized".to_string(),
-                    poem::http::StatusCode::UNAUTHORIZED,
-                ));
+        let identity = if authenticator.is_disabled() {
+            Some(AdminIdentity {
+                name: "anonymous".to_string(),
+                role: AdminRole::Operator,
+            })
+        } else {
+            match self {
+                Self::Basic(BasicAuth(basic)) => {
+                    authenticator
+                        .verify_password(&basic.username, &basic.password)
+                        .await
+                }
+                Self::Jwt(JwtAuth(bearer)) => authenticator
+                    .verify_token(&bearer.token)
+                    .await
+                    .map_err(|err| {
+                        poem::error::Error::from_string(
+                            err.to_string(),
+                            poem::http::StatusCode::INTERNAL_SERVER_ERROR,
+                        )
+                    })?,
             }
+        };
+
+        let Some(identity) = identity else {
+            return Err(poem::error::Error::from_string(
+                "Unauthorized".to_string(),
+                poem::http::StatusCode::UNAUTHORIZED,
+            ));
+        };
+
+        tracing::Span::current().record("admin", identity.name.as_str());
+
+        if identity.role < role {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    poem::http::StatusCode::FORBIDDEN,
+                    "insufficient_role",
+                    "The admin is not allowed to perform this operation.",
+                )
+                .into_response(),
+            ));
         }
 
-        Ok(())
+        Ok(identity)
     }
 }
 
 impl ApiKey {
-    pub async fn validate(&self, app: impl AsRef<App>) -> Result<()> {
+    /// Checks the API key and that it grants the scope of the operation,
+    /// then takes a token from its rate limit of the scope
+    pub async fn validate(
+        &self,
+        app: impl AsRef<App>,
+        scope: ApiKeyScope,
+    ) -> Result<ApiKeyInfo> {
         let app = app.as_ref();
 
-        let is_authorized = app.is_authorized(self).await.map_err(|err| {
+        let key_info = app.api_key_info(self).await.map_err(|err| {
             poem::error::Error::from_string(
                 err.to_string(),
                 poem::http::StatusCode::INTERNAL_SERVER_ERROR,
             )
         })?;
 
-        if !is_authorized {
+        let Some(key_info) = key_info else {
             return Err(poem::error::Error::from_string(
                 "Unauthorized".to_string(),
                 poem::http::StatusCode::UNAUTHORIZED,
             ));
-        }
-
-        Ok(())
-    }
-}
+        };
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn check_credentials_succeeds_when_none_configured() {
-        assert!(BasicAuth::check_credentials(None, "any", "any").is_ok());
-    }
-
-    #[test]
-    fn check_credentials_succeeds_with_correct_credentials() {
-        let expected = Some(("admin", "secret"));
-        assert!(
-            BasicAuth::check_credentials(expected, "admin", "secret").is_ok()
-        );
-    }
-
-    #[test]
-    fn check_credentials_fails_with_wrong_username() {
-        let expected = Some(("admin", "secret"));
-        assert!(
-            BasicAuth::check_credentials(expected, "wrong", "secret").is_err()
-        );
-    }
+        if !key_info.scopes.contains(&scope) {
+            return Err(poem::error::Error::from_response(
+                ErrorResponse::new(
+                    poem::http::StatusCode::FORBIDDEN,
+                    "missing_scope",
+                    "The API key does not allow this operation.",
+                )
+                .into_response(),
+            ));
+        }
 
-    #[test]
-    fn check_credentials_fails_with_wrong_password() {
-        let expected = Some(("admin", "secret"));
-        assert!(
-            BasicAuth::check_credentials(expected, "admin", "wrong").is_err()
-        );
-    }
+        app.rate_limiter.check(app, self, scope).await?;
 
-    #[test]
-    fn check_credentials_fails_with_both_wrong() {
-        let expected = Some(("admin", "secret"));
-        assert!(
-            BasicAuth::check_credentials(expected, "wrong", "wrong").is_err()
-        );
+        Ok(key_info)
     }
 }
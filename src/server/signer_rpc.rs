@@ -0,0 +1,364 @@
+use std::sync::Arc;
+
+use ethers::types::{Address, Bytes, H256, U256};
+use poem::http::StatusCode;
+use poem::web::Data;
+use poem::{IntoResponse, Result};
+use poem_openapi::param::Path;
+use poem_openapi::payload::Json;
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use super::security::BearerAuth;
+use super::RelayerApi;
+use crate::api_key::ApiKey;
+use crate::app::App;
+use crate::db::data::RpcKind;
+use crate::types::{
+    ErrorResponse, JsonRpcVersion, RpcRequest, SendTxRequest,
+    SignMessageRequest, SignTypedDataRequest,
+};
+
+/// JSON-RPC error code of invalid method parameters
+const INVALID_PARAMS_CODE: i64 = -32602;
+
+/// JSON-RPC error code of any other failed request
+const SERVER_ERROR_CODE: i64 = -32000;
+
+/// Outcome of a request to the signer facade
+pub enum SignerCall {
+    /// Result of a method served by tx-sitter
+    Handled(Value),
+    /// Request to forward to the node instead
+    Forward(RpcRequest),
+}
+
+/// Transaction object of `eth_sendTransaction`
+///
+/// Fees and nonces are managed by tx-sitter, so they are ignored.
+#[derive(Debug, Deserialize)]
+struct EthTransaction {
+    #[serde(default)]
+    from: Option<Address>,
+    #[serde(default)]
+    to: Option<Address>,
+    #[serde(default)]
+    gas: Option<U256>,
+    #[serde(default)]
+    value: Option<U256>,
+    #[serde(default)]
+    data: Option<Bytes>,
+    #[serde(default)]
+    input: Option<Bytes>,
+}
+
+pub async fn call(
+    app: &Arc<App>,
+    api_token: &ApiKey,
+    chain_id: u64,
+    req: RpcRequest,
+) -> Result<SignerCall> {
+    let result = match req.method.as_str() {
+        "eth_accounts" => json!(accounts(app, api_token).await?),
+        "eth_sendTransaction" => {
+            let (tx,): (EthTransaction,) = parse_params(&req)?;
+
+            send_transaction(app, api_token, chain_id, tx).await?
+        }
+        "eth_sign" => {
+            let (address, message): (Address, Bytes) = parse_params(&req)?;
+            check_account(app, api_token, address).await?;
+
+            let Json(signature) = RelayerApi
+                .sign_message(
+                    Data(app),
+                    BearerAuth(api_token.clone()),
+                    Json(SignMessageRequest {
+                        message: message.into(),
+                    }),
+                )
+                .await?;
+
+            json!(signature.signature)
+        }
+        "eth_signTypedData_v4" => {
+            let (address, typed_data): (Address, Value) = parse_params(&req)?;
+            check_account(app, api_token, address).await?;
+
+            // Wallets commonly send the typed data as a JSON string
+            let typed_data = match typed_data {
+                Value::String(s) => serde_json::from_str(&s)
+                    .map_err(|err| invalid_params(err.to_string()))?,
+                typed_data => typed_data,
+            };
+
+            let Json(signature) = RelayerApi
+                .sign_typed_data(
+                    Data(app),
+                    BearerAuth(api_token.clone()),
+                    Json(SignTypedDataRequest { typed_data }),
+                )
+                .await?;
+
+            json!(signature.signature)
+        }
+        "eth_getTransactionByHash" | "eth_getTransactionReceipt" => {
+            let (hash,): (String,) = parse_params(&req)?;
+
+            return resolve_tx_hash(app, api_token, req, &hash).await;
+        }
+        _ => return Ok(SignerCall::Forward(req)),
+    };
+
+    Ok(SignerCall::Handled(json!({
+        "jsonrpc": "2.0",
+        "id": req.id,
+        "result": result,
+    })))
+}
+
+/// JSON-RPC error response of a failed request
+pub async fn error_response(id: i32, err: poem::Error) -> Value {
+    let code = if err.status() == StatusCode::BAD_REQUEST {
+        INVALID_PARAMS_CODE
+    } else {
+        SERVER_ERROR_CODE
+    };
+
+    let fallback = err.to_string();
+    let message = err
+        .into_response()
+        .into_body()
+        .into_json::<Value>()
+        .await
+        .ok()
+        .and_then(|body| body["errorMessage"].as_str().map(String::from))
+        .unwrap_or(fallback);
+
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": {
+            "code": code,
+            "message": message,
+        },
+    })
+}
+
+/// Addresses the API key can send from, all members for pool API keys
+async fn accounts(app: &App, api_token: &ApiKey) -> Result<Vec<Address>> {
+    let owner_id = api_token.relayer_id();
+
+    let relayers = match app.db.get_relayer(owner_id).await? {
+        Some(relayer) => vec![relayer],
+        None => app.db.get_pool_relayers_by_queue_depth(owner_id).await?,
+    };
+
+    Ok(relayers
+        .into_iter()
+        .map(|relayer| relayer.address.0)
+        .collect())
+}
+
+async fn check_account(
+    app: &App,
+    api_token: &ApiKey,
+    address: Address,
+) -> Result<()> {
+    if !accounts(app, api_token).await?.contains(&address) {
+        return Err(invalid_params(format!("Unknown account {address:?}")));
+    }
+
+    Ok(())
+}
+
+/// Queues the transaction, the returned hash is the id of the tx-sitter
+/// transaction
+///
+/// The hash of the sent transaction changes with every escalation, the id
+/// stays the same and resolves to the latest hash in
+/// `eth_getTransactionReceipt`.
+async fn send_transaction(
+    app: &Arc<App>,
+    api_token: &ApiKey,
+    chain_id: u64,
+    tx: EthTransaction,
+) -> Result<Value> {
+    // Pool API keys send from the given member, or the one picked by the
+    // pool without `from`
+    if let Some(from) = tx.from {
+        check_account(app, api_token, from).await?;
+    }
+
+    if tx.data.is_some() && tx.input.is_some() && tx.data != tx.input {
+        return Err(invalid_params("Both `data` and `input` are set"));
+    }
+
+    let data = tx.input.or(tx.data);
+    let value = tx.value.unwrap_or_default();
+
+    let gas_limit = match tx.gas {
+        Some(gas) => gas,
+        None => {
+            let from = match tx.from {
+                Some(from) => Some(from),
+                None => app
+                    .db
+                    .get_relayer(api_token.relayer_id())
+                    .await?
+                    .map(|relayer| relayer.address.0),
+            };
+
+            estimate_gas(app, chain_id, from, tx.to, value, data.as_ref())
+                .await?
+        }
+    };
+
+    let tx_id = format!("{:?}", H256::random());
+
+    // Takes a token of the send rate limit, on top of the RPC one
+    super::create_tx(
+        app,
+        api_token,
+        SendTxRequest {
+            to: tx.to.map(Into::into),
+            value: value.into(),
+            data: data.map(Into::into),
+            gas_limit: gas_limit.into(),
+            priority: Default::default(),
+            tx_id: Some(tx_id.clone()),
+            blobs: None,
+            call: None,
+            route_key: None,
+        },
+        tx.from,
+    )
+    .await?;
+
+    Ok(json!(tx_id))
+}
+
+async fn estimate_gas(
+    app: &App,
+    chain_id: u64,
+    from: Option<Address>,
+    to: Option<Address>,
+    value: U256,
+    data: Option<&Bytes>,
+) -> Result<U256> {
+    let url = app.db.get_network_rpc(chain_id, RpcKind::Http).await?;
+
+    let req = RpcRequest {
+        id: 1,
+        method: "eth_estimateGas".to_string(),
+        params: json!([{
+            "from": from,
+            "to": to,
+            "value": value,
+            "data": data,
+        }]),
+        jsonrpc: JsonRpcVersion::V2,
+    };
+
+    let response = app
+        .rpc_proxy
+        .call(chain_id, &url, std::slice::from_ref(&req))
+        .await?
+        .remove(0);
+
+    if let Some(message) = response["error"]["message"].as_str() {
+        return Err(poem::error::Error::from_response(
+            ErrorResponse::new(
+                StatusCode::BAD_REQUEST,
+                "gas_estimation_failed",
+                format!("Error estimating gas: {message}"),
+            )
+            .into_response(),
+        ));
+    }
+
+    Ok(serde_json::from_value(response["result"].clone())
+        .map_err(|err| eyre::eyre!("Invalid gas estimate: {}", err))?)
+}
+
+/// Looks up transactions of tx-sitter by their id, other hashes are left to
+/// the node
+async fn resolve_tx_hash(
+    app: &Arc<App>,
+    api_token: &ApiKey,
+    mut req: RpcRequest,
+    hash: &str,
+) -> Result<SignerCall> {
+    let tx = RelayerApi
+        .get_tx(
+            Data(app),
+            BearerAuth(api_token.clone()),
+            Path(hash.to_string()),
+        )
+        .await;
+
+    let tx = match tx {
+        Ok(Json(tx)) => tx,
+        // Keys without the read scope can still look up hashes on the node
+        Err(err)
+            if err.status() == StatusCode::NOT_FOUND
+                || err.status() == StatusCode::FORBIDDEN =>
+        {
+            return Ok(SignerCall::Forward(req));
+        }
+        Err(err) => return Err(err),
+    };
+
+    match tx.tx_hash {
+        Some(tx_hash) => {
+            req.params = json!([tx_hash.0]);
+            Ok(SignerCall::Forward(req))
+        }
+        // Unsent transactions are unknown to the node
+        None => Ok(SignerCall::Handled(json!({
+            "jsonrpc": "2.0",
+            "id": req.id,
+            "result": Value::Null,
+        }))),
+    }
+}
+
+fn parse_params<T>(req: &RpcRequest) -> Result<T>
+where
+    T: for<'de> Deserialize<'de>,
+{
+    serde_json::from_value(req.params.clone()).map_err(|err| {
+        invalid_params(format!("Invalid params of {}: {err}", req.method))
+    })
+}
+
+fn invalid_params(message: impl Into<String>) -> poem::Error {
+    poem::error::Error::from_response(
+        ErrorResponse::new(StatusCode::BAD_REQUEST, "invalid_params", message)
+            .into_response(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_transaction_objects() {
+        let tx: EthTransaction = serde_json::from_value(json!({
+            "from": "0x0000000000000000000000000000000000000001",
+            "to": "0x0000000000000000000000000000000000000002",
+            "gas": "0x5208",
+            "value": "0x1",
+            "input": "0x1234",
+            "maxFeePerGas": "0x1",
+            "nonce": "0x7",
+        }))
+        .unwrap();
+
+        assert_eq!(tx.gas, Some(U256::from(21_000)));
+        assert_eq!(tx.value, Some(U256::one()));
+        assert_eq!(tx.input, Some(Bytes::from(vec![0x12, 0x34])));
+        assert!(tx.data.is_none());
+    }
+}
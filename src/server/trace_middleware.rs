@@ -1,8 +1,27 @@
+use std::borrow::Cow;
+
 use poem::http::uri::Builder;
+use poem::http::HeaderValue;
 use poem::{Endpoint, IntoResponse, Middleware, Request, Response, Result};
 use telemetry_batteries::tracing::{trace_from_headers, trace_to_headers};
 use tracing::{Instrument, Level};
 
+/// First path segments of the relayer API routes authenticated with a header
+const HEADER_ROUTES: [&str; 4] = ["tx", "txs", "sign", "rpc"];
+
+const REQUEST_ID_HEADER: &str = "x-request-id";
+const MAX_REQUEST_ID_LEN: usize = 255;
+
+tokio::task_local! {
+    static REQUEST_ID: String;
+}
+
+/// Id of the request being processed, taken from the `x-request-id` header
+/// or generated
+pub fn request_id() -> Option<String> {
+    REQUEST_ID.try_with(|request_id| request_id.clone()).ok()
+}
+
 pub struct TraceMiddleware;
 
 impl<E: Endpoint> Middleware<E> for TraceMiddleware {
@@ -19,13 +38,7 @@ impl<E: Endpoint> Endpoint for TraceMiddlwareImpl<E> {
     type Output = Response;
 
     async fn call(&self, req: Request) -> Result<Self::Output> {
-        let obfuscated_uri_path = if req.uri().path().starts_with("/1/api") {
-            // Regex to hide the api token in a url like /1/api/<API_TOKEN>/...
-            let re = regex::Regex::new(r"/1/api/([^/]+)/").unwrap();
-            re.replace(req.uri().path(), "/1/api/.../")
-        } else {
-            req.uri().path().into()
-        };
+        let obfuscated_uri_path = obfuscate_api_token(req.uri().path());
 
         let mut req_uri_builder = Builder::from(req.uri().clone());
         if let Some(path_and_query) = req.uri().path_and_query() {
@@ -42,7 +55,27 @@ impl<E: Endpoint> Endpoint for TraceMiddlwareImpl<E> {
 
         let req_uri = req_uri_builder.build().expect("Invalid URI");
 
-        let span = tracing::span!(Level::INFO, "request", method = %req.method(), uri = %req_uri);
+        let request_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| {
+                !value.is_empty() && value.len() <= MAX_REQUEST_ID_LEN
+            })
+            .map(|value| value.to_string())
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+
+        // The admin is recorded once the credentials are checked
+        let span = tracing::span!(
+            Level::INFO,
+            "request",
+            method = %req.method(),
+            uri = %req_uri,
+            request_id = %request_id,
+            admin = tracing::field::Empty
+        );
+
+        let response_request_id = request_id.clone();
 
         let res = async move {
             trace_from_headers(req.headers());
@@ -72,9 +105,56 @@ impl<E: Endpoint> Endpoint for TraceMiddlwareImpl<E> {
 
             trace_to_headers(response.headers_mut());
 
+            if let Ok(value) = HeaderValue::from_str(&response_request_id) {
+                response.headers_mut().insert(REQUEST_ID_HEADER, value);
+            }
+
             response
-        }.instrument(span).await;
+        }.instrument(span);
+
+        let res = REQUEST_ID.scope(request_id, res).await;
 
         Ok(res)
     }
 }
+
+/// Hides the api token in a url like /1/api/<API_TOKEN>/...
+fn obfuscate_api_token(path: &str) -> Cow<'_, str> {
+    let Some(rest) = path.strip_prefix("/1/api/") else {
+        return path.into();
+    };
+
+    match rest.split_once('/') {
+        Some((segment, tail)) if !HEADER_ROUTES.contains(&segment) => {
+            format!("/1/api/.../{tail}").into()
+        }
+        _ => path.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn obfuscates_api_token_in_path() {
+        assert_eq!(obfuscate_api_token("/1/api/c2VjcmV0/tx"), "/1/api/.../tx");
+        assert_eq!(
+            obfuscate_api_token("/1/api/c2VjcmV0/tx/123"),
+            "/1/api/.../tx/123"
+        );
+    }
+
+    #[test]
+    fn keeps_header_authenticated_paths() {
+        assert_eq!(obfuscate_api_token("/1/api/tx/123"), "/1/api/tx/123");
+        assert_eq!(
+            obfuscate_api_token("/1/api/sign/message"),
+            "/1/api/sign/message"
+        );
+        assert_eq!(
+            obfuscate_api_token("/1/admin/relayers"),
+            "/1/admin/relayers"
+        );
+    }
+}
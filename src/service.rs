@@ -1,14 +1,18 @@
+use std::future::Future;
 use std::net::SocketAddr;
 use std::sync::Arc;
 
-use ethers::signers::{Signer, Wallet};
+use ethers::signers::Signer;
 use tokio::task::JoinHandle;
 
 use crate::app::App;
 use crate::config::Config;
 use crate::keys::local_keys::signing_key_from_hex;
+use crate::leader_election::Lease;
+use crate::shutdown::Shutdown;
 use crate::task_runner::TaskRunner;
 use crate::tasks;
+use crate::types::ApiKeyScope;
 
 pub struct Service {
     app: Arc<App>,
@@ -24,16 +28,83 @@ impl Service {
         let chain_ids = app.db.get_network_chain_ids().await?;
 
         tracing::info!("Spawning tasks");
-        let task_runner = TaskRunner::new(app.clone());
-        task_runner.add_task("Broadcast transactions", tasks::broadcast_txs);
-        task_runner.add_task("Escalate transactions", tasks::escalate_txs_task);
-        task_runner.add_task("Prune blocks", tasks::prune_blocks);
-        task_runner.add_task("Prune transactions", tasks::prune_txs);
-        task_runner.add_task("Finalize transactions", tasks::finalize_txs);
-        task_runner.add_task("Handle soft reorgs", tasks::handle_soft_reorgs);
-        task_runner.add_task("Handle hard reorgs", tasks::handle_hard_reorgs);
-        task_runner.add_task("Monitor relayer funds", tasks::monitor_funds);
+        let task_runner = TaskRunner::new(app.clone(), app.shutdown.clone());
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "broadcast_txs",
+            "Broadcast transactions",
+            tasks::broadcast_txs,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "escalate_txs",
+            "Escalate transactions",
+            tasks::escalate_txs_task,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "prune_blocks",
+            "Prune blocks",
+            tasks::prune_blocks,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "prune_txs",
+            "Prune transactions",
+            tasks::prune_txs,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "finalize_txs",
+            "Finalize transactions",
+            tasks::finalize_txs,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "handle_soft_reorgs",
+            "Handle soft reorgs",
+            tasks::handle_soft_reorgs,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "handle_hard_reorgs",
+            "Handle hard reorgs",
+            tasks::handle_hard_reorgs,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "monitor_funds",
+            "Monitor relayer funds",
+            tasks::monitor_funds,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "rotate_keys",
+            "Rotate relayer keys",
+            tasks::rotate_keys,
+        );
+        Self::add_leader_task(
+            &app,
+            &task_runner,
+            "decommission_relayers",
+            "Decommission relayers",
+            tasks::decommission_relayers,
+        );
 
+        if app.leader_election.is_enabled() {
+            task_runner.add_task("Elect leaders", tasks::elect_leaders);
+        }
+
+        // Metrics are emitted by every instance
         if let Some(telemetry_config) = app.config.service.telemetry.as_ref() {
             if telemetry_config.metrics.is_some() {
                 task_runner.add_task("Emit metrics", tasks::emit_metrics);
@@ -41,7 +112,7 @@ impl Service {
         }
 
         for chain_id in chain_ids {
-            Self::spawn_chain_tasks(&task_runner, chain_id)?;
+            Self::spawn_chain_tasks(&app, &task_runner, chain_id)?;
         }
 
         let server = crate::server::spawn_server(app.clone()).await?;
@@ -57,31 +128,90 @@ impl Service {
         })
     }
 
+    /// Spawns the indexing tasks of the chain, unless this instance already
+    /// runs them
     pub fn spawn_chain_tasks(
+        app: &App,
         task_runner: &TaskRunner<App>,
         chain_id: u64,
     ) -> eyre::Result<()> {
-        task_runner.add_task(
+        Self::add_leader_task(
+            app,
+            task_runner,
+            format!("index_chain:{chain_id}"),
             format!("Index blocks (chain id: {chain_id})"),
-            move |app| crate::tasks::index::index_chain(app, chain_id),
+            move |app, lease| {
+                crate::tasks::index::index_chain(app, chain_id, lease)
+            },
         );
 
-        task_runner.add_task(
+        Self::add_leader_task(
+            app,
+            task_runner,
+            format!("estimate_gas:{chain_id}"),
             format!("Estimate fees (chain id: {chain_id})"),
-            move |app| crate::tasks::index::estimate_gas(app, chain_id),
+            move |app, lease| {
+                crate::tasks::index::estimate_gas(app, chain_id, lease)
+            },
         );
 
         Ok(())
     }
 
+    /// Adds a task run only by the leader of `group`
+    fn add_leader_task<C, F>(
+        app: &App,
+        task_runner: &TaskRunner<App>,
+        group: impl Into<String>,
+        label: impl ToString,
+        task: C,
+    ) where
+        C: Fn(Arc<App>, Lease) -> F + Send + Sync + 'static,
+        F: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        if let Some(leadership) = app.leader_election.register(group) {
+            task_runner.add_leader_task(label, leadership, task);
+        }
+    }
+
     pub fn local_addr(&self) -> SocketAddr {
         self.local_addr
     }
 
+    pub fn shutdown_handle(&self) -> Shutdown {
+        self.app.shutdown.clone()
+    }
+
+    /// Waits for the server to stop, then for the tasks to finish their
+    /// current iteration
+    ///
+    /// Both share a single `shutdown_timeout` counted from the shutdown
+    /// trigger.
     pub async fn wait(self) -> eyre::Result<()> {
-        self.server_handle.await??;
+        let result = self.server_handle.await?;
 
-        Ok(())
+        // The server failing takes the tasks down as well
+        self.app.shutdown.trigger();
+
+        tracing::info!("Waiting for tasks to finish");
+        let timeout = self.app.config.service.shutdown_timeout;
+        if let Err(err) = self.app.shutdown.drain(timeout).await {
+            tracing::warn!(error = ?err, "Tasks did not finish in time");
+        }
+
+        // Lets other instances take over right away
+        self.app.leader_election.release().await;
+
+        tracing::info!("Shutdown complete");
+
+        result
+    }
+
+    /// Stops accepting requests and waits for in-flight work
+    pub async fn shutdown(self) -> eyre::Result<()> {
+        self.app.shutdown.trigger();
+
+        self.wait().await
     }
 
     pub async fn are_estimates_ready_for_chain(&self, chain_id: u64) -> bool {
@@ -100,10 +230,6 @@ impl Service {
 async fn initialize_predefined_values(
     app: &Arc<App>,
 ) -> Result<(), eyre::Error> {
-    if app.config.service.predefined.is_some() && !app.config.keys.is_local() {
-        eyre::bail!("Predefined relayers are only supported with local keys");
-    }
-
     let Some(predefined) = app.config.service.predefined.as_ref() else {
         return Ok(());
     };
@@ -122,36 +248,55 @@ async fn initialize_predefined_values(
                 &predefined.network.name,
                 &predefined.network.http_rpc,
                 &predefined.network.ws_rpc,
+                None,
             )
             .await?;
 
-        let task_runner = TaskRunner::new(app.clone());
-        Service::spawn_chain_tasks(&task_runner, predefined.network.chain_id)?;
+        let task_runner = TaskRunner::new(app.clone(), app.shutdown.clone());
+        Service::spawn_chain_tasks(
+            app,
+            &task_runner,
+            predefined.network.chain_id,
+        )?;
     }
 
-    let secret_key = signing_key_from_hex(&predefined.relayer.key_id)?;
+    if app.db.get_relayer(&predefined.relayer.id).await?.is_none() {
+        // Other key sources reference an existing key
+        let (key_id, signer) = if app.config.keys.is_local() {
+            let secret_key = signing_key_from_hex(&predefined.relayer.key_id)?;
 
-    let signer = Wallet::from(secret_key);
-    let address = signer.address();
+            app.keys_source.import_private_key(secret_key).await?
+        } else {
+            let key_id = predefined.relayer.key_id.clone();
+            let signer = app.keys_source.load_signer(key_id.clone()).await?;
+
+            (key_id, signer)
+        };
 
-    if app.db.get_relayer(&predefined.relayer.id).await?.is_none() {
         app.db
             .create_relayer(
                 &predefined.relayer.id,
                 &predefined.relayer.name,
                 predefined.relayer.chain_id,
-                &predefined.relayer.key_id,
-                address,
+                &key_id,
+                signer.address(),
             )
             .await?;
     }
 
-    app.db
-        .upsert_api_key(
-            predefined.relayer.api_key.relayer_id(),
-            predefined.relayer.api_key.api_key_secret_hash(),
-        )
-        .await?;
+    let mut tx = app.db.begin().await?;
+
+    tx.upsert_api_key(
+        &uuid::Uuid::new_v4().to_string(),
+        predefined.relayer.api_key.relayer_id(),
+        Some("predefined"),
+        &ApiKeyScope::ALL,
+        None,
+        predefined.relayer.api_key.api_key_secret_hash(),
+    )
+    .await?;
+
+    tx.commit().await?;
 
     Ok(())
 }
@@ -1,17 +1,97 @@
 use core::panic;
+use std::future::Future;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
 
 use tokio::signal::unix::{signal, SignalKind};
+use tokio::task::JoinHandle;
+use tokio::time::Instant;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::TaskTracker;
 
-pub fn spawn_await_shutdown_task() {
-    tokio::spawn(async {
+/// Coordinates the shutdown of the server and the background tasks
+///
+/// Tasks spawned with `spawn` are awaited on shutdown, they are expected to
+/// return after their current iteration once shutdown is triggered.
+#[derive(Debug, Clone, Default)]
+pub struct Shutdown {
+    token: CancellationToken,
+    tracker: TaskTracker,
+    triggered_at: Arc<OnceLock<Instant>>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn spawn<F>(&self, task: F) -> JoinHandle<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        self.tracker.spawn(task)
+    }
+
+    pub fn trigger(&self) {
+        self.triggered_at.get_or_init(Instant::now);
+        self.token.cancel();
+    }
+
+    pub fn is_triggered(&self) -> bool {
+        self.token.is_cancelled()
+    }
+
+    /// Completes once shutdown is triggered
+    pub async fn triggered(&self) {
+        self.token.cancelled().await;
+    }
+
+    /// Sleeps for `duration`, returns false if shutdown was triggered in the
+    /// meantime
+    pub async fn sleep(&self, duration: Duration) -> bool {
+        tokio::select! {
+            _ = tokio::time::sleep(duration) => true,
+            _ = self.token.cancelled() => false,
+        }
+    }
+
+    /// Waits for the spawned tasks to return, up to `timeout` after shutdown
+    /// was triggered
+    ///
+    /// The deadline is shared with everything else stopping since the
+    /// trigger, e.g. the graceful shutdown of the server.
+    pub async fn drain(&self, timeout: Duration) -> eyre::Result<()> {
+        self.tracker.close();
+
+        let triggered_at = self
+            .triggered_at
+            .get()
+            .copied()
+            .unwrap_or_else(Instant::now);
+
+        tokio::time::timeout_at(triggered_at + timeout, self.tracker.wait())
+            .await
+            .map_err(|_| {
+                eyre::eyre!(
+                    "{} tasks still running after {:?}",
+                    self.tracker.len(),
+                    timeout
+                )
+            })
+    }
+}
+
+/// Triggers the shutdown on SIGINT or SIGTERM
+pub fn spawn_await_shutdown_task(shutdown: Shutdown) {
+    tokio::spawn(async move {
         let result = await_shutdown_signal().await;
         if let Err(err) = result {
             tracing::error!("Error while waiting for shutdown signal: {}", err);
             panic!("Error while waiting for shutdown signal: {}", err);
         }
 
-        tracing::info!("Shutdown complete");
-        std::process::exit(0);
+        shutdown.trigger();
     });
 }
 
@@ -26,3 +106,40 @@ pub async fn await_shutdown_signal() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn drains_spawned_tasks() {
+        let shutdown = Shutdown::new();
+
+        let task_shutdown = shutdown.clone();
+        shutdown.spawn(async move {
+            while task_shutdown.sleep(Duration::from_secs(60)).await {}
+        });
+
+        assert!(shutdown.drain(Duration::from_millis(10)).await.is_err());
+
+        shutdown.trigger();
+        assert!(shutdown.drain(Duration::from_secs(1)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn drain_deadline_starts_at_trigger() {
+        let shutdown = Shutdown::new();
+
+        shutdown.spawn(async move {
+            tokio::time::sleep(Duration::from_secs(60)).await;
+        });
+
+        shutdown.trigger();
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        // Only the time left since the trigger is waited for
+        let started = Instant::now();
+        assert!(shutdown.drain(Duration::from_millis(600)).await.is_err());
+        assert!(started.elapsed() < Duration::from_millis(400));
+    }
+}
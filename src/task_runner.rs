@@ -4,15 +4,20 @@ use std::time::{Duration, Instant};
 use futures::Future;
 use tokio::task::JoinHandle;
 
+use crate::leader_election::{Leadership, Lease};
+use crate::shutdown::Shutdown;
+
 const FAILURE_MONITORING_PERIOD: Duration = Duration::from_secs(60);
 
 pub struct TaskRunner<T> {
     app: Arc<T>,
+    shutdown: Shutdown,
 }
 
 impl<T> TaskRunner<T> {
-    pub fn new(app: Arc<T>) -> Self {
-        Self { app }
+    /// Tasks are tracked by `shutdown` and not restarted once it's triggered
+    pub fn new(app: Arc<T>, shutdown: Shutdown) -> Self {
+        Self { app, shutdown }
     }
 }
 
@@ -27,34 +32,96 @@ where
         F: Future<Output = eyre::Result<()>> + Send + 'static,
     {
         let app = self.app.clone();
+        let shutdown = self.shutdown.clone();
         let label = label.to_string();
 
-        tokio::spawn(async move {
-            let mut failures = vec![];
-
-            loop {
-                tracing::info!(task_label = label, "Running task");
-
-                let result = task(app.clone()).await;
+        self.shutdown.spawn(async move {
+            run_task(&label, app, &shutdown, &task).await;
+        })
+    }
 
-                if let Err(err) = result {
-                    tracing::error!(task_label = label, error = ?err, "Task failed");
+    /// Runs the task only while this instance leads its group
+    ///
+    /// The task gets a lease to check before each iteration, it's expected
+    /// to return once the lease is no longer valid. It's started again once
+    /// the leadership is regained.
+    pub fn add_leader_task<S, C, F>(
+        &self,
+        label: S,
+        mut leadership: Leadership,
+        task: C,
+    ) -> JoinHandle<()>
+    where
+        S: ToString,
+        C: Fn(Arc<T>, Lease) -> F + Send + Sync + 'static,
+        F: Future<Output = eyre::Result<()>> + Send + 'static,
+    {
+        let app = self.app.clone();
+        let shutdown = self.shutdown.clone();
+        let label = label.to_string();
 
-                    failures.push(Instant::now());
-                    let backoff = determine_backoff(&failures);
+        self.shutdown.spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = leadership.acquired() => {}
+                    _ = shutdown.triggered() => break,
+                }
 
-                    tokio::time::sleep(backoff).await;
+                let lease = Lease::new(leadership.clone(), shutdown.clone());
+                let run = |app| task(app, lease.clone());
+                run_task(&label, app.clone(), &shutdown, &run).await;
 
-                    prune_failures(&mut failures);
-                } else {
-                    tracing::info!(task_label = label, "Task finished");
+                if shutdown.is_triggered() {
                     break;
                 }
+
+                if !leadership.is_leader() {
+                    tracing::warn!(
+                        task_label = label,
+                        "Task stopped, leadership lost"
+                    );
+                }
             }
         })
     }
 }
 
+/// Runs the task until it finishes, restarting it with a backoff on failures
+async fn run_task<T, C, F>(
+    label: &str,
+    app: Arc<T>,
+    shutdown: &Shutdown,
+    task: &C,
+) where
+    C: Fn(Arc<T>) -> F,
+    F: Future<Output = eyre::Result<()>>,
+{
+    let mut failures = vec![];
+
+    loop {
+        tracing::info!(task_label = label, "Running task");
+
+        let result = task(app.clone()).await;
+
+        if let Err(err) = result {
+            tracing::error!(task_label = label, error = ?err, "Task failed");
+
+            failures.push(Instant::now());
+            let backoff = determine_backoff(&failures);
+
+            if !shutdown.sleep(backoff).await {
+                tracing::info!(task_label = label, "Task stopped");
+                break;
+            }
+
+            prune_failures(&mut failures);
+        } else {
+            tracing::info!(task_label = label, "Task finished");
+            break;
+        }
+    }
+}
+
 fn determine_backoff(failures: &[Instant]) -> Duration {
     let mut base_backoff = Duration::from_secs(5);
 
@@ -1,4 +1,6 @@
 pub mod broadcast;
+pub mod decommission_relayers;
+pub mod elect_leaders;
 pub mod escalate;
 pub mod finalize;
 pub mod handle_reorgs;
@@ -6,8 +8,11 @@ pub mod index;
 pub mod metrics;
 pub mod monitor_funds;
 pub mod prune;
+pub mod rotate_keys;
 
 pub use self::broadcast::broadcast_txs;
+pub use self::decommission_relayers::decommission_relayers;
+pub use self::elect_leaders::elect_leaders;
 pub use self::escalate::escalate_txs_task;
 pub use self::finalize::finalize_txs;
 pub use self::handle_reorgs::{handle_hard_reorgs, handle_soft_reorgs};
@@ -15,3 +20,4 @@ pub use self::index::index_chain;
 pub use self::metrics::emit_metrics;
 pub use self::monitor_funds::monitor_funds;
 pub use self::prune::{prune_blocks, prune_txs};
+pub use self::rotate_keys::rotate_keys;
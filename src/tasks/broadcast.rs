@@ -1,6 +1,6 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use ethers::providers::Middleware;
 use ethers::types::transaction::eip2718::TypedTransaction;
@@ -10,38 +10,148 @@ use eyre::ContextCompat;
 use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use itertools::Itertools;
+use sqlx::postgres::PgListener;
+use tokio::sync::mpsc;
+use tokio_util::task::AbortOnDropHandle;
 
 use crate::app::App;
 use crate::broadcast_utils::{
     calculate_gas_fees_from_estimates, should_send_relayer_transactions,
+    txs_within_budget,
 };
-use crate::db::UnsentTx;
+use crate::db::{UnsentTx, UNSENT_TXS_CHANNEL};
+use crate::leader_election::Lease;
+
+/// Interval of full scans for unsent txs
+///
+/// Relayers are woken by notifications, scans pick up txs which become
+/// sendable without one, e.g. once a budget window moves on, or whose
+/// notification was missed while the listener reconnected.
+const SCAN_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Delay before retrying relayers which failed broadcasting
+const RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Reason to look for unsent txs
+#[derive(Debug)]
+enum Wake {
+    Relayer(String),
+    /// Notifications may have been missed
+    All,
+}
 
-const NO_TXS_SLEEP_DURATION: Duration = Duration::from_secs(2);
+pub async fn broadcast_txs(app: Arc<App>, lease: Lease) -> eyre::Result<()> {
+    // Listen before the first scan so that no tx is missed
+    let mut listener = PgListener::connect_with(&app.db.pool).await?;
+    listener.listen(UNSENT_TXS_CHANNEL).await?;
 
-pub async fn broadcast_txs(app: Arc<App>) -> eyre::Result<()> {
-    loop {
-        let txs = app.db.get_unsent_txs().await?;
-        let num_txs = txs.len();
+    let (sender, mut wakes) = mpsc::unbounded_channel();
+    let _listener =
+        AbortOnDropHandle::new(tokio::spawn(forward_wakes(listener, sender)));
 
-        let txs_by_relayer = sort_txs_by_relayer(txs);
+    let mut next_scan = Instant::now();
+    let mut relayer_ids: HashSet<String> = HashSet::new();
 
-        let mut futures = FuturesUnordered::new();
+    while lease.is_valid().await {
+        let txs = if Instant::now() >= next_scan {
+            next_scan = Instant::now() + SCAN_INTERVAL;
+            relayer_ids.clear();
+
+            app.db.get_unsent_txs().await?
+        } else {
+            let mut txs = vec![];
+            for relayer_id in relayer_ids.drain() {
+                txs.extend(app.db.get_relayer_unsent_txs(&relayer_id).await?);
+            }
 
-        for (relayer_id, txs) in txs_by_relayer {
-            futures.push(broadcast_relayer_txs(&app, relayer_id, txs));
+            txs
+        };
+
+        let failed = broadcast(&app, txs).await;
+
+        let wake_at = if failed.is_empty() {
+            next_scan
+        } else {
+            next_scan.min(Instant::now() + RETRY_INTERVAL)
+        };
+        relayer_ids.extend(failed);
+
+        let wake = tokio::select! {
+            wake = wakes.recv() => wake.context("Notification listener stopped")?,
+            _ = tokio::time::sleep_until(wake_at.into()) => continue,
+            _ = lease.stopped() => break,
+        };
+
+        // Notifications come in bursts, handle all of them at once
+        for wake in std::iter::once(wake).chain(iter_ready(&mut wakes)) {
+            match wake {
+                Wake::Relayer(relayer_id) => {
+                    relayer_ids.insert(relayer_id);
+                }
+                Wake::All => next_scan = Instant::now(),
+            }
         }
+    }
+
+    Ok(())
+}
 
-        while let Some(result) = futures.next().await {
-            if let Err(err) = result {
-                tracing::error!(error = ?err, "Failed broadcasting transactions");
+/// Forwards notifications until the listener fails
+async fn forward_wakes(
+    mut listener: PgListener,
+    sender: mpsc::UnboundedSender<Wake>,
+) {
+    loop {
+        let wake = match listener.try_recv().await {
+            Ok(Some(notification)) => {
+                Wake::Relayer(notification.payload().to_string())
+            }
+            Ok(None) => {
+                tracing::warn!("Lost connection of the notification listener");
+                Wake::All
             }
+            Err(err) => {
+                tracing::error!(error = ?err, "Notification listener failed");
+                return;
+            }
+        };
+
+        if sender.send(wake).is_err() {
+            return;
         }
+    }
+}
+
+fn iter_ready(
+    wakes: &mut mpsc::UnboundedReceiver<Wake>,
+) -> impl Iterator<Item = Wake> + '_ {
+    std::iter::from_fn(move || wakes.try_recv().ok())
+}
+
+/// Broadcasts the txs of each relayer concurrently, returns the relayers
+/// which failed
+async fn broadcast(app: &App, txs: Vec<UnsentTx>) -> HashSet<String> {
+    let mut futures = FuturesUnordered::new();
 
-        if num_txs == 0 {
-            tokio::time::sleep(NO_TXS_SLEEP_DURATION).await;
+    for (relayer_id, txs) in sort_txs_by_relayer(txs) {
+        futures.push(async move {
+            let result =
+                broadcast_relayer_txs(app, relayer_id.clone(), txs).await;
+
+            (relayer_id, result)
+        });
+    }
+
+    let mut failed = HashSet::new();
+
+    while let Some((relayer_id, result)) = futures.next().await {
+        if let Err(err) = result {
+            tracing::error!(relayer_id, error = ?err, "Failed broadcasting transactions");
+            failed.insert(relayer_id);
         }
     }
+
+    failed
 }
 
 #[tracing::instrument(skip(app, txs))]
@@ -66,6 +176,8 @@ async fn broadcast_relayer_txs(
         return Ok(());
     }
 
+    let txs = txs_within_budget(app, &relayer, txs).await?;
+
     tracing::info!(
         relayer_id,
         num_txs = txs.len(),
@@ -105,7 +217,7 @@ async fn broadcast_relayer_tx(app: &App, tx: UnsentTx) -> eyre::Result<()> {
     let mut typed_transaction =
         TypedTransaction::Eip1559(Eip1559TransactionRequest {
             from: None,
-            to: Some(NameOrAddress::from(Address::from(tx.tx_to.0))),
+            to: tx.tx_to.map(|to| NameOrAddress::from(Address::from(to.0))),
             gas: Some(tx.gas_limit.0),
             value: Some(tx.value.0),
             data: Some(tx.data.into()),
@@ -129,7 +241,8 @@ async fn broadcast_relayer_tx(app: &App, tx: UnsentTx) -> eyre::Result<()> {
     let tx_hash = H256::from(ethers::utils::keccak256(&raw_signed_tx));
 
     tracing::debug!(tx_id = tx.id, "Saving transaction");
-    app.db
+    let latency = app
+        .db
         .insert_tx_broadcast(
             &tx.id,
             tx_hash,
@@ -138,6 +251,10 @@ async fn broadcast_relayer_tx(app: &App, tx: UnsentTx) -> eyre::Result<()> {
         )
         .await?;
 
+    let labels = [("chain_id", tx.chain_id.to_string())];
+    metrics::histogram!("tx_broadcast_latency_seconds", &labels)
+        .record(latency.as_secs_f64());
+
     tracing::debug!(tx_id = tx.id, "Sending transaction");
 
     let pending_tx = middleware.send_raw_transaction(raw_signed_tx).await;
@@ -0,0 +1,125 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ethers::providers::Middleware;
+use eyre::ContextCompat;
+use serde_json::json;
+
+use crate::app::App;
+use crate::audit::{AuditAction, AuditEvent, SYSTEM_ACTOR};
+use crate::broadcast_utils::sign_sweep_tx;
+use crate::db::data::RelayerDecommission;
+use crate::leader_election::Lease;
+use crate::types::{ApiKeyResponse, RelayerDecommissionResponse};
+
+const INTERVAL: Duration = Duration::from_secs(2);
+
+/// Completes decommissions of relayers
+///
+/// Once a relayer has no unsent or pending txs left its remaining balance is
+/// swept to the requested address. After the sweep is mined the API keys of
+/// the relayer are revoked and it is deleted.
+pub async fn decommission_relayers(
+    app: Arc<App>,
+    lease: Lease,
+) -> eyre::Result<()> {
+    while lease.is_valid().await {
+        for decommission in app.db.get_decommissions().await? {
+            let relayer_id = decommission.relayer_id.clone();
+
+            if let Err(err) = decommission_relayer(&app, decommission).await {
+                tracing::error!(relayer_id, error = ?err, "Failed decommissioning relayer");
+            }
+        }
+
+        lease.sleep(INTERVAL).await;
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(skip(app, decommission), fields(relayer_id = decommission.relayer_id))]
+async fn decommission_relayer(
+    app: &App,
+    decommission: RelayerDecommission,
+) -> eyre::Result<()> {
+    let relayer = app
+        .db
+        .get_relayer(&decommission.relayer_id)
+        .await?
+        .context("Missing relayer")?;
+
+    if !app.db.is_relayer_idle(&relayer.id).await? {
+        tracing::debug!("Waiting for relayer transactions to drain");
+        return Ok(());
+    }
+
+    let provider = app.http_provider(relayer.chain_id).await?;
+
+    if let Some(sweep_tx_hash) = decommission.sweep_tx_hash {
+        let sweep_tx_hash = sweep_tx_hash.0;
+
+        if provider
+            .get_transaction_receipt(sweep_tx_hash)
+            .await?
+            .is_none()
+        {
+            if provider.get_transaction(sweep_tx_hash).await?.is_none() {
+                tracing::warn!(?sweep_tx_hash, "Sweep transaction dropped");
+
+                app.db.set_decommission_sweep_tx(&relayer.id, None).await?;
+            }
+
+            return Ok(());
+        }
+    } else if let Some(sweep) =
+        sign_sweep_tx(app, &relayer, decommission.sweep_to.0).await?
+    {
+        // Saved before sending, dropped sweeps are sent again
+        app.db
+            .set_decommission_sweep_tx(&relayer.id, Some(sweep.tx_hash))
+            .await?;
+
+        provider.send_raw_transaction(sweep.raw_tx).await?;
+
+        tracing::info!(
+            tx_hash = ?sweep.tx_hash,
+            value = %sweep.value,
+            sweep_to = ?decommission.sweep_to.0,
+            "Sweeping relayer balance"
+        );
+
+        return Ok(());
+    }
+
+    let mut tx = app.db.begin().await?;
+
+    let api_keys: Vec<ApiKeyResponse> = tx
+        .get_api_keys(&relayer.id)
+        .await?
+        .into_iter()
+        .map(ApiKeyResponse::from)
+        .collect();
+
+    tx.complete_decommission(&relayer.id).await?;
+
+    let after = tx
+        .get_decommission(&relayer.id)
+        .await?
+        .map(RelayerDecommissionResponse::from);
+
+    app.audit_log
+        .record(
+            tx,
+            SYSTEM_ACTOR,
+            None,
+            AuditEvent::new(AuditAction::CompleteDecommission, &relayer.id)
+                .before(json!({ "revokedApiKeys": api_keys }))
+                .after(after),
+        )
+        .await?;
+
+    tracing::info!(address = ?relayer.address.0, "Relayer deleted");
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use crate::app::App;
+use crate::service::Service;
+use crate::task_runner::TaskRunner;
+
+/// Renews the leadership of this instance and takes over the task groups of
+/// instances which stopped renewing theirs
+///
+/// Networks created through other instances are picked up here as well, so
+/// their indexing can fail over to this instance.
+pub async fn elect_leaders(app: Arc<App>) -> eyre::Result<()> {
+    let Some(config) = app.leader_election.config() else {
+        return Ok(());
+    };
+    let lease_interval = config.lease_interval;
+
+    let task_runner = TaskRunner::new(app.clone(), app.shutdown.clone());
+
+    while !app.shutdown.is_triggered() {
+        let renewal =
+            tokio::time::timeout(lease_interval, renew(&app, &task_runner))
+                .await
+                .unwrap_or_else(|_| {
+                    Err(eyre::eyre!("Lease renewal timed out"))
+                });
+
+        if let Err(err) = renewal {
+            tracing::error!(error = ?err, "Failed to renew leadership");
+            app.leader_election.step_down().await;
+        }
+
+        app.shutdown.sleep(lease_interval).await;
+    }
+
+    Ok(())
+}
+
+async fn renew(
+    app: &Arc<App>,
+    task_runner: &TaskRunner<App>,
+) -> eyre::Result<()> {
+    for chain_id in app.db.get_network_chain_ids().await? {
+        Service::spawn_chain_tasks(app, task_runner, chain_id)?;
+    }
+
+    app.leader_election.renew(&app.db).await
+}
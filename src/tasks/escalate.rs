@@ -13,13 +13,19 @@ use crate::app::App;
 use crate::broadcast_utils::should_send_relayer_transactions;
 use crate::db::data::RelayerInfo;
 use crate::db::TxForEscalation;
+use crate::leader_election::Lease;
 
-pub async fn escalate_txs_task(app: Arc<App>) -> eyre::Result<()> {
-    loop {
+pub async fn escalate_txs_task(
+    app: Arc<App>,
+    lease: Lease,
+) -> eyre::Result<()> {
+    while lease.is_valid().await {
         escalate_txs(&app).await?;
 
-        tokio::time::sleep(app.config.service.escalation_interval).await;
+        lease.sleep(app.config.service.escalation_interval).await;
     }
+
+    Ok(())
 }
 
 #[tracing::instrument(skip(app))]
@@ -125,7 +131,7 @@ async fn escalate_relayer_tx(
 
     let eip1559_tx = Eip1559TransactionRequest {
         from: None,
-        to: Some(NameOrAddress::from(Address::from(tx.tx_to.0))),
+        to: tx.tx_to.map(|to| NameOrAddress::from(Address::from(to.0))),
         gas: Some(tx.gas_limit.0),
         value: Some(tx.value.0),
         data: Some(tx.data.into()),
@@ -2,11 +2,13 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use crate::app::App;
+use crate::leader_election::Lease;
+use crate::tasks::index::save_tx_receipts;
 
 const TIME_BETWEEN_FINALIZATIONS_SECONDS: i64 = 60;
 
-pub async fn finalize_txs(app: Arc<App>) -> eyre::Result<()> {
-    loop {
+pub async fn finalize_txs(app: Arc<App>, lease: Lease) -> eyre::Result<()> {
+    while lease.is_valid().await {
         let finalization_timestamp =
             chrono::Utc::now() - chrono::Duration::seconds(60 * 60);
 
@@ -17,9 +19,32 @@ pub async fn finalize_txs(app: Arc<App>) -> eyre::Result<()> {
 
         app.db.finalize_txs(finalization_timestamp).await?;
 
-        tokio::time::sleep(Duration::from_secs(
-            TIME_BETWEEN_FINALIZATIONS_SECONDS as u64,
-        ))
-        .await;
+        for chain_id in app.db.get_network_chain_ids().await? {
+            retry_tx_receipts(&app, chain_id).await?;
+        }
+
+        lease
+            .sleep(Duration::from_secs(
+                TIME_BETWEEN_FINALIZATIONS_SECONDS as u64,
+            ))
+            .await;
     }
+
+    Ok(())
+}
+
+/// Saves the receipts which failed to be fetched when their tx was mined
+async fn retry_tx_receipts(app: &App, chain_id: u64) -> eyre::Result<()> {
+    let txs = app.db.get_mined_txs_without_receipt(chain_id).await?;
+
+    if txs.is_empty() {
+        return Ok(());
+    }
+
+    tracing::info!(chain_id, count = txs.len(), "Retrying tx receipts");
+
+    let rpc = app.http_provider(chain_id).await?;
+    save_tx_receipts(app, &rpc, txs).await;
+
+    Ok(())
 }
@@ -14,6 +14,7 @@ use crate::broadcast_utils::gas_estimation::{
     estimate_percentile_fees, FeesEstimate,
 };
 use crate::db::data::RelayerInfo;
+use crate::leader_election::Lease;
 
 const BLOCK_FEE_HISTORY_SIZE: usize = 10;
 const FEE_PERCENTILES: [f64; 5] = [5.0, 25.0, 50.0, 75.0, 95.0];
@@ -23,14 +24,26 @@ const GAS_PRICE_FOR_METRICS_FACTOR: f64 = 1e-9;
 
 const MAX_RECENT_BLOCKS_TO_CHECK: u64 = 60;
 
-pub async fn index_chain(app: Arc<App>, chain_id: u64) -> eyre::Result<()> {
-    loop {
-        index_inner(app.clone(), chain_id).await?;
+const MAX_CONCURRENT_RECEIPT_REQUESTS: usize = 10;
+
+pub async fn index_chain(
+    app: Arc<App>,
+    chain_id: u64,
+    lease: Lease,
+) -> eyre::Result<()> {
+    while lease.is_valid().await {
+        index_inner(app.clone(), chain_id, &lease).await?;
     }
+
+    Ok(())
 }
 
-#[tracing::instrument(skip(app), level = "info")]
-async fn index_inner(app: Arc<App>, chain_id: u64) -> eyre::Result<()> {
+#[tracing::instrument(skip(app, lease), level = "info")]
+async fn index_inner(
+    app: Arc<App>,
+    chain_id: u64,
+    lease: &Lease,
+) -> eyre::Result<()> {
     let ws_rpc = app.ws_provider(chain_id).await?;
     let rpc = app.http_provider(chain_id).await?;
 
@@ -43,14 +56,20 @@ async fn index_inner(app: Arc<App>, chain_id: u64) -> eyre::Result<()> {
     }
 
     loop {
-        let next_block = timeout(
-            app.config.service.block_stream_timeout,
-            blocks_stream.next(),
-        )
-        .await;
+        let next_block = tokio::select! {
+            next_block = timeout(
+                app.config.service.block_stream_timeout,
+                blocks_stream.next(),
+            ) => next_block,
+            _ = lease.stopped() => break,
+        };
 
         match next_block {
             Ok(Some(block)) => {
+                if !lease.is_valid().await {
+                    break;
+                }
+
                 index_block(app.clone(), chain_id, &rpc, block).await?;
             }
             Ok(None) => {
@@ -104,7 +123,7 @@ pub async fn index_block(
     let metric_labels: [(&str, String); 1] =
         [("chain_id", chain_id.to_string())];
 
-    for tx in mined_txs {
+    for tx in &mined_txs {
         tracing::info!(
             tx_id = tx.0,
             tx_hash = ?tx.1,
@@ -114,6 +133,8 @@ pub async fn index_block(
         metrics::counter!("tx_mined", &metric_labels).increment(1);
     }
 
+    save_tx_receipts(&app, rpc, mined_txs).await;
+
     let relayers = app.db.get_relayers_by_chain_id(chain_id).await?;
 
     update_relayer_nonces(&relayers, &app, rpc, chain_id).await?;
@@ -176,10 +197,14 @@ pub async fn backfill_to_block(
     Ok(())
 }
 
-pub async fn estimate_gas(app: Arc<App>, chain_id: u64) -> eyre::Result<()> {
+pub async fn estimate_gas(
+    app: Arc<App>,
+    chain_id: u64,
+    lease: Lease,
+) -> eyre::Result<()> {
     let rpc = app.http_provider(chain_id).await?;
 
-    loop {
+    while lease.is_valid().await {
         let latest_block_number = app
             .db
             .get_latest_block_number_without_fee_estimates(chain_id)
@@ -188,7 +213,7 @@ pub async fn estimate_gas(app: Arc<App>, chain_id: u64) -> eyre::Result<()> {
         let Some(latest_block_number) = latest_block_number else {
             tracing::info!(chain_id, "No blocks to estimate fees for");
 
-            tokio::time::sleep(Duration::from_secs(2)).await;
+            lease.sleep(Duration::from_secs(2)).await;
 
             continue;
         };
@@ -235,11 +260,61 @@ pub async fn estimate_gas(app: Arc<App>, chain_id: u64) -> eyre::Result<()> {
             .set(percentile_fee.as_u64() as f64 * GAS_PRICE_FOR_METRICS_FACTOR);
         }
 
-        tokio::time::sleep(Duration::from_secs(
-            TIME_BETWEEN_FEE_ESTIMATION_SECONDS,
-        ))
-        .await;
+        lease
+            .sleep(Duration::from_secs(TIME_BETWEEN_FEE_ESTIMATION_SECONDS))
+            .await;
     }
+
+    Ok(())
+}
+
+/// Fetches and saves the receipts of mined txs
+///
+/// Failures are only logged, `finalize_txs` retries txs without a receipt.
+pub async fn save_tx_receipts(
+    app: &App,
+    rpc: &Provider<Http>,
+    txs: Vec<(String, H256)>,
+) {
+    futures::stream::iter(txs)
+        .for_each_concurrent(
+            MAX_CONCURRENT_RECEIPT_REQUESTS,
+            |(tx_id, tx_hash)| async move {
+                if let Err(err) =
+                    save_tx_receipt(app, rpc, &tx_id, tx_hash).await
+                {
+                    tracing::warn!(
+                        tx_id,
+                        ?tx_hash,
+                        error = ?err,
+                        "Failed saving tx receipt"
+                    );
+                }
+            },
+        )
+        .await;
+}
+
+async fn save_tx_receipt(
+    app: &App,
+    rpc: &Provider<Http>,
+    tx_id: &str,
+    tx_hash: H256,
+) -> eyre::Result<()> {
+    // Nodes behind a load balancer may lag behind the block stream
+    let Some(receipt) = rpc.get_transaction_receipt(tx_hash).await? else {
+        tracing::debug!(tx_id, ?tx_hash, "Receipt not available yet");
+        return Ok(());
+    };
+
+    app.db
+        .save_tx_receipt(
+            tx_id,
+            receipt.gas_used.unwrap_or_default(),
+            receipt.effective_gas_price.unwrap_or_default(),
+            receipt.contract_address,
+        )
+        .await
 }
 
 async fn update_relayer_nonces(
@@ -6,7 +6,7 @@ use crate::app::App;
 const EMIT_METRICS_INTERVAL: Duration = Duration::from_secs(1);
 
 pub async fn emit_metrics(app: Arc<App>) -> eyre::Result<()> {
-    loop {
+    while !app.shutdown.is_triggered() {
         let chain_ids = app.db.get_network_chain_ids().await?;
 
         for chain_id in chain_ids {
@@ -25,6 +25,8 @@ pub async fn emit_metrics(app: Arc<App>) -> eyre::Result<()> {
             metrics::gauge!("block_txs", &labels).set(stats.block_txs as f64);
         }
 
-        tokio::time::sleep(EMIT_METRICS_INTERVAL).await;
+        app.shutdown.sleep(EMIT_METRICS_INTERVAL).await;
     }
+
+    Ok(())
 }
@@ -1,57 +1,459 @@
-use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+use ethers::contract::abigen;
 use ethers::providers::Middleware;
-use ethers::types::{NameOrAddress, U256};
+use ethers::types::{Address, NameOrAddress, U256};
+use eyre::ContextCompat;
 use tokio::time::{self, MissedTickBehavior};
 
 use crate::app::App;
+use crate::broadcast_utils::{check_new_tx, TxRejection};
+use crate::db::data::RelayerInfo;
+use crate::db::CreateResult;
+use crate::leader_election::Lease;
+use crate::types::TransactionPriority;
+
+abigen!(
+    Erc20,
+    r#"[
+        function balanceOf(address owner) external view returns (uint256)
+        function decimals() external view returns (uint8)
+    ]"#
+);
 
 const INTERVAL: Duration = Duration::from_secs(15);
-const LOW_BALANCE_THRESHOLD: u64 = 1000000000000000000; // 1 ETH
 const REPORTING_INTERVAL: Duration = Duration::from_secs(60);
+const TRANSFER_GAS_LIMIT: u64 = 21_000;
 
-pub async fn monitor_funds(app: Arc<App>) -> eyre::Result<()> {
+/// Records relayer balances, reports relayers with low balance and tops them
+/// up from their treasury
+pub async fn monitor_funds(app: Arc<App>, lease: Lease) -> eyre::Result<()> {
     let mut timer = time::interval(INTERVAL);
     timer.set_missed_tick_behavior(MissedTickBehavior::Skip);
 
-    let low_balance_threshold = U256::from(LOW_BALANCE_THRESHOLD);
-
     let mut reporting_cache = HashMap::new();
+    let mut decimals_cache = HashMap::new();
+    let mut balance_gauges = BalanceGauges::default();
 
     loop {
-        timer.tick().await;
+        tokio::select! {
+            _ = timer.tick() => {}
+            _ = lease.stopped() => return Ok(()),
+        }
 
-        let relayers = app.db.get_relayers().await?;
+        if !lease.is_valid().await {
+            return Ok(());
+        }
 
-        for relayer in &relayers {
-            // skip if we already reported it in the previous hour
-            if let Some(last_time_reported) = reporting_cache.get(&relayer.id) {
-                let diff = Instant::now() - *last_time_reported;
+        let network_low_balances: HashMap<u64, Option<U256>> = app
+            .db
+            .get_networks()
+            .await?
+            .into_iter()
+            .map(|network| (network.chain_id, network.low_balance.map(|v| v.0)))
+            .collect();
 
-                if diff < REPORTING_INTERVAL {
-                    continue;
-                }
-            }
+        let relayers = app.db.get_relayers().await?;
 
-            // update cache
-            reporting_cache.insert(relayer.id.clone(), Instant::now());
+        balance_gauges.retain(&relayers);
 
+        let treasury_relayer_ids: HashSet<&str> = relayers
+            .iter()
+            .filter_map(|relayer| {
+                relayer.funding.treasury_relayer_id.as_deref()
+            })
+            .collect();
+
+        for relayer in &relayers {
             let provider = app.http_provider(relayer.chain_id).await?;
 
             let balance = provider
                 .get_balance(NameOrAddress::Address(relayer.address.0), None)
                 .await?;
 
-            if balance < low_balance_threshold {
-                tracing::warn!(
+            app.db
+                .upsert_relayer_balance(&relayer.id, Address::zero(), balance)
+                .await?;
+            balance_gauges.record(relayer, None, balance, 18);
+
+            if let Err(err) = monitor_tokens(
+                &app,
+                relayer,
+                &mut reporting_cache,
+                &mut decimals_cache,
+                &mut balance_gauges,
+            )
+            .await
+            {
+                tracing::error!(
+                    relayer_id = relayer.id,
+                    error = ?err,
+                    "Failed checking relayer token balances"
+                );
+            }
+
+            let low_balance = relayer.funding.effective_low_balance(
+                network_low_balances
+                    .get(&relayer.chain_id)
+                    .copied()
+                    .flatten(),
+            );
+
+            if balance >= low_balance {
+                continue;
+            }
+
+            // Decommissioned relayers are drained on purpose
+            if app.db.get_decommission(&relayer.id).await?.is_some() {
+                continue;
+            }
+
+            // skip reporting if we already reported it recently
+            let reported_recently = matches!(
+                reporting_cache.get(&relayer.id),
+                Some(last_time_reported) if Instant::now() - *last_time_reported < REPORTING_INTERVAL
+            );
+
+            if !reported_recently {
+                reporting_cache.insert(relayer.id.clone(), Instant::now());
+
+                if treasury_relayer_ids.contains(relayer.id.as_str()) {
+                    report_low_treasury(relayer, balance);
+                } else {
+                    tracing::warn!(
+                        relayer_id = relayer.id,
+                        address = %relayer.address.0,
+                        balance = %balance,
+                        "Relayer has low balance"
+                    );
+                }
+            }
+
+            if let Err(err) = top_up(&app, relayer, balance).await {
+                tracing::error!(
                     relayer_id = relayer.id,
-                    address = %relayer.address.0,
-                    balance = %balance,
-                    "Relayer has low balance"
+                    error = ?err,
+                    "Failed topping up relayer"
                 );
             }
         }
     }
 }
+
+/// Records the balances of the ERC-20 tokens tracked by the relayer
+async fn monitor_tokens(
+    app: &App,
+    relayer: &RelayerInfo,
+    reporting_cache: &mut HashMap<String, Instant>,
+    decimals_cache: &mut HashMap<(u64, Address), u8>,
+    balance_gauges: &mut BalanceGauges,
+) -> eyre::Result<()> {
+    let tokens: Vec<Address> = relayer
+        .funding
+        .tokens
+        .iter()
+        .map(|token| token.address.0)
+        .collect();
+
+    app.db
+        .retain_relayer_balances(
+            &relayer.id,
+            &[&[Address::zero()], tokens.as_slice()].concat(),
+        )
+        .await?;
+
+    if tokens.is_empty() {
+        return Ok(());
+    }
+
+    let provider = Arc::new(app.http_provider(relayer.chain_id).await?);
+
+    for token in &relayer.funding.tokens {
+        let contract = Erc20::new(token.address.0, provider.clone());
+
+        let balance = contract.balance_of(relayer.address.0).call().await?;
+
+        let decimals =
+            match decimals_cache.entry((relayer.chain_id, token.address.0)) {
+                Entry::Occupied(entry) => *entry.get(),
+                Entry::Vacant(entry) => {
+                    *entry.insert(contract.decimals().call().await?)
+                }
+            };
+
+        app.db
+            .upsert_relayer_balance(&relayer.id, token.address.0, balance)
+            .await?;
+        balance_gauges.record(
+            relayer,
+            Some(token.address.0),
+            balance,
+            decimals,
+        );
+
+        let Some(low_balance) = &token.low_balance else {
+            continue;
+        };
+
+        if balance >= low_balance.0 {
+            continue;
+        }
+
+        let cache_key = format!("{}:{:?}", relayer.id, token.address.0);
+        let reported_recently = matches!(
+            reporting_cache.get(&cache_key),
+            Some(last_time_reported) if Instant::now() - *last_time_reported < REPORTING_INTERVAL
+        );
+
+        if !reported_recently {
+            reporting_cache.insert(cache_key, Instant::now());
+
+            tracing::warn!(
+                relayer_id = relayer.id,
+                address = %relayer.address.0,
+                token = ?token.address.0,
+                balance = %balance,
+                "Relayer has low token balance"
+            );
+        }
+    }
+
+    Ok(())
+}
+
+type BalanceLabels = [(&'static str, String); 4];
+
+/// Balance gauges set so far
+#[derive(Default)]
+struct BalanceGauges(HashSet<BalanceLabels>);
+
+impl BalanceGauges {
+    /// Sets the balance gauge of the relayer in whole units of the token
+    fn record(
+        &mut self,
+        relayer: &RelayerInfo,
+        token: Option<Address>,
+        balance: U256,
+        decimals: u8,
+    ) {
+        let Some(value) = ethers::utils::format_units(balance, decimals as u32)
+            .ok()
+            .and_then(|balance| balance.parse::<f64>().ok())
+        else {
+            tracing::warn!(
+                relayer_id = relayer.id,
+                ?token,
+                %balance,
+                decimals,
+                "Skipping balance sample which can't be converted"
+            );
+            return;
+        };
+
+        let labels = balance_labels(relayer, token);
+        metrics::gauge!("relayer_balance", &labels).set(value);
+
+        self.0.insert(labels);
+    }
+
+    /// Resets the gauges of deleted relayers, replaced addresses and
+    /// untracked tokens
+    ///
+    /// Gauges can't be unregistered through the metrics facade, they are set
+    /// to zero once and not set again.
+    fn retain(&mut self, relayers: &[RelayerInfo]) {
+        let tracked: HashSet<BalanceLabels> = relayers
+            .iter()
+            .flat_map(|relayer| {
+                std::iter::once(None)
+                    .chain(
+                        relayer
+                            .funding
+                            .tokens
+                            .iter()
+                            .map(|token| Some(token.address.0)),
+                    )
+                    .map(|token| balance_labels(relayer, token))
+            })
+            .collect();
+
+        self.0.retain(|labels| {
+            if tracked.contains(labels) {
+                return true;
+            }
+
+            metrics::gauge!("relayer_balance", labels).set(0.0);
+
+            false
+        });
+    }
+}
+
+fn balance_labels(
+    relayer: &RelayerInfo,
+    token: Option<Address>,
+) -> BalanceLabels {
+    [
+        ("chain_id", relayer.chain_id.to_string()),
+        ("relayer_id", relayer.id.clone()),
+        ("address", format!("{:?}", relayer.address.0)),
+        (
+            "token",
+            token.map_or_else(|| "native".to_string(), |t| format!("{t:?}")),
+        ),
+    ]
+}
+
+/// Queues a transfer from the treasury relayer bringing the balance of the
+/// relayer up to its target
+///
+/// Only one top-up per relayer is in flight at a time.
+async fn top_up(
+    app: &App,
+    relayer: &RelayerInfo,
+    balance: U256,
+) -> eyre::Result<()> {
+    let (Some(treasury_relayer_id), Some(target_balance)) = (
+        &relayer.funding.treasury_relayer_id,
+        &relayer.funding.target_balance,
+    ) else {
+        return Ok(());
+    };
+
+    if app
+        .db
+        .has_unconfirmed_transfer(treasury_relayer_id, relayer.address.0)
+        .await?
+    {
+        tracing::debug!(relayer_id = relayer.id, "Top-up already in flight");
+        return Ok(());
+    }
+
+    let treasury = app
+        .db
+        .get_relayer(treasury_relayer_id)
+        .await?
+        .context("Missing treasury relayer")?;
+
+    if !treasury.enabled
+        || app.db.get_key_rotation(&treasury.id).await?.is_some()
+        || app.db.get_decommission(&treasury.id).await?.is_some()
+    {
+        tracing::warn!(
+            relayer_id = relayer.id,
+            treasury_relayer_id,
+            "Treasury relayer cannot send top-ups"
+        );
+        return Ok(());
+    }
+
+    let value = target_balance.0.saturating_sub(balance);
+
+    let gas_price = app
+        .db
+        .get_latest_block_fees_by_chain_id(treasury.chain_id)
+        .await?
+        .map(|fees| fees.gas_price)
+        .unwrap_or_default();
+
+    // Queued txs of the treasury are sent before the top-up
+    let gas_limit = app
+        .db
+        .get_relayer_pending_txs_gas_limit_sum(&treasury.id)
+        .await?
+        + U256::from(TRANSFER_GAS_LIMIT);
+
+    match check_new_tx(
+        app,
+        &treasury,
+        Some(relayer.address.0),
+        &[],
+        value,
+        U256::from(TRANSFER_GAS_LIMIT),
+        gas_price,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(TxRejection::Other(err)) => return Err(err),
+        Err(rejection) => {
+            tracing::warn!(
+                relayer_id = relayer.id,
+                treasury_relayer_id,
+                %rejection,
+                "Treasury relayer rejected the top-up"
+            );
+            return Ok(());
+        }
+    }
+
+    let provider = app.http_provider(treasury.chain_id).await?;
+    let treasury_balance = provider
+        .get_balance(NameOrAddress::Address(treasury.address.0), None)
+        .await?;
+
+    if treasury_balance < value + gas_price * gas_limit {
+        report_low_treasury(&treasury, treasury_balance);
+        return Ok(());
+    }
+
+    let tx_id = format!("top-up-{}", uuid::Uuid::new_v4());
+
+    let res = app
+        .db
+        .create_transaction(
+            &tx_id,
+            Some(relayer.address.0),
+            &[],
+            value,
+            U256::from(TRANSFER_GAS_LIMIT),
+            TransactionPriority::Regular,
+            None,
+            None,
+            &treasury.id,
+            None,
+            None,
+        )
+        .await?;
+
+    // The treasury may have started a rotation or decommission meanwhile
+    if !matches!(res, CreateResult::SUCCESS { .. }) {
+        tracing::warn!(
+            relayer_id = relayer.id,
+            treasury_relayer_id,
+            "Treasury relayer cannot send top-ups"
+        );
+        return Ok(());
+    }
+
+    tracing::info!(
+        tx_id,
+        relayer_id = relayer.id,
+        treasury_relayer_id,
+        value = %value,
+        "Relayer top-up queued"
+    );
+
+    let labels = [("chain_id", relayer.chain_id.to_string())];
+    metrics::counter!("relayer_top_ups", &labels).increment(1);
+
+    Ok(())
+}
+
+fn report_low_treasury(treasury: &RelayerInfo, balance: U256) {
+    tracing::warn!(
+        relayer_id = treasury.id,
+        address = %treasury.address.0,
+        balance = %balance,
+        "Treasury relayer has low balance"
+    );
+
+    let labels = [
+        ("chain_id", treasury.chain_id.to_string()),
+        ("relayer_id", treasury.id.clone()),
+    ];
+    metrics::counter!("treasury_low_balance", &labels).increment(1);
+}
@@ -0,0 +1,133 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use ethers::providers::Middleware;
+use eyre::ContextCompat;
+use serde_json::json;
+
+use crate::app::App;
+use crate::audit::{AuditAction, AuditEvent, SYSTEM_ACTOR};
+use crate::broadcast_utils::sign_sweep_tx;
+use crate::db::data::{KeyRotation, RelayerInfo};
+use crate::leader_election::Lease;
+
+const INTERVAL: Duration = Duration::from_secs(2);
+
+/// Completes key rotations of relayers
+///
+/// Once the old address of a relayer has no unsent or pending txs left its
+/// remaining balance is swept to the new address. After the sweep is mined
+/// the relayer switches to the new key.
+pub async fn rotate_keys(app: Arc<App>, lease: Lease) -> eyre::Result<()> {
+    while lease.is_valid().await {
+        for rotation in app.db.get_key_rotations().await? {
+            let relayer_id = rotation.relayer_id.clone();
+
+            if let Err(err) = rotate_relayer_key(&app, rotation).await {
+                tracing::error!(relayer_id, error = ?err, "Failed rotating relayer key");
+            }
+        }
+
+        lease.sleep(INTERVAL).await;
+    }
+
+    Ok(())
+}
+
+#[tracing::instrument(skip(app, rotation), fields(relayer_id = rotation.relayer_id))]
+async fn rotate_relayer_key(
+    app: &App,
+    rotation: KeyRotation,
+) -> eyre::Result<()> {
+    let relayer = app
+        .db
+        .get_relayer(&rotation.relayer_id)
+        .await?
+        .context("Missing relayer")?;
+
+    if !app.db.is_relayer_idle(&relayer.id).await? {
+        tracing::debug!("Waiting for relayer transactions to drain");
+        return Ok(());
+    }
+
+    let provider = app.http_provider(relayer.chain_id).await?;
+
+    if let Some(sweep_tx_hash) = rotation.sweep_tx_hash {
+        let sweep_tx_hash = sweep_tx_hash.0;
+
+        if provider
+            .get_transaction_receipt(sweep_tx_hash)
+            .await?
+            .is_none()
+        {
+            if provider.get_transaction(sweep_tx_hash).await?.is_none() {
+                tracing::warn!(?sweep_tx_hash, "Sweep transaction dropped");
+
+                app.db.set_key_rotation_sweep_tx(&relayer.id, None).await?;
+            }
+
+            return Ok(());
+        }
+    } else if sweep_balance(app, &relayer, &rotation).await? {
+        return Ok(());
+    }
+
+    let nonce = provider
+        .get_transaction_count(rotation.address.0, None)
+        .await?;
+
+    let mut tx = app.db.begin().await?;
+
+    tx.complete_key_rotation(&relayer.id, nonce.as_u64())
+        .await?;
+
+    app.audit_log
+        .record(
+            tx,
+            SYSTEM_ACTOR,
+            None,
+            AuditEvent::new(AuditAction::CompleteKeyRotation, &relayer.id)
+                .before(json!({ "address": relayer.address.0 }))
+                .after(json!({ "address": rotation.address.0 })),
+        )
+        .await?;
+
+    tracing::info!(
+        old_address = ?relayer.address.0,
+        new_address = ?rotation.address.0,
+        "Relayer key rotated"
+    );
+
+    Ok(())
+}
+
+/// Sends the balance of the old address minus gas to the new address
+///
+/// Returns false if the balance does not cover the gas of the transfer
+async fn sweep_balance(
+    app: &App,
+    relayer: &RelayerInfo,
+    rotation: &KeyRotation,
+) -> eyre::Result<bool> {
+    let Some(sweep) = sign_sweep_tx(app, relayer, rotation.address.0).await?
+    else {
+        tracing::info!("No balance left to sweep");
+        return Ok(false);
+    };
+
+    // Saved before sending, dropped sweeps are sent again
+    app.db
+        .set_key_rotation_sweep_tx(&relayer.id, Some(sweep.tx_hash))
+        .await?;
+
+    let provider = app.http_provider(relayer.chain_id).await?;
+    provider.send_raw_transaction(sweep.raw_tx).await?;
+
+    tracing::info!(
+        tx_hash = ?sweep.tx_hash,
+        value = %sweep.value,
+        "Sweeping relayer balance"
+    );
+
+    Ok(true)
+}
@@ -1,12 +1,17 @@
 use base_api_types::{Address, DecimalU256, HexBytes, H256};
 use poem::http::{header, StatusCode};
 use poem::{IntoResponse, Response};
-use poem_openapi::{Enum, Object};
+use poem_openapi::{Enum, Object, Union};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
 use crate::api_key::ApiKey;
-use crate::db::data::{NetworkInfo, RelayerGasPriceLimit, RelayerInfo};
+use crate::db::data::{
+    AllowedTarget, ApiKeyInfo, AuditLogEntry, DecodedArg, DecodedCall,
+    NetworkInfo, RateLimit, RelayerBalance, RelayerBudget, RelayerDecommission,
+    RelayerFunding, RelayerGasPriceLimit, RelayerInfo, RelayerPolicy,
+    RelayerPool, RelayerRateLimits, TrackedToken,
+};
 
 pub mod secret_string;
 
@@ -51,6 +56,15 @@ pub struct RelayerResponse {
     pub max_queued_txs: u64,
     pub gas_price_limits: Vec<RelayerGasPriceLimitResponse>,
     pub enabled: bool,
+    pub budget: RelayerBudgetResponse,
+    pub policy: RelayerPolicyResponse,
+    pub signing_enabled: bool,
+    pub funding: RelayerFundingResponse,
+    pub rate_limits: RelayerRateLimitsResponse,
+    /// Last observed balances of the relayer
+    #[serde(default)]
+    #[oai(default)]
+    pub balances: Vec<RelayerBalanceResponse>,
 }
 
 impl From<RelayerInfo> for RelayerResponse {
@@ -71,6 +85,12 @@ impl From<RelayerInfo> for RelayerResponse {
                 .map(|v| v.into())
                 .collect(),
             enabled: value.enabled,
+            budget: value.budget.into(),
+            policy: value.policy.into(),
+            signing_enabled: value.signing_enabled,
+            funding: value.funding.into(),
+            rate_limits: value.rate_limits.into(),
+            balances: vec![],
         }
     }
 }
@@ -89,6 +109,18 @@ pub struct RelayerUpdateRequest {
     pub gas_price_limits: Option<Vec<RelayerGasPriceLimitResponse>>,
     #[serde(default)]
     pub enabled: Option<bool>,
+    #[serde(default)]
+    pub budget: Option<RelayerBudgetResponse>,
+    #[serde(default)]
+    pub policy: Option<RelayerPolicyResponse>,
+    /// Allows the relayer to sign messages and typed data
+    #[serde(default)]
+    pub signing_enabled: Option<bool>,
+    #[serde(default)]
+    pub funding: Option<RelayerFundingResponse>,
+    /// Limits of the API keys of the relayer
+    #[serde(default)]
+    pub rate_limits: Option<RelayerRateLimitsResponse>,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone, Object)]
@@ -108,13 +140,296 @@ impl From<RelayerGasPriceLimit> for RelayerGasPriceLimitResponse {
     }
 }
 
+/// Spending limits of a relayer
+///
+/// Window limits apply to a rolling window of `windowSeconds` and account for
+/// both mined (using their receipts) and queued transactions.
+#[derive(Deserialize, Serialize, Debug, Clone, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerBudgetResponse {
+    /// Max value of a single transaction
+    #[serde(default)]
+    #[oai(default)]
+    pub max_tx_value: Option<DecimalU256>,
+    /// Max total value of transactions within the window
+    #[serde(default)]
+    #[oai(default)]
+    pub max_window_value: Option<DecimalU256>,
+    /// Max total gas spend (in wei) of transactions within the window
+    #[serde(default)]
+    #[oai(default)]
+    pub max_window_gas_spend: Option<DecimalU256>,
+    /// Length of the rolling window in seconds
+    pub window_seconds: u64,
+}
+
+impl From<RelayerBudget> for RelayerBudgetResponse {
+    fn from(value: RelayerBudget) -> Self {
+        Self {
+            max_tx_value: value.max_tx_value.map(|v| v.into()),
+            max_window_value: value.max_window_value.map(|v| v.into()),
+            max_window_gas_spend: value.max_window_gas_spend.map(|v| v.into()),
+            window_seconds: value.window_seconds,
+        }
+    }
+}
+
+/// Balance thresholds of a relayer
+///
+/// Relayers below `lowBalance` are reported and, if a treasury relayer is
+/// set, topped up to `targetBalance` by a transfer from the treasury.
+#[derive(Deserialize, Serialize, Debug, Clone, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerFundingResponse {
+    /// Low balance watermark, defaults to 1 ETH
+    #[serde(default)]
+    #[oai(default)]
+    pub low_balance: Option<DecimalU256>,
+    /// Balance the relayer is topped up to, must be above `lowBalance`
+    #[serde(default)]
+    #[oai(default)]
+    pub target_balance: Option<DecimalU256>,
+    /// Relayer on the same chain sending the top-ups
+    #[serde(default)]
+    #[oai(default)]
+    pub treasury_relayer_id: Option<String>,
+    /// ERC-20 tokens whose balances are tracked
+    #[serde(default)]
+    #[oai(default)]
+    pub tokens: Vec<TrackedTokenResponse>,
+}
+
+impl From<RelayerFunding> for RelayerFundingResponse {
+    fn from(value: RelayerFunding) -> Self {
+        Self {
+            low_balance: value.low_balance.map(|v| v.into()),
+            target_balance: value.target_balance.map(|v| v.into()),
+            treasury_relayer_id: value.treasury_relayer_id,
+            tokens: value.tokens.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TrackedTokenResponse {
+    pub address: Address,
+    /// Token balance in base units below which the relayer is reported
+    #[serde(default)]
+    #[oai(default)]
+    pub low_balance: Option<DecimalU256>,
+}
+
+impl From<TrackedToken> for TrackedTokenResponse {
+    fn from(value: TrackedToken) -> Self {
+        Self {
+            address: value.address.into(),
+            low_balance: value.low_balance.map(|v| v.into()),
+        }
+    }
+}
+
+/// Limits of the API keys of a relayer per route class, the defaults of
+/// `server.rate_limits` apply to classes without a limit
+#[derive(Deserialize, Serialize, Debug, Clone, Default, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerRateLimitsResponse {
+    /// Sending transactions
+    #[serde(default)]
+    #[oai(default)]
+    pub send: Option<RateLimitResponse>,
+    /// Reading transactions
+    #[serde(default)]
+    #[oai(default)]
+    pub read: Option<RateLimitResponse>,
+    /// The RPC proxy
+    #[serde(default)]
+    #[oai(default)]
+    pub rpc: Option<RateLimitResponse>,
+    /// Signing messages and typed data
+    #[serde(default)]
+    #[oai(default)]
+    pub sign: Option<RateLimitResponse>,
+}
+
+impl From<RelayerRateLimits> for RelayerRateLimitsResponse {
+    fn from(value: RelayerRateLimits) -> Self {
+        Self {
+            send: value.send.map(|v| v.into()),
+            read: value.read.map(|v| v.into()),
+            rpc: value.rpc.map(|v| v.into()),
+            sign: value.sign.map(|v| v.into()),
+        }
+    }
+}
+
+/// Token bucket holding up to `burst` requests, refilled at
+/// `requestsPerMinute`
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RateLimitResponse {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+}
+
+impl From<RateLimit> for RateLimitResponse {
+    fn from(value: RateLimit) -> Self {
+        Self {
+            requests_per_minute: value.requests_per_minute,
+            burst: value.burst,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerBalanceResponse {
+    /// ERC-20 token address, not set for the native balance
+    #[serde(default)]
+    #[oai(default)]
+    pub token: Option<Address>,
+    /// Balance in base units
+    pub balance: DecimalU256,
+}
+
+impl From<RelayerBalance> for RelayerBalanceResponse {
+    fn from(value: RelayerBalance) -> Self {
+        Self {
+            token: (!value.token.0.is_zero()).then(|| value.token.into()),
+            balance: value.balance.into(),
+        }
+    }
+}
+
+/// Restricts what transactions a relayer can send
+#[derive(Deserialize, Serialize, Debug, Clone, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerPolicyResponse {
+    /// Allowed destination addresses, any destination is allowed if not set
+    #[serde(default)]
+    #[oai(default)]
+    pub allowed_targets: Option<Vec<AllowedTargetResponse>>,
+    pub allow_contract_creation: bool,
+}
+
+impl From<RelayerPolicy> for RelayerPolicyResponse {
+    fn from(value: RelayerPolicy) -> Self {
+        Self {
+            allowed_targets: value
+                .allowed_targets
+                .map(|targets| targets.into_iter().map(|v| v.into()).collect()),
+            allow_contract_creation: value.allow_contract_creation,
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize, Debug, Clone, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct AllowedTargetResponse {
+    pub address: Address,
+    /// Allowed 4-byte function selectors, any calldata is allowed if not set
+    #[serde(default)]
+    #[oai(default)]
+    pub selectors: Option<Vec<HexBytes>>,
+}
+
+impl From<AllowedTarget> for AllowedTargetResponse {
+    fn from(value: AllowedTarget) -> Self {
+        Self {
+            address: value.address.into(),
+            selectors: value.selectors.map(|selectors| {
+                selectors.into_iter().map(|v| v.into()).collect()
+            }),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
 pub struct CreateApiKeyResponse {
+    /// Id used to revoke the key
+    pub id: String,
     pub api_key: ApiKey,
 }
 
+/// Relayer API operations an API key is allowed to perform
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Enum,
+)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub enum ApiKeyScope {
+    /// Send transactions
+    Send,
+    /// Read transactions
+    Read,
+    /// Call the RPC proxy
+    Rpc,
+    /// Sign messages and typed data
+    Sign,
+}
+
+impl ApiKeyScope {
+    pub const ALL: [ApiKeyScope; 4] = [
+        ApiKeyScope::Send,
+        ApiKeyScope::Read,
+        ApiKeyScope::Rpc,
+        ApiKeyScope::Sign,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Send => "send",
+            Self::Read => "read",
+            Self::Rpc => "rpc",
+            Self::Sign => "sign",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ApiKeyResponse {
+    pub id: String,
+    #[serde(default)]
+    #[oai(default)]
+    pub name: Option<String>,
+    pub scopes: Vec<ApiKeyScope>,
+    /// Unix timestamp in seconds
+    pub created_at: i64,
+    /// Unix timestamp in seconds of the last authenticated request
+    #[serde(default)]
+    #[oai(default)]
+    pub last_used_at: Option<i64>,
+    /// Unix timestamp in seconds after which the key is rejected
+    #[serde(default)]
+    #[oai(default)]
+    pub expires_at: Option<i64>,
+}
+
+impl From<ApiKeyInfo> for ApiKeyResponse {
+    fn from(value: ApiKeyInfo) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            scopes: value.scopes,
+            created_at: value.created_at.timestamp(),
+            last_used_at: value.last_used_at.map(|v| v.timestamp()),
+            expires_at: value.expires_at.map(|v| v.timestamp()),
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -122,6 +437,10 @@ pub struct CreateNetworkRequest {
     pub name: String,
     pub http_rpc: String,
     pub ws_rpc: String,
+    /// Default low balance watermark of relayers on the network
+    #[serde(default)]
+    #[oai(default)]
+    pub low_balance: Option<DecimalU256>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Object)]
@@ -132,6 +451,10 @@ pub struct NetworkResponse {
     pub name: String,
     pub http_rpc: String,
     pub ws_rpc: String,
+    /// Default low balance watermark of relayers on the network
+    #[serde(default)]
+    #[oai(default)]
+    pub low_balance: Option<DecimalU256>,
 }
 
 impl From<NetworkInfo> for NetworkResponse {
@@ -141,6 +464,7 @@ impl From<NetworkInfo> for NetworkResponse {
             name: value.name,
             http_rpc: value.http_rpc,
             ws_rpc: value.ws_rpc,
+            low_balance: value.low_balance.map(|v| v.into()),
         }
     }
 }
@@ -155,6 +479,39 @@ pub struct CreateRelayerRequest {
     pub chain_id: u64,
 }
 
+/// Imports an existing key as a relayer
+///
+/// Exactly one of `keyId`, `keystore` or `privateKey` must be provided.
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ImportRelayerRequest {
+    /// New relayer name
+    pub name: String,
+    /// The chain id of the relayer
+    pub chain_id: u64,
+    /// Expected address of the imported key
+    #[serde(default)]
+    #[oai(default)]
+    pub address: Option<Address>,
+    /// Id of an existing key in the configured keys source, e.g. a KMS key id
+    #[serde(default)]
+    #[oai(default)]
+    pub key_id: Option<String>,
+    /// Encrypted JSON keystore, only supported with local keys
+    #[serde(default)]
+    #[oai(default)]
+    pub keystore: Option<Value>,
+    /// Password of the JSON keystore
+    #[serde(default)]
+    #[oai(default)]
+    pub keystore_password: Option<String>,
+    /// Hex encoded private key, only supported with local keys
+    #[serde(default)]
+    #[oai(default)]
+    pub private_key: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
@@ -165,11 +522,141 @@ pub struct CreateRelayerResponse {
     pub address: Address,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct CreateRelayerPoolRequest {
+    /// New pool name
+    pub name: String,
+    /// The chain id of the pool, all members must be on this chain
+    pub chain_id: u64,
+    /// Initial members of the pool
+    #[serde(default)]
+    #[oai(default)]
+    pub relayer_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerPoolResponse {
+    pub id: String,
+    pub name: String,
+    pub chain_id: u64,
+    pub relayer_ids: Vec<String>,
+}
+
+impl From<RelayerPool> for RelayerPoolResponse {
+    fn from(value: RelayerPool) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            chain_id: value.chain_id,
+            relayer_ids: value.relayer_ids,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RotateRelayerKeyResponse {
+    /// Address of the key being retired
+    pub previous_address: Address,
+    /// Address of the new key
+    pub address: Address,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct DecommissionRelayerRequest {
+    /// Address receiving the remaining balance of the relayer
+    pub sweep_to: Address,
+    /// Purges unsent transactions instead of waiting for them to be mined
+    #[serde(default)]
+    #[oai(default)]
+    pub cancel_unsent: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct RelayerDecommissionResponse {
+    pub relayer_id: String,
+    /// Address receiving the remaining balance of the relayer
+    pub sweep_to: Address,
+    /// Hash of the tx sweeping the remaining balance
+    #[serde(default)]
+    #[oai(default)]
+    pub sweep_tx_hash: Option<H256>,
+    /// True once the relayer was swept and deleted
+    pub deleted: bool,
+}
+
+impl From<RelayerDecommission> for RelayerDecommissionResponse {
+    fn from(value: RelayerDecommission) -> Self {
+        Self {
+            relayer_id: value.relayer_id,
+            sweep_to: value.sweep_to.into(),
+            sweep_tx_hash: value.sweep_tx_hash.map(|v| v.into()),
+            deleted: value.deleted,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct AuditLogEntryResponse {
+    pub id: i64,
+    /// Unix timestamp in seconds
+    pub created_at: i64,
+    /// Admin or API key (`api_key:<id>`) that performed the action
+    pub actor: String,
+    /// Action performed, e.g. `relayer.update`
+    pub action: String,
+    /// Id of the changed relayer, pool, API key or network
+    #[serde(default)]
+    #[oai(default)]
+    pub target: Option<String>,
+    /// State of the target before the action
+    #[serde(default)]
+    #[oai(default)]
+    pub before: Option<Value>,
+    /// State of the target after the action
+    #[serde(default)]
+    #[oai(default)]
+    pub after: Option<Value>,
+    /// Id of the request, sent back in the `x-request-id` header
+    #[serde(default)]
+    #[oai(default)]
+    pub request_id: Option<String>,
+}
+
+impl From<AuditLogEntry> for AuditLogEntryResponse {
+    fn from(value: AuditLogEntry) -> Self {
+        Self {
+            id: value.id,
+            created_at: value.created_at.timestamp(),
+            actor: value.actor,
+            action: value.action,
+            target: value.target,
+            before: value.before,
+            after: value.after,
+            request_id: value.request_id,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
 pub struct SendTxRequest {
-    pub to: Address,
+    /// Transaction destination, omit to deploy a contract
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub to: Option<Address>,
     /// Transaction value
     pub value: DecimalU256,
     #[serde(default)]
@@ -205,6 +692,34 @@ pub struct SendTxRequest {
     #[serde(default, with = "crate::serde_utils::base64_binary")]
     #[oai(default)]
     pub blobs: Option<Vec<Vec<u8>>>,
+    /// ABI-aware alternative to `data`, tx-sitter will encode the calldata
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub call: Option<ContractCall>,
+    /// Only used with pool API keys, txs with the same route key are sent by
+    /// the same relayer to preserve their order
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub route_key: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ContractCall {
+    /// Human readable function signature, e.g. `transfer(address to, uint256 amount)`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub signature: Option<String>,
+    /// JSON ABI fragment of the function, alternative to `signature`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub abi: Option<Value>,
+    /// Function arguments. Arrays and tuples are passed as JSON arrays,
+    /// integers can be passed as numbers or strings.
+    #[serde(default)]
+    #[oai(default)]
+    pub args: Vec<Value>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -212,6 +727,14 @@ pub struct SendTxRequest {
 #[oai(rename_all = "camelCase")]
 pub struct SendTxResponse {
     pub tx_id: String,
+    /// Address of the deployed contract if this is a contract creation tx
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub contract_address: Option<Address>,
+    /// Relayer the tx was routed to, only set for pool API keys
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub relayer_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Object)]
@@ -219,7 +742,9 @@ pub struct SendTxResponse {
 #[oai(rename_all = "camelCase")]
 pub struct GetTxResponse {
     pub tx_id: String,
-    pub to: Address,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub to: Option<Address>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub data: Option<HexBytes>,
     pub value: DecimalU256,
@@ -232,6 +757,83 @@ pub struct GetTxResponse {
     #[serde(default)]
     #[oai(default)]
     pub status: Option<TxStatus>,
+    /// Address of the deployed contract, available once mined
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub contract_address: Option<Address>,
+    /// Decoded function call, if the tx was submitted with `call`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[oai(default)]
+    pub call: Option<DecodedCallResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct DecodedCallResponse {
+    /// Function name, e.g. `transfer`
+    pub function: String,
+    /// Canonical function signature, e.g. `transfer(address,uint256)`
+    pub signature: String,
+    pub args: Vec<DecodedArgResponse>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct DecodedArgResponse {
+    pub name: String,
+    /// Solidity type of the argument
+    #[serde(rename = "type")]
+    #[oai(rename = "type")]
+    pub kind: String,
+    pub value: Value,
+}
+
+impl From<DecodedCall> for DecodedCallResponse {
+    fn from(value: DecodedCall) -> Self {
+        Self {
+            function: value.function,
+            signature: value.signature,
+            args: value.args.into_iter().map(|v| v.into()).collect(),
+        }
+    }
+}
+
+impl From<DecodedArg> for DecodedArgResponse {
+    fn from(value: DecodedArg) -> Self {
+        Self {
+            name: value.name,
+            kind: value.kind,
+            value: value.value,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SignMessageRequest {
+    /// Message to sign, the EIP-191 prefix is added before signing
+    pub message: HexBytes,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SignTypedDataRequest {
+    /// EIP-712 typed data, as used by `eth_signTypedData_v4`
+    pub typed_data: Value,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct SignatureResponse {
+    /// The signed EIP-191 or EIP-712 hash
+    pub hash: H256,
+    /// 65 byte `r || s || v` signature
+    pub signature: HexBytes,
 }
 
 #[derive(
@@ -247,7 +849,16 @@ pub enum TxStatus {
     Finalized,
 }
 
-#[derive(Debug, Serialize, Deserialize, Object)]
+/// Single JSON-RPC request or a batch of requests
+#[derive(Debug, Clone, Serialize, Deserialize, Union)]
+#[serde(untagged)]
+#[oai(one_of)]
+pub enum RpcPayload {
+    Single(RpcRequest),
+    Batch(Vec<RpcRequest>),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
 #[serde(rename_all = "camelCase")]
 #[oai(rename_all = "camelCase")]
 pub struct RpcRequest {
@@ -268,7 +879,29 @@ pub struct RpcResponse {
     pub jsonrpc: JsonRpcVersion,
 }
 
-#[derive(Debug, Serialize, Deserialize, Enum)]
+/// Role of the instance serving the request
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct ServiceStatus {
+    pub instance_id: String,
+    /// Whether tasks are coordinated with other instances, every instance
+    /// runs all tasks otherwise
+    pub leader_election: bool,
+    pub tasks: Vec<TaskStatus>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Object)]
+#[serde(rename_all = "camelCase")]
+#[oai(rename_all = "camelCase")]
+pub struct TaskStatus {
+    /// Task group, e.g. `broadcast_txs` or `index_chain:1`
+    pub group: String,
+    /// Whether this instance currently runs the tasks of the group
+    pub leader: bool,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, Enum)]
 pub enum JsonRpcVersion {
     #[serde(rename = "2.0")]
     #[oai(rename = "2.0")]
@@ -356,6 +989,12 @@ mod tests {
                 chain_id: 1,
             }],
             enabled: true,
+            budget: RelayerBudget::default().into(),
+            policy: RelayerPolicy::default().into(),
+            signing_enabled: false,
+            funding: RelayerFunding::default().into(),
+            rate_limits: RelayerRateLimits::default().into(),
+            balances: vec![],
         };
 
         let json = serde_json::to_string_pretty(&info).unwrap();
@@ -377,7 +1016,31 @@ mod tests {
                   "chainId": 1
                 }
               ],
-              "enabled": true
+              "enabled": true,
+              "budget": {
+                "maxTxValue": null,
+                "maxWindowValue": null,
+                "maxWindowGasSpend": null,
+                "windowSeconds": 86400
+              },
+              "policy": {
+                "allowedTargets": null,
+                "allowContractCreation": true
+              },
+              "signingEnabled": false,
+              "funding": {
+                "lowBalance": null,
+                "targetBalance": null,
+                "treasuryRelayerId": null,
+                "tokens": []
+              },
+              "rateLimits": {
+                "send": null,
+                "read": null,
+                "rpc": null,
+                "sign": null
+              },
+              "balances": []
             }
         "#};
 
@@ -389,13 +1052,15 @@ mod tests {
         let value: U256 = parse_units("1", "ether").unwrap().into();
 
         let request = SendTxRequest {
-            to: Address(Address::zero()),
+            to: Some(Address(Address::zero())),
             value: value.into(),
             data: Some(HexBytes::from(vec![0])),
             gas_limit: U256::zero().into(),
             priority: TransactionPriority::Regular,
             tx_id: Some("tx_id".to_string()),
             blobs: Some(vec![vec![0]]),
+            call: None,
+            route_key: None,
         };
 
         let json = serde_json::to_string_pretty(&request).unwrap();
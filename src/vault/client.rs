@@ -0,0 +1,317 @@
+//! Minimal client for the transit secrets engine of HashiCorp Vault
+
+use base64::Engine;
+use ethers::core::k256::ecdsa::{Signature as KSig, VerifyingKey};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio::sync::RwLock;
+
+use crate::config::{VaultAuthConfig, VaultKeysConfig};
+use crate::types::secret_string::SecretString;
+
+const TOKEN_HEADER: &str = "X-Vault-Token";
+const NAMESPACE_HEADER: &str = "X-Vault-Namespace";
+
+/// Prefix of signatures returned by the transit engine, followed by the key
+/// version
+const SIGNATURE_PREFIX: &str = "vault:v";
+
+#[derive(thiserror::Error, Debug)]
+pub enum VaultError {
+    #[error("{0}")]
+    Http(#[from] reqwest::Error),
+    #[error("Vault responded with {status}: {}", errors.join(", "))]
+    Api {
+        status: StatusCode,
+        errors: Vec<String>,
+    },
+    #[error("{0}")]
+    K256(#[from] ethers::core::k256::ecdsa::Error),
+    #[error("{0}")]
+    Spki(spki::Error),
+    #[error("{0}")]
+    Base64(#[from] base64::DecodeError),
+    #[error("{0}")]
+    Other(String),
+}
+
+impl From<spki::Error> for VaultError {
+    fn from(e: spki::Error) -> Self {
+        Self::Spki(e)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DataResponse<T> {
+    data: T,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginResponse {
+    auth: LoginAuth,
+}
+
+#[derive(Debug, Deserialize)]
+struct LoginAuth {
+    client_token: SecretString,
+}
+
+#[derive(Debug, Deserialize)]
+struct KeyData {
+    latest_version: u64,
+    keys: serde_json::Map<String, Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SignData {
+    signature: String,
+}
+
+pub struct VaultClient {
+    http: reqwest::Client,
+    config: VaultKeysConfig,
+    token: RwLock<SecretString>,
+}
+
+impl std::fmt::Debug for VaultClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultClient")
+            .field("address", &self.config.address)
+            .field("mount", &self.config.mount)
+            .field("namespace", &self.config.namespace)
+            .finish()
+    }
+}
+
+impl VaultClient {
+    /// Creates a new client, logging in if AppRole auth is configured
+    pub async fn new(config: &VaultKeysConfig) -> Result<Self, VaultError> {
+        let http = reqwest::Client::new();
+
+        let token = login(&http, config).await?;
+
+        Ok(Self {
+            http,
+            config: config.clone(),
+            token: RwLock::new(token),
+        })
+    }
+
+    /// Creates a new key of the configured key type
+    pub async fn create_key(&self, name: &str) -> Result<(), VaultError> {
+        let body = json!({ "type": self.config.key_type });
+
+        self.request::<Value>(Method::POST, &self.key_path("keys", name), body)
+            .await?;
+
+        Ok(())
+    }
+
+    /// Returns the public key of the latest version of a key
+    pub async fn public_key(
+        &self,
+        name: &str,
+    ) -> Result<VerifyingKey, VaultError> {
+        let key_data: KeyData = self
+            .request(Method::GET, &self.key_path("keys", name), Value::Null)
+            .await?
+            .ok_or_else(|| missing("key data"))?;
+
+        let public_key = key_data
+            .keys
+            .get(&key_data.latest_version.to_string())
+            .and_then(|key| key.get("public_key"))
+            .and_then(Value::as_str)
+            .ok_or_else(|| missing("public key"))?;
+
+        decode_public_key(public_key)
+    }
+
+    /// Signs a prehashed digest, the signature is normalized to a low `s`
+    pub async fn sign_digest(
+        &self,
+        name: &str,
+        digest: [u8; 32],
+    ) -> Result<KSig, VaultError> {
+        let body = json!({
+            "input": base64::prelude::BASE64_STANDARD.encode(digest),
+            "prehashed": true,
+            "hash_algorithm": "sha2-256",
+            "marshaling_algorithm": "asn1",
+        });
+
+        let sign_data: SignData = self
+            .request(Method::POST, &self.key_path("sign", name), body)
+            .await?
+            .ok_or_else(|| missing("signature"))?;
+
+        decode_signature(&sign_data.signature)
+    }
+
+    fn key_path(&self, endpoint: &str, name: &str) -> String {
+        format!("{}/{endpoint}/{name}", self.config.mount.trim_matches('/'))
+    }
+
+    /// Sends a request, logging in again once if the token was rejected
+    async fn request<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: Value,
+    ) -> Result<Option<T>, VaultError> {
+        let token = self.token.read().await.clone();
+
+        match self.send(method.clone(), path, &body, &token).await {
+            Err(VaultError::Api {
+                status: StatusCode::FORBIDDEN,
+                ..
+            }) if matches!(
+                self.config.auth,
+                VaultAuthConfig::AppRole { .. }
+            ) =>
+            {
+                tracing::info!("Vault token rejected, logging in again");
+
+                let token = login(&self.http, &self.config).await?;
+                *self.token.write().await = token.clone();
+
+                self.send(method, path, &body, &token).await
+            }
+            res => res,
+        }
+    }
+
+    async fn send<T: DeserializeOwned>(
+        &self,
+        method: Method,
+        path: &str,
+        body: &Value,
+        token: &SecretString,
+    ) -> Result<Option<T>, VaultError> {
+        let mut request = self
+            .http
+            .request(method, url(&self.config, path))
+            .header(TOKEN_HEADER, token.expose());
+
+        if let Some(namespace) = &self.config.namespace {
+            request = request.header(NAMESPACE_HEADER, namespace);
+        }
+
+        if !body.is_null() {
+            request = request.json(body);
+        }
+
+        let response = request.send().await?;
+
+        let response = error_for_status(response).await?;
+
+        if response.status() == StatusCode::NO_CONTENT {
+            return Ok(None);
+        }
+
+        let response: DataResponse<Option<T>> = response.json().await?;
+
+        Ok(response.data)
+    }
+}
+
+async fn login(
+    http: &reqwest::Client,
+    config: &VaultKeysConfig,
+) -> Result<SecretString, VaultError> {
+    let (role_id, secret_id, mount) = match &config.auth {
+        VaultAuthConfig::Token { token } => return Ok(token.clone()),
+        VaultAuthConfig::AppRole {
+            role_id,
+            secret_id,
+            mount,
+        } => (role_id, secret_id, mount),
+    };
+
+    let path = format!("auth/{}/login", mount.trim_matches('/'));
+
+    let mut request = http.post(url(config, &path)).json(&json!({
+        "role_id": role_id,
+        "secret_id": secret_id.expose(),
+    }));
+
+    if let Some(namespace) = &config.namespace {
+        request = request.header(NAMESPACE_HEADER, namespace);
+    }
+
+    let response = error_for_status(request.send().await?).await?;
+    let response: LoginResponse = response.json().await?;
+
+    Ok(response.auth.client_token)
+}
+
+fn url(config: &VaultKeysConfig, path: &str) -> String {
+    format!("{}/v1/{path}", config.address.trim_end_matches('/'))
+}
+
+async fn error_for_status(
+    response: reqwest::Response,
+) -> Result<reqwest::Response, VaultError> {
+    let status = response.status();
+
+    if status.is_success() {
+        return Ok(response);
+    }
+
+    let ErrorResponse { errors } = response.json().await.unwrap_or_default();
+
+    Err(VaultError::Api { status, errors })
+}
+
+fn missing(what: &str) -> VaultError {
+    VaultError::Other(format!("Missing {what} in Vault response"))
+}
+
+/// Decodes a PEM encoded SPKI or a hex encoded SEC1 public key
+fn decode_public_key(public_key: &str) -> Result<VerifyingKey, VaultError> {
+    let public_key = public_key.trim();
+
+    if !public_key.starts_with("-----BEGIN") {
+        let public_key = public_key.strip_prefix("0x").unwrap_or(public_key);
+        let sec1 = hex::decode(public_key)
+            .map_err(|e| VaultError::Other(e.to_string()))?;
+
+        return Ok(VerifyingKey::from_sec1_bytes(&sec1)?);
+    }
+
+    let der: String = public_key
+        .lines()
+        .filter(|line| !line.starts_with("-----"))
+        .collect();
+    let der = base64::prelude::BASE64_STANDARD.decode(der)?;
+
+    let spki = spki::SubjectPublicKeyInfoRef::try_from(der.as_slice())?;
+    let key =
+        VerifyingKey::from_sec1_bytes(spki.subject_public_key.raw_bytes())?;
+
+    Ok(key)
+}
+
+/// Decodes a `vault:v<version>:<base64 DER>` signature
+fn decode_signature(signature: &str) -> Result<KSig, VaultError> {
+    let der = signature
+        .strip_prefix(SIGNATURE_PREFIX)
+        .and_then(|s| s.split_once(':'))
+        .map(|(_version, der)| der)
+        .ok_or_else(|| {
+            VaultError::Other(format!("Malformed signature {signature}"))
+        })?;
+
+    let der = base64::prelude::BASE64_STANDARD.decode(der)?;
+    let sig = KSig::from_der(&der)?;
+
+    Ok(sig.normalize_s().unwrap_or(sig))
+}
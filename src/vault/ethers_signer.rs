@@ -0,0 +1,168 @@
+//! HashiCorp Vault transit-based Signer
+
+use std::sync::Arc;
+
+use ethers::core::k256::ecdsa::{RecoveryId, VerifyingKey};
+use ethers::core::types::transaction::eip2718::TypedTransaction;
+use ethers::core::types::transaction::eip712::Eip712;
+use ethers::core::types::{Address, Signature as EthSig, H256, U256};
+use ethers::core::utils::hash_message;
+use ethers::utils::public_key_to_address;
+use tracing::instrument;
+
+use super::client::{VaultClient, VaultError};
+
+/// An ethers Signer that uses keys held in the Vault transit engine.
+///
+/// Keys are identified by their name within the transit mount. Like the
+/// AWS signer the public key is fetched on instantiation. Message and typed
+/// data signatures use a `v` of 27 or 28, like local wallets.
+#[derive(Clone)]
+pub struct VaultSigner {
+    client: Arc<VaultClient>,
+    chain_id: u64,
+    key_name: String,
+    pubkey: VerifyingKey,
+    address: Address,
+}
+
+impl std::fmt::Debug for VaultSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultSigner")
+            .field("key_name", &self.key_name)
+            .field("chain_id", &self.chain_id)
+            .field("pubkey", &hex::encode(self.pubkey.to_sec1_bytes()))
+            .field("address", &self.address)
+            .finish()
+    }
+}
+
+/// Errors produced by the VaultSigner
+#[derive(thiserror::Error, Debug)]
+pub enum VaultSignerError {
+    #[error("{0}")]
+    Vault(#[from] VaultError),
+    #[error("Signature does not match the public key of {0}")]
+    Recovery(String),
+    /// Error type from Eip712Error message
+    #[error("error encoding eip712 struct: {0:?}")]
+    Eip712Error(String),
+}
+
+impl VaultSigner {
+    #[instrument(err, skip(client))]
+    pub async fn new(
+        client: Arc<VaultClient>,
+        key_name: &str,
+        chain_id: u64,
+    ) -> Result<Self, VaultSignerError> {
+        let pubkey = client.public_key(key_name).await?;
+        let address = public_key_to_address(&pubkey);
+
+        Ok(Self {
+            client,
+            chain_id,
+            key_name: key_name.to_owned(),
+            pubkey,
+            address,
+        })
+    }
+
+    /// Signs a digest and recovers the `v` value by trial recovery
+    async fn sign_digest(
+        &self,
+        digest: [u8; 32],
+    ) -> Result<EthSig, VaultSignerError> {
+        let sig = self.client.sign_digest(&self.key_name, digest).await?;
+
+        let recovery_id = RecoveryId::trial_recovery_from_prehash(
+            &self.pubkey,
+            &digest,
+            &sig,
+        )
+        .map_err(|_| VaultSignerError::Recovery(self.key_name.clone()))?;
+
+        let (r, s) = sig.split_bytes();
+
+        Ok(EthSig {
+            r: U256::from_big_endian(&r),
+            s: U256::from_big_endian(&s),
+            v: u64::from(recovery_id.to_byte()),
+        })
+    }
+
+    /// Signs a digest and adds the eip155 `v` value of the chain id
+    async fn sign_digest_with_eip155(
+        &self,
+        digest: H256,
+        chain_id: u64,
+    ) -> Result<EthSig, VaultSignerError> {
+        let mut sig = self.sign_digest(digest.into()).await?;
+        sig.v += chain_id * 2 + 35;
+
+        Ok(sig)
+    }
+}
+
+#[async_trait::async_trait]
+impl ethers::signers::Signer for VaultSigner {
+    type Error = VaultSignerError;
+
+    #[instrument(err, skip(message))]
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<EthSig, Self::Error> {
+        let message_hash = hash_message(message.as_ref());
+
+        let mut sig = self.sign_digest(message_hash.into()).await?;
+        sig.v += 27;
+
+        Ok(sig)
+    }
+
+    #[instrument(err)]
+    async fn sign_transaction(
+        &self,
+        tx: &TypedTransaction,
+    ) -> Result<EthSig, Self::Error> {
+        let mut tx_with_chain = tx.clone();
+        let chain_id = tx_with_chain
+            .chain_id()
+            .map(|id| id.as_u64())
+            .unwrap_or(self.chain_id);
+        tx_with_chain.set_chain_id(chain_id);
+
+        let sighash = tx_with_chain.sighash();
+        self.sign_digest_with_eip155(sighash, chain_id).await
+    }
+
+    async fn sign_typed_data<T: Eip712 + Send + Sync>(
+        &self,
+        payload: &T,
+    ) -> Result<EthSig, Self::Error> {
+        let digest = payload
+            .encode_eip712()
+            .map_err(|e| Self::Error::Eip712Error(e.to_string()))?;
+
+        let mut sig = self.sign_digest(digest).await?;
+        sig.v += 27;
+
+        Ok(sig)
+    }
+
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Returns the signer's chain id
+    fn chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Sets the signer's chain id
+    fn with_chain_id<T: Into<u64>>(mut self, chain_id: T) -> Self {
+        self.chain_id = chain_id.into();
+        self
+    }
+}
@@ -0,0 +1,149 @@
+mod common;
+
+use base64::Engine;
+use jsonwebtoken::{Algorithm, EncodingKey, Header};
+use poem::http;
+use ring::rand::SystemRandom;
+use ring::signature::{Ed25519KeyPair, KeyPair};
+use serde_json::json;
+use tx_sitter::admin_auth::AdminRole;
+use tx_sitter::config::JwtConfig;
+use tx_sitter_client::apis::admin_v1_api::CreateNetworkParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+const ISSUER: &str = "https://issuer.example.com";
+
+#[tokio::test]
+async fn admin_auth() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    // Locally generated key set standing in for the identity provider
+    let pkcs8 = Ed25519KeyPair::generate_pkcs8(&SystemRandom::new())
+        .map_err(|_| eyre::eyre!("Failed generating key"))?;
+    let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8.as_ref())
+        .map_err(|_| eyre::eyre!("Invalid key"))?;
+    let encoding_key = EncodingKey::from_ed_der(pkcs8.as_ref());
+
+    let jwks = json!({
+        "keys": [{
+            "kty": "OKP",
+            "crv": "Ed25519",
+            "kid": "test",
+            "x": base64::prelude::BASE64_URL_SAFE_NO_PAD
+                .encode(key_pair.public_key().as_ref()),
+        }]
+    });
+    let jwks_file = std::env::temp_dir()
+        .join(format!("jwks-{}.json", uuid::Uuid::new_v4()));
+    std::fs::write(&jwks_file, jwks.to_string())?;
+
+    let (_service, client) = ServiceBuilder::default()
+        .admin("viewer", "viewer-password", AdminRole::ReadOnly)
+        .jwt(JwtConfig {
+            jwks_url: None,
+            jwks_file: Some(jwks_file.clone()),
+            issuer: Some(ISSUER.to_string()),
+            audience: None,
+            role_claim: "role".to_string(),
+        })
+        .build(&anvil, &db_url)
+        .await?;
+
+    let create_network = |client: Configuration| {
+        let create_network_request = CreateNetworkRequest {
+            name: "Other".to_string(),
+            http_rpc: anvil.endpoint(),
+            ws_rpc: anvil.ws_endpoint(),
+            low_balance: None,
+        };
+
+        async move {
+            tx_sitter_client::apis::admin_v1_api::create_network(
+                &client,
+                CreateNetworkParams {
+                    chain_id: 1337,
+                    create_network_request,
+                },
+            )
+            .await
+        }
+    };
+
+    let basic_client = |username: &str, password: &str| Configuration {
+        basic_auth: Some((username.to_string(), Some(password.to_string()))),
+        ..client.clone()
+    };
+
+    let jwt_client = |role: &str, exp: i64| -> eyre::Result<Configuration> {
+        let token = jsonwebtoken::encode(
+            &Header {
+                kid: Some("test".to_string()),
+                ..Header::new(Algorithm::EdDSA)
+            },
+            &json!({
+                "sub": format!("{role}@example.com"),
+                "iss": ISSUER,
+                "exp": exp,
+                "role": role,
+            }),
+            &encoding_key,
+        )?;
+
+        Ok(Configuration {
+            basic_auth: None,
+            bearer_access_token: Some(token),
+            ..client.clone()
+        })
+    };
+
+    // Wrong passwords are rejected
+    let Err(Error::ResponseError(e)) =
+        tx_sitter_client::apis::admin_v1_api::get_networks(&basic_client(
+            "viewer", "wrong",
+        ))
+        .await
+    else {
+        eyre::bail!("Expected a wrong password to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::UNAUTHORIZED);
+
+    // Read-only admins can read but not change anything
+    let viewer = basic_client("viewer", "viewer-password");
+    tx_sitter_client::apis::admin_v1_api::get_networks(&viewer).await?;
+
+    let Err(Error::ResponseError(e)) = create_network(viewer).await else {
+        eyre::bail!("Expected a read-only admin to be unable to write");
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+
+    // The role of JWT admins comes from the token
+    let exp = chrono::Utc::now().timestamp() + 60;
+
+    let read_only = jwt_client("read_only", exp)?;
+    tx_sitter_client::apis::admin_v1_api::get_networks(&read_only).await?;
+
+    let Err(Error::ResponseError(e)) = create_network(read_only).await else {
+        eyre::bail!("Expected a read-only token to be unable to write");
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+
+    create_network(jwt_client("operator", exp)?).await?;
+
+    // Expired tokens are rejected
+    let expired = jwt_client("operator", exp - 3600)?;
+    let Err(Error::ResponseError(e)) =
+        tx_sitter_client::apis::admin_v1_api::get_networks(&expired).await
+    else {
+        eyre::bail!("Expected an expired token to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::UNAUTHORIZED);
+
+    std::fs::remove_file(jwks_file)?;
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    GetRelayerApiKeysParams, RelayerCreateApiKeyParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::{
+    CreateTransactionParams, GetTransactionsParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn api_key_scopes() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let create_key = |scopes: Option<Vec<ApiKeyScope>>| {
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes,
+                expires_at: None,
+            },
+        )
+    };
+
+    let get_txs = |api_token: String| {
+        let client = relayer_client(&client, api_token);
+
+        async move {
+            tx_sitter_client::apis::relayer_v1_api::get_transactions(
+                &client,
+                GetTransactionsParams {
+                    status: None,
+                    unsent: None,
+                },
+            )
+            .await
+        }
+    };
+
+    let send_tx = |api_token: String| {
+        let client = relayer_client(&client, api_token);
+
+        async move {
+            tx_sitter_client::apis::relayer_v1_api::create_transaction(
+                &client,
+                CreateTransactionParams {
+                    send_tx_request: SendTxRequest {
+                        to: Some(ARBITRARY_ADDRESS.into()),
+                        value: U256::from(1).into(),
+                        gas_limit: U256::from(21_000).into(),
+                        ..Default::default()
+                    },
+                },
+            )
+            .await
+        }
+    };
+
+    let Err(Error::ResponseError(e)) = create_key(Some(vec![])).await else {
+        eyre::bail!("Expected a key without scopes to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    let read_key = create_key(Some(vec![ApiKeyScope::Read])).await?;
+    let rpc_key = create_key(Some(vec![ApiKeyScope::Rpc])).await?;
+    let full_key = create_key(None).await?;
+
+    // Read-only keys can list transactions but not send them
+    get_txs(read_key.api_key.clone()).await?;
+
+    let Err(Error::ResponseError(e)) = send_tx(read_key.api_key.clone()).await
+    else {
+        eyre::bail!("Expected a read-only key to be unable to send");
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+
+    // RPC-only keys cannot read transactions
+    let Err(Error::ResponseError(e)) = get_txs(rpc_key.api_key.clone()).await
+    else {
+        eyre::bail!("Expected an RPC-only key to be unable to read");
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+
+    // Keys default to all scopes
+    send_tx(full_key.api_key.clone()).await?;
+    get_txs(full_key.api_key.clone()).await?;
+
+    let keys = tx_sitter_client::apis::admin_v1_api::get_relayer_api_keys(
+        &client,
+        GetRelayerApiKeysParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await?;
+
+    let read = keys
+        .iter()
+        .find(|key| key.id == read_key.id)
+        .ok_or_else(|| eyre::eyre!("Missing read-only key"))?;
+    assert_eq!(read.scopes, vec![ApiKeyScope::Read]);
+
+    let full = keys
+        .iter()
+        .find(|key| key.id == full_key.id)
+        .ok_or_else(|| eyre::eyre!("Missing full key"))?;
+    assert_eq!(full.scopes.len(), 4);
+
+    Ok(())
+}
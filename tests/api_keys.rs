@@ -0,0 +1,132 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    GetRelayerApiKeysParams, RelayerCreateApiKeyParams,
+    RevokeRelayerApiKeyParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::GetTransactionsParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn api_keys() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let create_key = |name: &str, expires_at: Option<i64>| {
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: Some(name.to_string()),
+                scopes: None,
+                expires_at,
+            },
+        )
+    };
+
+    let get_txs = |api_token: String| {
+        let client = relayer_client(&client, api_token);
+
+        async move {
+            tx_sitter_client::apis::relayer_v1_api::get_transactions(
+                &client,
+                GetTransactionsParams {
+                    status: None,
+                    unsent: None,
+                },
+            )
+            .await
+        }
+    };
+
+    let now = chrono::Utc::now().timestamp();
+
+    let res = create_key("expired", Some(now - 60)).await;
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected a key expiring in the past to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    let backend_key = create_key("backend", None).await?;
+    let expiring_key = create_key("expiring", Some(now + 3)).await?;
+
+    get_txs(backend_key.api_key.clone()).await?;
+    get_txs(expiring_key.api_key.clone()).await?;
+
+    let keys = tx_sitter_client::apis::admin_v1_api::get_relayer_api_keys(
+        &client,
+        GetRelayerApiKeysParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await?;
+
+    let backend = keys
+        .iter()
+        .find(|key| key.id == backend_key.id)
+        .ok_or_else(|| eyre::eyre!("Missing backend key"))?;
+    assert_eq!(backend.name.as_deref(), Some("backend"));
+    assert!(backend.last_used_at.is_some());
+    assert_eq!(backend.expires_at, None);
+
+    let expiring = keys
+        .iter()
+        .find(|key| key.id == expiring_key.id)
+        .ok_or_else(|| eyre::eyre!("Missing expiring key"))?;
+    assert_eq!(expiring.expires_at, Some(now + 3));
+
+    // Expired keys are rejected
+    tokio::time::sleep(Duration::from_secs(4)).await;
+
+    let Err(Error::ResponseError(e)) =
+        get_txs(expiring_key.api_key.clone()).await
+    else {
+        eyre::bail!("Expected the expired key to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::UNAUTHORIZED);
+
+    // Revoked keys are rejected, other keys keep working
+    let other_key = create_key("other", None).await?;
+
+    tx_sitter_client::apis::admin_v1_api::revoke_relayer_api_key(
+        &client,
+        RevokeRelayerApiKeyParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            key_id: backend_key.id.clone(),
+        },
+    )
+    .await?;
+
+    let Err(Error::ResponseError(e)) =
+        get_txs(backend_key.api_key.clone()).await
+    else {
+        eyre::bail!("Expected the revoked key to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::UNAUTHORIZED);
+
+    get_txs(other_key.api_key.clone()).await?;
+
+    let res = tx_sitter_client::apis::admin_v1_api::revoke_relayer_api_key(
+        &client,
+        RevokeRelayerApiKeyParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            key_id: backend_key.id,
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected revoking a missing key to fail");
+    };
+    assert_eq!(e.status, http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
@@ -0,0 +1,140 @@
+mod common;
+
+use tx_sitter_client::apis::admin_v1_api::{
+    GetAuditLogParams, RelayerCreateApiKeyParams, RevokeRelayerApiKeyParams,
+    UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::SignMessageParams;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn audit_log() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let audit_log_file = std::env::temp_dir()
+        .join(format!("audit-{}.jsonl", uuid::Uuid::new_v4()));
+
+    let (_service, client) = ServiceBuilder::default()
+        .audit_log_file(audit_log_file.clone())
+        .build(&anvil, &db_url)
+        .await?;
+
+    let get_audit_log = |action: &str| {
+        tx_sitter_client::apis::admin_v1_api::get_audit_log(
+            &client,
+            GetAuditLogParams {
+                actor: None,
+                action: Some(action.to_string()),
+                target: None,
+                since: None,
+                until: None,
+                limit: None,
+            },
+        )
+    };
+
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                max_inflight_txs: Some(7),
+                signing_enabled: Some(true),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let CreateApiKeyResponse { id, api_key } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: Some("audited".to_string()),
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    tx_sitter_client::apis::relayer_v1_api::sign_message(
+        &relayer_client(&client, api_key.clone()),
+        SignMessageParams {
+            sign_message_request: SignMessageRequest {
+                message: b"audited message".to_vec().into(),
+            },
+        },
+    )
+    .await?;
+
+    tx_sitter_client::apis::admin_v1_api::revoke_relayer_api_key(
+        &client,
+        RevokeRelayerApiKeyParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            key_id: id.clone(),
+        },
+    )
+    .await?;
+
+    // Relayer changes hold the state before and after
+    let updates = get_audit_log("relayer.update").await?;
+    assert_eq!(updates.len(), 1);
+    let update = &updates[0];
+    assert_eq!(update.actor, TEST_ADMIN_USERNAME);
+    assert_eq!(update.target.as_deref(), Some(DEFAULT_RELAYER_ID));
+    assert_ne!(
+        update.before.as_ref().map(|v| &v["maxInflightTxs"]),
+        update.after.as_ref().map(|v| &v["maxInflightTxs"]),
+    );
+    assert_eq!(
+        update.after.as_ref().map(|v| &v["maxInflightTxs"]),
+        Some(&serde_json::json!(7)),
+    );
+    assert!(update.request_id.is_some());
+
+    // API keys are logged without the key itself
+    let created = get_audit_log("api_key.create").await?;
+    assert_eq!(created.len(), 1);
+    assert_eq!(created[0].target.as_deref(), Some(id.as_str()));
+    let after = created[0].after.clone().unwrap_or_default();
+    assert_eq!(after["key"]["name"], "audited");
+    assert!(!after.to_string().contains(&api_key));
+
+    // Signing is attributed to the API key
+    let signed = get_audit_log("relayer.sign_message").await?;
+    assert_eq!(signed.len(), 1);
+    assert_eq!(signed[0].actor, format!("api_key:{id}"));
+
+    let revoked = tx_sitter_client::apis::admin_v1_api::get_audit_log(
+        &client,
+        GetAuditLogParams {
+            actor: None,
+            action: None,
+            target: Some(id.clone()),
+            since: None,
+            until: None,
+            limit: None,
+        },
+    )
+    .await?;
+    let actions: Vec<_> = revoked.iter().map(|e| e.action.as_str()).collect();
+    assert_eq!(actions, vec!["api_key.revoke", "api_key.create"]);
+
+    // The file sink holds the same entries
+    let lines = std::fs::read_to_string(&audit_log_file)?;
+    let entries = lines
+        .lines()
+        .map(serde_json::from_str::<AuditLogEntryResponse>)
+        .collect::<Result<Vec<_>, _>>()?;
+    assert_eq!(entries.len(), 4);
+    assert_eq!(entries[0].action, "relayer.update");
+
+    std::fs::remove_file(audit_log_file)?;
+
+    Ok(())
+}
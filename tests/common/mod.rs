@@ -13,10 +13,12 @@ use tracing::level_filters::LevelFilter;
 use tracing_subscriber::layer::SubscriberExt;
 use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
+use tx_sitter_client::apis::configuration::Configuration;
 
 pub type AppMiddleware = SignerMiddleware<Arc<Provider<Http>>, LocalWallet>;
 
 mod anvil_builder;
+pub mod remote_signer;
 mod service_builder;
 
 pub use self::anvil_builder::AnvilBuilder;
@@ -36,6 +38,7 @@ pub mod prelude {
     pub use tx_sitter_client::models::*;
     pub use url::Url;
 
+    pub use super::service_builder::TEST_ADMIN_USERNAME;
     pub use super::*;
 }
 
@@ -71,6 +74,17 @@ pub fn setup_tracing() {
         .init();
 }
 
+/// Client configuration sending the API key as a bearer token
+pub fn relayer_client(
+    client: &Configuration,
+    api_key: impl ToString,
+) -> Configuration {
+    Configuration {
+        bearer_access_token: Some(api_key.to_string()),
+        ..client.clone()
+    }
+}
+
 pub async fn setup_db() -> eyre::Result<(String, DockerContainerGuard)> {
     let db_container = postgres_docker_utils::setup().await?;
     let db_socket_addr = db_container.address();
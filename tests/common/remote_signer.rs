@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use ethers::core::types::transaction::eip2718::TypedTransaction;
+use ethers::core::types::transaction::eip712::TypedData;
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::{Address, Bytes};
+use poem::listener::{Acceptor, Listener, TcpListener};
+use poem::web::{Data, Json};
+use poem::{handler, EndpointExt, Route};
+use serde_json::{json, Value};
+
+/// Stub of a Web3Signer compatible remote signer holding local wallets
+pub struct RemoteSignerStub {
+    url: String,
+}
+
+impl RemoteSignerStub {
+    pub async fn spawn(wallets: Vec<LocalWallet>) -> eyre::Result<Self> {
+        let router = Route::new()
+            .at("/", poem::post(rpc))
+            .data(Arc::new(wallets));
+
+        let acceptor = TcpListener::bind("127.0.0.1:0").into_acceptor().await?;
+        let addr = acceptor.local_addr()[0]
+            .as_socket_addr()
+            .cloned()
+            .ok_or_else(|| eyre::eyre!("Missing socket address"))?;
+
+        tokio::spawn(poem::Server::new_with_acceptor(acceptor).run(router));
+
+        Ok(Self {
+            url: format!("http://{addr}"),
+        })
+    }
+
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+}
+
+#[handler]
+async fn rpc(
+    Json(req): Json<Value>,
+    Data(wallets): Data<&Arc<Vec<LocalWallet>>>,
+) -> Json<Value> {
+    let mut response = match handle(&req, wallets).await {
+        Ok(result) => json!({ "result": result }),
+        Err(err) => json!({
+            "error": { "code": -32000, "message": err.to_string() }
+        }),
+    };
+
+    response["jsonrpc"] = json!("2.0");
+    response["id"] = req["id"].clone();
+
+    Json(response)
+}
+
+async fn handle(req: &Value, wallets: &[LocalWallet]) -> eyre::Result<Value> {
+    let params = &req["params"];
+
+    let wallet = |address: &Value| -> eyre::Result<&LocalWallet> {
+        let address: Address = serde_json::from_value(address.clone())?;
+
+        wallets
+            .iter()
+            .find(|wallet| wallet.address() == address)
+            .ok_or_else(|| eyre::eyre!("Unknown account {address:?}"))
+    };
+
+    let result = match req["method"].as_str().unwrap_or_default() {
+        "eth_accounts" => json!(wallets
+            .iter()
+            .map(|wallet| wallet.address())
+            .collect::<Vec<_>>()),
+        "eth_sign" => {
+            let message: Bytes = serde_json::from_value(params[1].clone())?;
+            let signature =
+                wallet(&params[0])?.sign_message(&message[..]).await?;
+
+            json!(format!("0x{signature}"))
+        }
+        "eth_signTypedData" => {
+            let typed_data: TypedData =
+                serde_json::from_value(params[1].clone())?;
+            let signature =
+                wallet(&params[0])?.sign_typed_data(&typed_data).await?;
+
+            json!(format!("0x{signature}"))
+        }
+        "eth_signTransaction" => {
+            let tx: TypedTransaction =
+                serde_json::from_value(params[0].clone())?;
+            let wallet = wallet(&params[0]["from"])?;
+            let signature = wallet.sign_transaction(&tx).await?;
+
+            json!(tx.rlp_signed(&signature))
+        }
+        method => eyre::bail!("Unsupported method {method}"),
+    };
+
+    Ok(result)
+}
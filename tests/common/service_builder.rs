@@ -1,24 +1,45 @@
 use std::net::{Ipv4Addr, SocketAddr, SocketAddrV4};
+use std::path::PathBuf;
 use std::time::Duration;
 
 use ethers::utils::AnvilInstance;
+use tx_sitter::admin_auth::{hash_password, AdminRole};
 use tx_sitter::api_key::ApiKey;
 use tx_sitter::config::{
-    Config, DatabaseConfig, KeysConfig, LocalKeysConfig, Predefined,
-    PredefinedNetwork, PredefinedRelayer, ServerConfig, TxSitterConfig,
+    AdminAccount, Config, DatabaseConfig, JwtConfig, KeysConfig,
+    LeaderElectionConfig, LocalKeysConfig, Predefined, PredefinedNetwork,
+    PredefinedRelayer, RateLimitsConfig, RpcProxyConfig, ServerConfig,
+    TxSitterConfig,
 };
 use tx_sitter::service::Service;
+use tx_sitter::types::secret_string::SecretString;
 use tx_sitter_client::apis::configuration::Configuration;
 
 use super::prelude::{
-    DEFAULT_ANVIL_CHAIN_ID, DEFAULT_ANVIL_PRIVATE_KEY, DEFAULT_RELAYER_ID,
+    DEFAULT_ANVIL_ACCOUNT, DEFAULT_ANVIL_CHAIN_ID, DEFAULT_ANVIL_PRIVATE_KEY,
+    DEFAULT_RELAYER_ID,
 };
 
+/// Test only master key used to encrypt local keys
+const TEST_MASTER_KEY: [u8; 32] = [0x42; 32];
+
+/// Operator admin used by the returned client
+pub const TEST_ADMIN_USERNAME: &str = "admin";
+pub const TEST_ADMIN_PASSWORD: &str = "admin";
+
 pub struct ServiceBuilder {
     escalation_interval: Duration,
     soft_reorg_interval: Duration,
     hard_reorg_interval: Duration,
     max_escalations: usize,
+    path_api_keys: bool,
+    admins: Vec<(String, String, AdminRole)>,
+    jwt: Option<JwtConfig>,
+    audit_log_file: Option<PathBuf>,
+    rate_limits: Option<RateLimitsConfig>,
+    rpc_proxy: Option<RpcProxyConfig>,
+    leader_election: Option<LeaderElectionConfig>,
+    keys: KeysConfig,
 }
 
 impl Default for ServiceBuilder {
@@ -28,6 +49,23 @@ impl Default for ServiceBuilder {
             soft_reorg_interval: Duration::from_secs(10),
             hard_reorg_interval: Duration::from_secs(15),
             max_escalations: 100,
+            path_api_keys: false,
+            admins: vec![(
+                TEST_ADMIN_USERNAME.to_string(),
+                TEST_ADMIN_PASSWORD.to_string(),
+                AdminRole::Operator,
+            )],
+            jwt: None,
+            audit_log_file: None,
+            rate_limits: None,
+            rpc_proxy: None,
+            leader_election: None,
+            keys: KeysConfig::Local(LocalKeysConfig {
+                master_key: Some(SecretString::new(hex::encode(
+                    TEST_MASTER_KEY,
+                ))),
+                master_key_file: None,
+            }),
         }
     }
 }
@@ -53,12 +91,85 @@ impl ServiceBuilder {
         self
     }
 
+    /// Serve the relayer API with the API key in the path
+    pub fn path_api_keys(mut self, enabled: bool) -> Self {
+        self.path_api_keys = enabled;
+        self
+    }
+
+    /// Appends audit log entries to the file
+    pub fn audit_log_file(mut self, path: PathBuf) -> Self {
+        self.audit_log_file = Some(path);
+        self
+    }
+
+    /// Default limits of the relayer API keys
+    pub fn rate_limits(mut self, rate_limits: RateLimitsConfig) -> Self {
+        self.rate_limits = Some(rate_limits);
+        self
+    }
+
+    /// Settings of the relayer RPC proxy
+    pub fn rpc_proxy(mut self, rpc_proxy: RpcProxyConfig) -> Self {
+        self.rpc_proxy = Some(rpc_proxy);
+        self
+    }
+
+    /// Runs the tasks only while this instance is their leader
+    pub fn leader_election(
+        mut self,
+        leader_election: LeaderElectionConfig,
+    ) -> Self {
+        self.leader_election = Some(leader_election);
+        self
+    }
+
+    /// Adds an admin account next to the default operator
+    pub fn admin(
+        mut self,
+        username: &str,
+        password: &str,
+        role: AdminRole,
+    ) -> Self {
+        self.admins
+            .push((username.to_string(), password.to_string(), role));
+        self
+    }
+
+    pub fn jwt(mut self, jwt: JwtConfig) -> Self {
+        self.jwt = Some(jwt);
+        self
+    }
+
+    /// Key source holding the default anvil account
+    pub fn keys(mut self, keys: KeysConfig) -> Self {
+        self.keys = keys;
+        self
+    }
+
     pub async fn build(
         self,
         anvil: &AnvilInstance,
         db_url: &str,
     ) -> eyre::Result<(Service, Configuration)> {
-        let anvil_private_key = hex::encode(DEFAULT_ANVIL_PRIVATE_KEY);
+        // Non-local key sources reference the key by its address
+        let key_id = if self.keys.is_local() {
+            hex::encode(DEFAULT_ANVIL_PRIVATE_KEY)
+        } else {
+            format!("{DEFAULT_ANVIL_ACCOUNT:?}")
+        };
+
+        let admins = self
+            .admins
+            .iter()
+            .map(|(username, password, role)| {
+                Ok(AdminAccount {
+                    username: username.clone(),
+                    password_hash: hash_password(password)?,
+                    role: *role,
+                })
+            })
+            .collect::<eyre::Result<_>>()?;
 
         let config = Config {
             service: TxSitterConfig {
@@ -67,7 +178,9 @@ impl ServiceBuilder {
                 soft_reorg_interval: self.soft_reorg_interval,
                 hard_reorg_interval: self.hard_reorg_interval,
                 block_stream_timeout: Duration::from_secs(60),
+                shutdown_timeout: Duration::from_secs(30),
                 telemetry: None,
+                leader_election: self.leader_election,
                 predefined: Some(Predefined {
                     network: PredefinedNetwork {
                         chain_id: DEFAULT_ANVIL_CHAIN_ID,
@@ -78,7 +191,7 @@ impl ServiceBuilder {
                     relayer: PredefinedRelayer {
                         name: "Anvil".to_string(),
                         id: DEFAULT_RELAYER_ID.to_string(),
-                        key_id: anvil_private_key,
+                        key_id,
                         chain_id: DEFAULT_ANVIL_CHAIN_ID,
                         // TODO: Use this key in tests
                         api_key: ApiKey::random(DEFAULT_RELAYER_ID),
@@ -92,10 +205,17 @@ impl ServiceBuilder {
                 )),
                 username: None,
                 password: None,
+                admins,
+                jwt: self.jwt,
+                disable_auth: false,
                 server_address: None,
+                path_api_keys: self.path_api_keys,
+                audit_log_file: self.audit_log_file,
+                rate_limits: self.rate_limits,
+                rpc_proxy: self.rpc_proxy,
             },
             database: DatabaseConfig::connection_string(db_url),
-            keys: KeysConfig::Local(LocalKeysConfig::default()),
+            keys: self.keys,
         };
 
         let service = Service::new(config).await?;
@@ -119,7 +239,10 @@ impl ServiceBuilder {
         let client_config =
             tx_sitter_client::apis::configuration::ConfigurationBuilder::new()
                 .base_path(format!("http://{}", service.local_addr()))
-                .basic_auth("".to_string(), Some("".to_string()))
+                .basic_auth(
+                    TEST_ADMIN_USERNAME.to_string(),
+                    Some(TEST_ADMIN_PASSWORD.to_string()),
+                )
                 .build();
 
         Ok((service, client_config))
@@ -0,0 +1,109 @@
+mod common;
+
+use eyre::ContextCompat;
+use poem::http;
+use serde_json::json;
+use tx_sitter_client::apis::admin_v1_api::RelayerCreateApiKeyParams;
+use tx_sitter_client::apis::relayer_v1_api::{
+    CreateTransactionParams, GetTransactionParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+const TRANSFER_SELECTOR: [u8; 4] = hex_literal::hex!("a9059cbb");
+
+#[tokio::test]
+async fn contract_call() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    // Invalid arguments are rejected
+    let res = tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: U256::zero().into(),
+                gas_limit: U256::from(100_000).into(),
+                call: Some(ContractCall {
+                    signature: Some(
+                        "transfer(address to, uint256 amount)".to_string(),
+                    ),
+                    abi: None,
+                    args: Some(vec![json!("0x1234"), json!(1)]),
+                }),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected call with invalid arguments to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+    assert!(e.content.contains("invalid_call"));
+
+    let SendTxResponse { tx_id, .. } =
+        tx_sitter_client::apis::relayer_v1_api::create_transaction(
+            &relayer_client(&client, api_key.clone()),
+            CreateTransactionParams {
+                send_tx_request: SendTxRequest {
+                    to: Some(ARBITRARY_ADDRESS.into()),
+                    value: U256::zero().into(),
+                    gas_limit: U256::from(100_000).into(),
+                    call: Some(ContractCall {
+                        signature: Some(
+                            "transfer(address to, uint256 amount)".to_string(),
+                        ),
+                        abi: None,
+                        args: Some(vec![
+                            json!(format!("{DEFAULT_ANVIL_ACCOUNT:?}")),
+                            json!("1000"),
+                        ]),
+                    }),
+                    ..Default::default()
+                },
+            },
+        )
+        .await?;
+
+    let tx = tx_sitter_client::apis::relayer_v1_api::get_transaction(
+        &relayer_client(&client, api_key.clone()),
+        GetTransactionParams { tx_id },
+    )
+    .await?;
+
+    let data = tx.data.context("Missing calldata")?;
+    assert_eq!(&data.0[..4], &TRANSFER_SELECTOR);
+
+    let call = tx.call.context("Missing decoded call")?;
+    assert_eq!(call.function, "transfer");
+    assert_eq!(call.signature, "transfer(address,uint256)");
+    assert_eq!(call.args[0].name, "to");
+    assert_eq!(
+        call.args[0].value,
+        json!(format!("{DEFAULT_ANVIL_ACCOUNT:?}"))
+    );
+    assert_eq!(call.args[1].r#type, "uint256");
+    assert_eq!(call.args[1].value, json!("1000"));
+
+    Ok(())
+}
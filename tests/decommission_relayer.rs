@@ -0,0 +1,168 @@
+mod common;
+
+use ethers::types::Address;
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    DecommissionRelayerParams, GetAuditLogParams, GetRelayerDecommissionParams,
+    GetRelayerParams, RelayerCreateApiKeyParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn decommission_relayer() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+    let provider = setup_provider(anvil.endpoint()).await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let api_client = relayer_client(&client, &api_key);
+
+    let value: U256 = parse_units("1", "ether")?.into();
+    let send_tx = || {
+        tx_sitter_client::apis::relayer_v1_api::create_transaction(
+            &api_client,
+            CreateTransactionParams {
+                send_tx_request: SendTxRequest {
+                    to: Some(ARBITRARY_ADDRESS.into()),
+                    value: value.into(),
+                    gas_limit: U256::from(21_000).into(),
+                    ..Default::default()
+                },
+            },
+        )
+    };
+
+    // The decommission waits for this transaction to be mined
+    send_tx().await?;
+
+    let sweep_to = Address::from_low_u64_be(0xdead);
+    let decommission = |cancel_unsent| {
+        tx_sitter_client::apis::admin_v1_api::decommission_relayer(
+            &client,
+            DecommissionRelayerParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                decommission_relayer_request: DecommissionRelayerRequest {
+                    sweep_to: sweep_to.into(),
+                    cancel_unsent: Some(cancel_unsent),
+                },
+            },
+        )
+    };
+
+    tracing::info!("Decommissioning relayer");
+    let RelayerDecommissionResponse { deleted, .. } =
+        decommission(false).await?;
+    assert!(!deleted);
+
+    let Err(Error::ResponseError(e)) = send_tx().await else {
+        eyre::bail!("Expected transactions during decommission to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    let Err(Error::ResponseError(e)) = decommission(true).await else {
+        eyre::bail!("Expected a second decommission to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    let res = tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                enabled: Some(true),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected re-enabling the relayer to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    let decommission = loop {
+        let decommission =
+            tx_sitter_client::apis::admin_v1_api::get_relayer_decommission(
+                &client,
+                GetRelayerDecommissionParams {
+                    relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                },
+            )
+            .await?;
+
+        if decommission.deleted {
+            break decommission;
+        }
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    };
+
+    assert!(decommission.sweep_tx_hash.is_some());
+
+    // The queued transaction was mined before the sweep
+    let balance = provider.get_balance(ARBITRARY_ADDRESS, None).await?;
+    assert_eq!(balance, value);
+
+    let balance = provider.get_balance(sweep_to, None).await?;
+    assert!(balance > U256::zero());
+
+    let relayers =
+        tx_sitter_client::apis::admin_v1_api::get_relayers(&client).await?;
+    assert!(relayers.is_empty());
+
+    let res = tx_sitter_client::apis::admin_v1_api::get_relayer(
+        &client,
+        GetRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected the deleted relayer to be hidden");
+    };
+    assert_eq!(e.status, http::StatusCode::NOT_FOUND);
+
+    // API keys are revoked
+    let Err(Error::ResponseError(e)) = send_tx().await else {
+        eyre::bail!("Expected the API key to be revoked");
+    };
+    assert_eq!(e.status, http::StatusCode::UNAUTHORIZED);
+
+    // Completing the decommission is audited as well
+    let completed = tx_sitter_client::apis::admin_v1_api::get_audit_log(
+        &client,
+        GetAuditLogParams {
+            actor: None,
+            action: Some("relayer.complete_decommission".to_string()),
+            target: Some(DEFAULT_RELAYER_ID.to_string()),
+            since: None,
+            until: None,
+            limit: None,
+        },
+    )
+    .await?;
+    assert_eq!(completed.len(), 1);
+    assert_eq!(completed[0].actor, "system");
+
+    Ok(())
+}
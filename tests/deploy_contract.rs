@@ -0,0 +1,82 @@
+mod common;
+
+use eyre::ContextCompat;
+use tx_sitter_client::apis::admin_v1_api::RelayerCreateApiKeyParams;
+use tx_sitter_client::apis::relayer_v1_api::{
+    CreateTransactionParams, GetTransactionParams,
+};
+
+use crate::common::prelude::*;
+
+// Deploys a contract which always returns 42
+const INIT_CODE: [u8; 22] =
+    hex_literal::hex!("600a600c600039600a6000f3602a60005260206000f3");
+
+#[tokio::test]
+async fn deploy_contract() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+
+    let SendTxResponse {
+        tx_id,
+        contract_address,
+        ..
+    } = tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: None,
+                value: U256::zero().into(),
+                data: Some(INIT_CODE.to_vec().into()),
+                gas_limit: U256::from(100_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let expected_address =
+        contract_address.context("Missing expected contract address")?;
+
+    for _ in 0..50 {
+        let tx = tx_sitter_client::apis::relayer_v1_api::get_transaction(
+            &relayer_client(&client, api_key.clone()),
+            GetTransactionParams {
+                tx_id: tx_id.clone(),
+            },
+        )
+        .await?;
+
+        if let Some(contract_address) = tx.contract_address {
+            assert_eq!(tx.to, None);
+            assert_eq!(contract_address, expected_address);
+
+            let code = provider.get_code(contract_address.0, None).await?;
+            assert!(!code.is_empty(), "Contract was not deployed");
+
+            return Ok(());
+        }
+
+        tokio::time::sleep(Duration::from_secs(3)).await;
+    }
+
+    eyre::bail!("Contract address not recorded in time");
+}
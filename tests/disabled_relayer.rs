@@ -31,11 +31,14 @@ async fn disabled_relayer() -> eyre::Result<()> {
         .await?;
 
     tracing::info!("Creating API key");
-    let CreateApiKeyResponse { api_key } =
+    let CreateApiKeyResponse { api_key, .. } =
         tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
             &client,
             RelayerCreateApiKeyParams {
                 relayer_id: relayer_id.clone(),
+                name: None,
+                scopes: None,
+                expires_at: None,
             },
         )
         .await?;
@@ -55,11 +58,10 @@ async fn disabled_relayer() -> eyre::Result<()> {
 
     let value: U256 = parse_units("1", "ether")?.into();
     let response = tx_sitter_client::apis::relayer_v1_api::create_transaction(
-        &client,
+        &relayer_client(&client, api_key.clone()),
         CreateTransactionParams {
-            api_token: api_key.clone(),
             send_tx_request: SendTxRequest {
-                to: ARBITRARY_ADDRESS.into(),
+                to: Some(ARBITRARY_ADDRESS.into()),
                 value: value.into(),
                 gas_limit: U256::from(21_000).into(),
                 ..Default::default()
@@ -0,0 +1,52 @@
+mod common;
+
+use tx_sitter_client::apis::admin_v1_api::RelayerCreateApiKeyParams;
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn graceful_shutdown() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: U256::from(1).into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let health_url = format!("{}/health", client.base_path);
+    assert!(reqwest::get(&health_url).await?.status().is_success());
+
+    // Tasks stop after their current iteration, well within the timeout
+    tokio::time::timeout(Duration::from_secs(20), service.shutdown()).await??;
+
+    assert!(reqwest::get(&health_url).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,190 @@
+mod common;
+
+use ethers::signers::{LocalWallet, Signer};
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    DecommissionRelayerParams, GetRelayerDecommissionParams, GetRelayerParams,
+    ImportRelayerParams, RelayerCreateApiKeyParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn import_relayer() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let secondary_address =
+        LocalWallet::from_bytes(SECONDARY_ANVIL_PRIVATE_KEY)?.address();
+
+    // The derived address must match the expected address
+    let res = tx_sitter_client::apis::admin_v1_api::import_relayer(
+        &client,
+        ImportRelayerParams {
+            import_relayer_request: ImportRelayerRequest {
+                name: "Imported".to_string(),
+                chain_id: DEFAULT_ANVIL_CHAIN_ID as i32,
+                address: Some(ARBITRARY_ADDRESS.into()),
+                private_key: Some(hex::encode(SECONDARY_ANVIL_PRIVATE_KEY)),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected import with a mismatched address to fail");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+    assert!(e.content.contains("address_mismatch"));
+
+    // The default relayer already uses this key
+    let res = tx_sitter_client::apis::admin_v1_api::import_relayer(
+        &client,
+        ImportRelayerParams {
+            import_relayer_request: ImportRelayerRequest {
+                name: "Duplicate".to_string(),
+                chain_id: DEFAULT_ANVIL_CHAIN_ID as i32,
+                private_key: Some(hex::encode(DEFAULT_ANVIL_PRIVATE_KEY)),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected duplicate import to fail");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    let CreateRelayerResponse {
+        relayer_id,
+        address,
+    } = tx_sitter_client::apis::admin_v1_api::import_relayer(
+        &client,
+        ImportRelayerParams {
+            import_relayer_request: ImportRelayerRequest {
+                name: "Imported".to_string(),
+                chain_id: DEFAULT_ANVIL_CHAIN_ID as i32,
+                address: Some(secondary_address.into()),
+                private_key: Some(hex::encode(SECONDARY_ANVIL_PRIVATE_KEY)),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    assert_eq!(address.0, secondary_address);
+
+    // The secondary account sends a tx when spawning anvil
+    let relayer = tx_sitter_client::apis::admin_v1_api::get_relayer(
+        &client,
+        GetRelayerParams {
+            relayer_id: relayer_id.clone(),
+        },
+    )
+    .await?;
+
+    assert_eq!(relayer.nonce, 1);
+    assert_eq!(relayer.current_nonce, 1);
+    assert_ne!(relayer.key_id, hex::encode(SECONDARY_ANVIL_PRIVATE_KEY));
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id,
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let value: U256 = parse_units("1", "ether")?.into();
+    tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    await_balance(&provider, value, ARBITRARY_ADDRESS).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn import_key_of_deleted_relayer() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    tx_sitter_client::apis::admin_v1_api::decommission_relayer(
+        &client,
+        DecommissionRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            decommission_relayer_request: DecommissionRelayerRequest {
+                sweep_to: ARBITRARY_ADDRESS.into(),
+                cancel_unsent: Some(true),
+            },
+        },
+    )
+    .await?;
+
+    loop {
+        let decommission =
+            tx_sitter_client::apis::admin_v1_api::get_relayer_decommission(
+                &client,
+                GetRelayerDecommissionParams {
+                    relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                },
+            )
+            .await?;
+
+        if decommission.deleted {
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    // The nonces of the deleted relayer may still be in flight
+    let res = tx_sitter_client::apis::admin_v1_api::import_relayer(
+        &client,
+        ImportRelayerParams {
+            import_relayer_request: ImportRelayerRequest {
+                name: "Reimported".to_string(),
+                chain_id: DEFAULT_ANVIL_CHAIN_ID as i32,
+                private_key: Some(hex::encode(DEFAULT_ANVIL_PRIVATE_KEY)),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected importing the key of a deleted relayer to fail");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use tx_sitter::config::LeaderElectionConfig;
+use tx_sitter_client::apis::admin_v1_api::RelayerCreateApiKeyParams;
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+use tx_sitter_client::apis::service_api;
+
+use crate::common::prelude::*;
+
+fn leader_election(instance_id: &str) -> LeaderElectionConfig {
+    LeaderElectionConfig {
+        instance_id: Some(instance_id.to_string()),
+        lease_interval: Duration::from_secs(1),
+    }
+}
+
+#[tokio::test]
+async fn leader_election_failover() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (leader, leader_client) = ServiceBuilder::default()
+        .leader_election(leader_election("leader"))
+        .build(&anvil, &db_url)
+        .await?;
+    let (_follower, follower_client) = ServiceBuilder::default()
+        .leader_election(leader_election("follower"))
+        .build(&anvil, &db_url)
+        .await?;
+
+    let status = service_api::status(&leader_client).await?;
+    assert_eq!(status.instance_id, "leader");
+    assert!(status.leader_election);
+    assert!(status.tasks.iter().all(|task| task.leader));
+
+    let status = service_api::status(&follower_client).await?;
+    assert_eq!(status.instance_id, "follower");
+    assert!(!status.tasks.is_empty());
+    assert!(status.tasks.iter().all(|task| !task.leader));
+
+    // The follower takes over once the leader stops
+    leader.shutdown().await?;
+
+    let mut took_over = false;
+    for _ in 0..10 {
+        let status = service_api::status(&follower_client).await?;
+        if status.tasks.iter().all(|task| task.leader) {
+            took_over = true;
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+    assert!(took_over, "Follower did not take over");
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &follower_client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+
+    let value: U256 = parse_units("1", "ether")?.into();
+    tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&follower_client, api_key),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    await_balance(&provider, value, ARBITRARY_ADDRESS).await?;
+
+    Ok(())
+}
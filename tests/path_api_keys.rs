@@ -0,0 +1,77 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::RelayerCreateApiKeyParams;
+
+use crate::common::prelude::*;
+
+async fn create_api_key(client: &Configuration) -> eyre::Result<String> {
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    Ok(api_key)
+}
+
+#[tokio::test]
+async fn path_api_keys_enabled() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) = ServiceBuilder::default()
+        .path_api_keys(true)
+        .build(&anvil, &db_url)
+        .await?;
+
+    let api_key = create_api_key(&client).await?;
+
+    let res = reqwest::get(format!("{}/1/api/{api_key}/txs", client.base_path))
+        .await?;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    // The header form is served as well
+    let res = reqwest::Client::new()
+        .get(format!("{}/1/api/txs", client.base_path))
+        .bearer_auth(&api_key)
+        .send()
+        .await?;
+    assert_eq!(res.status(), http::StatusCode::OK);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn path_api_keys_disabled() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let api_key = create_api_key(&client).await?;
+
+    let res = reqwest::get(format!("{}/1/api/{api_key}/txs", client.base_path))
+        .await?;
+    assert_eq!(res.status(), http::StatusCode::NOT_FOUND);
+
+    let res = reqwest::Client::new()
+        .get(format!("{}/1/api/txs", client.base_path))
+        .bearer_auth("not-an-api-key")
+        .send()
+        .await?;
+    assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+
+    Ok(())
+}
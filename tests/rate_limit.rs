@@ -0,0 +1,135 @@
+mod common;
+
+use poem::http;
+use serde_json::json;
+use tx_sitter::api_key::ApiKey;
+use tx_sitter::config::{RateLimitConfig, RateLimitsConfig};
+use tx_sitter_client::apis::admin_v1_api::{
+    RelayerCreateApiKeyParams, UpdateRelayerParams,
+};
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn rate_limit() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) = ServiceBuilder::default()
+        .rate_limits(RateLimitsConfig {
+            rpc: Some(RateLimitConfig {
+                requests_per_minute: 1,
+                burst: 1,
+            }),
+            ..Default::default()
+        })
+        .build(&anvil, &db_url)
+        .await?;
+
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                rate_limits: Some(RelayerRateLimitsResponse {
+                    read: Some(RateLimitResponse {
+                        requests_per_minute: 6,
+                        burst: 2,
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let http_client = reqwest::Client::new();
+
+    let get_txs = || {
+        http_client
+            .get(format!("{}/1/api/txs", client.base_path))
+            .bearer_auth(&api_key)
+            .send()
+    };
+
+    let call_rpc = || {
+        http_client
+            .post(format!("{}/1/api/rpc", client.base_path))
+            .bearer_auth(&api_key)
+            .json(&json!({
+                "jsonrpc": "2.0",
+                "method": "eth_chainId",
+                "params": [],
+                "id": 1,
+            }))
+            .send()
+    };
+
+    // The relayer allows a burst of 2 reads
+    assert_eq!(get_txs().await?.status(), http::StatusCode::OK);
+    assert_eq!(get_txs().await?.status(), http::StatusCode::OK);
+
+    let res = get_txs().await?;
+    assert_eq!(res.status(), http::StatusCode::TOO_MANY_REQUESTS);
+    let retry_after: u64 = res
+        .headers()
+        .get(http::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| eyre::eyre!("Missing Retry-After header"))?;
+    assert!((1..=10).contains(&retry_after));
+
+    // Unknown keys are rejected before taking a token
+    for _ in 0..3 {
+        let res = http_client
+            .get(format!("{}/1/api/txs", client.base_path))
+            .bearer_auth(ApiKey::random(DEFAULT_RELAYER_ID).reveal()?)
+            .send()
+            .await?;
+        assert_eq!(res.status(), http::StatusCode::UNAUTHORIZED);
+    }
+
+    // Other route classes use the defaults
+    assert_eq!(call_rpc().await?.status(), http::StatusCode::OK);
+    assert_eq!(
+        call_rpc().await?.status(),
+        http::StatusCode::TOO_MANY_REQUESTS
+    );
+
+    // Invalid limits are rejected
+    let res = tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                rate_limits: Some(RelayerRateLimitsResponse {
+                    send: Some(RateLimitResponse {
+                        requests_per_minute: 0,
+                        burst: 1,
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    CreateNetworkParams, GetRelayerParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn relayer_balances() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    let expected_balance =
+        provider.get_balance(DEFAULT_ANVIL_ACCOUNT, None).await?;
+
+    // The native balance is recorded by the funds monitor
+    let balances = loop {
+        let relayer = tx_sitter_client::apis::admin_v1_api::get_relayer(
+            &client,
+            GetRelayerParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            },
+        )
+        .await?;
+
+        match relayer.balances {
+            Some(balances) if !balances.is_empty() => break balances,
+            _ => tokio::time::sleep(Duration::from_secs(1)).await,
+        }
+    };
+
+    assert_eq!(balances.len(), 1);
+    assert_eq!(balances[0].token, None);
+    assert_eq!(balances[0].balance.0, expected_balance);
+
+    let network_low_balance: U256 = parse_units("20000", "ether")?.into();
+
+    tx_sitter_client::apis::admin_v1_api::create_network(
+        &client,
+        CreateNetworkParams {
+            chain_id: DEFAULT_ANVIL_CHAIN_ID as i32,
+            create_network_request: CreateNetworkRequest {
+                name: "Anvil".to_string(),
+                http_rpc: anvil.endpoint(),
+                ws_rpc: anvil.ws_endpoint(),
+                low_balance: Some(network_low_balance.into()),
+            },
+        },
+    )
+    .await?;
+
+    let networks =
+        tx_sitter_client::apis::admin_v1_api::get_networks(&client).await?;
+    assert_eq!(
+        networks[0].low_balance.as_ref().map(|v| v.0),
+        Some(network_low_balance)
+    );
+
+    let update_funding = |funding: RelayerFundingResponse| {
+        tx_sitter_client::apis::admin_v1_api::update_relayer(
+            &client,
+            UpdateRelayerParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                relayer_update_request: RelayerUpdateRequest {
+                    funding: Some(funding),
+                    ..Default::default()
+                },
+            },
+        )
+    };
+
+    // Relayers without a low balance fall back to the network's
+    let target_balance: U256 = parse_units("15000", "ether")?.into();
+    let res = update_funding(RelayerFundingResponse {
+        target_balance: Some(target_balance.into()),
+        ..Default::default()
+    })
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!(
+            "Expected a target below the network low balance to be rejected"
+        );
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    update_funding(RelayerFundingResponse {
+        low_balance: Some(U256::from(1).into()),
+        target_balance: Some(target_balance.into()),
+        ..Default::default()
+    })
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    RelayerCreateApiKeyParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn relayer_budget() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let max_tx_value: U256 = parse_units("1", "ether")?.into();
+    let max_window_value: U256 = parse_units("1.5", "ether")?.into();
+
+    tracing::info!("Updating relayer budget");
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                budget: Some(RelayerBudgetResponse {
+                    max_tx_value: Some(max_tx_value.into()),
+                    max_window_value: Some(max_window_value.into()),
+                    max_window_gas_spend: None,
+                    window_seconds: 60 * 60,
+                }),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    // A tx over the max tx value should be rejected
+    let value: U256 = parse_units("2", "ether")?.into();
+    let res = tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected tx over the max tx value to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(e.content.contains("tx_value_limit_exceeded"));
+
+    // A tx within the budget should go through
+    let value: U256 = parse_units("1", "ether")?.into();
+    tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    // But the next one exceeds the window budget
+    let res = tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected tx over the window budget to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::TOO_MANY_REQUESTS);
+    assert!(e.content.contains("value_budget_exceeded"));
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    await_balance(&provider, value, ARBITRARY_ADDRESS).await?;
+
+    Ok(())
+}
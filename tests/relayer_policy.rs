@@ -0,0 +1,116 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    RelayerCreateApiKeyParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn relayer_policy() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    // Selectors must be 4 bytes long
+    let res = tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                policy: Some(RelayerPolicyResponse {
+                    allowed_targets: Some(vec![AllowedTargetResponse {
+                        address: ARBITRARY_ADDRESS.into(),
+                        selectors: Some(vec![vec![1, 2, 3].into()]),
+                    }]),
+                    allow_contract_creation: false,
+                }),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected invalid selector to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    tracing::info!("Updating relayer policy");
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                policy: Some(RelayerPolicyResponse {
+                    allowed_targets: Some(vec![AllowedTargetResponse {
+                        address: ARBITRARY_ADDRESS.into(),
+                        selectors: None,
+                    }]),
+                    allow_contract_creation: false,
+                }),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let value: U256 = parse_units("1", "ether")?.into();
+
+    // Destination outside of the allowlist should be rejected
+    let res = tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(DEFAULT_ANVIL_ACCOUNT.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected tx to a disallowed destination to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+    assert!(e.content.contains("destination_not_allowed"));
+
+    tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    await_balance(&provider, value, ARBITRARY_ADDRESS).await?;
+
+    Ok(())
+}
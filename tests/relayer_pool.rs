@@ -0,0 +1,215 @@
+mod common;
+
+use poem::http;
+use serde_json::{json, Value};
+use tx_sitter_client::apis::admin_v1_api::{
+    AddRelayerPoolMemberParams, CreateRelayerParams,
+    CreateRelayerPoolApiKeyParams, CreateRelayerPoolParams, GetRelayerParams,
+    RemoveRelayerPoolMemberParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::{
+    CreateTransactionParams, GetTransactionParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn relayer_pool() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let CreateRelayerResponse {
+        relayer_id,
+        address,
+    } = tx_sitter_client::apis::admin_v1_api::create_relayer(
+        &client,
+        CreateRelayerParams {
+            create_relayer_request: CreateRelayerRequest::new(
+                "Pool relayer".to_string(),
+                DEFAULT_ANVIL_CHAIN_ID as i32,
+            ),
+        },
+    )
+    .await?;
+
+    let RelayerPoolResponse { id: pool_id, .. } =
+        tx_sitter_client::apis::admin_v1_api::create_relayer_pool(
+            &client,
+            CreateRelayerPoolParams {
+                create_relayer_pool_request: CreateRelayerPoolRequest {
+                    relayer_ids: Some(vec![DEFAULT_RELAYER_ID.to_string()]),
+                    ..CreateRelayerPoolRequest::new(
+                        "Pool".to_string(),
+                        DEFAULT_ANVIL_CHAIN_ID as i32,
+                    )
+                },
+            },
+        )
+        .await?;
+
+    tx_sitter_client::apis::admin_v1_api::add_relayer_pool_member(
+        &client,
+        AddRelayerPoolMemberParams {
+            pool_id: pool_id.clone(),
+            relayer_id: relayer_id.clone(),
+        },
+    )
+    .await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::create_relayer_pool_api_key(
+            &client,
+            CreateRelayerPoolApiKeyParams {
+                pool_id: pool_id.clone(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let api_client = relayer_client(&client, &api_key);
+
+    let value: U256 = parse_units("1", "ether")?.into();
+    let send_tx = |route_key: Option<&str>| {
+        tx_sitter_client::apis::relayer_v1_api::create_transaction(
+            &api_client,
+            CreateTransactionParams {
+                send_tx_request: SendTxRequest {
+                    to: Some(ARBITRARY_ADDRESS.into()),
+                    value: value.into(),
+                    gas_limit: U256::from(21_000).into(),
+                    route_key: route_key.map(|key| key.to_string()),
+                    ..Default::default()
+                },
+            },
+        )
+    };
+
+    // The new relayer is unfunded, txs go to the default relayer
+    let SendTxResponse {
+        tx_id,
+        relayer_id: routed_to,
+        ..
+    } = send_tx(Some("ordered")).await?;
+    assert_eq!(routed_to.as_deref(), Some(DEFAULT_RELAYER_ID));
+
+    let tx = tx_sitter_client::apis::relayer_v1_api::get_transaction(
+        &api_client,
+        GetTransactionParams { tx_id },
+    )
+    .await?;
+    assert_eq!(tx.value.0, value);
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    let middleware =
+        setup_middleware(anvil.endpoint(), SECONDARY_ANVIL_PRIVATE_KEY).await?;
+    middleware
+        .send_transaction(
+            Eip1559TransactionRequest {
+                to: Some(address.0.into()),
+                value: Some(parse_units("10", "ether")?.into()),
+                ..Default::default()
+            },
+            None,
+        )
+        .await?
+        .await?;
+
+    // Txs with the same route key stick to one relayer
+    let SendTxResponse {
+        relayer_id: routed_to,
+        ..
+    } = send_tx(Some("ordered")).await?;
+    assert_eq!(routed_to.as_deref(), Some(DEFAULT_RELAYER_ID));
+
+    await_balance(&provider, value * 2, ARBITRARY_ADDRESS).await?;
+
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                enabled: Some(false),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    // Disabled relayers are skipped
+    let SendTxResponse {
+        relayer_id: routed_to,
+        ..
+    } = send_tx(None).await?;
+    assert_eq!(routed_to.as_deref(), Some(relayer_id.as_str()));
+
+    let default_relayer = tx_sitter_client::apis::admin_v1_api::get_relayer(
+        &client,
+        GetRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await?;
+
+    // Txs sent over RPC from a member are not routed to other members
+    let res: Value = reqwest::Client::new()
+        .post(format!("{}/1/api/rpc", client.base_path))
+        .bearer_auth(&api_key)
+        .json(&json!({
+            "jsonrpc": "2.0",
+            "method": "eth_sendTransaction",
+            "params": [{
+                "from": default_relayer.address,
+                "to": ARBITRARY_ADDRESS,
+                "value": value,
+                "gas": U256::from(21_000),
+            }],
+            "id": 1,
+        }))
+        .send()
+        .await?
+        .json()
+        .await?;
+    assert!(res["error"].is_object(), "Expected an error, got {res}");
+
+    tx_sitter_client::apis::admin_v1_api::remove_relayer_pool_member(
+        &client,
+        RemoveRelayerPoolMemberParams {
+            pool_id: pool_id.clone(),
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await?;
+
+    // Routes pinned to removed members are moved
+    let SendTxResponse {
+        relayer_id: routed_to,
+        ..
+    } = send_tx(Some("ordered")).await?;
+    assert_eq!(routed_to.as_deref(), Some(relayer_id.as_str()));
+
+    await_balance(&provider, value * 4, ARBITRARY_ADDRESS).await?;
+
+    let res = tx_sitter_client::apis::admin_v1_api::remove_relayer_pool_member(
+        &client,
+        RemoveRelayerPoolMemberParams {
+            pool_id,
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected removing a non member to fail");
+    };
+    assert_eq!(e.status, http::StatusCode::NOT_FOUND);
+
+    Ok(())
+}
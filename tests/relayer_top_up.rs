@@ -0,0 +1,94 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    CreateRelayerParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn relayer_top_up() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let CreateRelayerResponse {
+        relayer_id,
+        address,
+    } = tx_sitter_client::apis::admin_v1_api::create_relayer(
+        &client,
+        CreateRelayerParams {
+            create_relayer_request: CreateRelayerRequest::new(
+                "Topped up relayer".to_string(),
+                DEFAULT_ANVIL_CHAIN_ID as i32,
+            ),
+        },
+    )
+    .await?;
+
+    let low_balance: U256 = parse_units("1", "ether")?.into();
+    let target_balance: U256 = parse_units("2", "ether")?.into();
+
+    let update_funding = |funding: RelayerFundingResponse| {
+        tx_sitter_client::apis::admin_v1_api::update_relayer(
+            &client,
+            UpdateRelayerParams {
+                relayer_id: relayer_id.clone(),
+                relayer_update_request: RelayerUpdateRequest {
+                    funding: Some(funding),
+                    ..Default::default()
+                },
+            },
+        )
+    };
+
+    let res = update_funding(RelayerFundingResponse {
+        low_balance: Some(target_balance.into()),
+        target_balance: Some(low_balance.into()),
+        treasury_relayer_id: Some(DEFAULT_RELAYER_ID.to_string()),
+        tokens: None,
+    })
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected a target below the low balance to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    let res = update_funding(RelayerFundingResponse {
+        low_balance: Some(low_balance.into()),
+        target_balance: Some(target_balance.into()),
+        treasury_relayer_id: Some(relayer_id.clone()),
+        tokens: None,
+    })
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected a relayer funding itself to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::BAD_REQUEST);
+
+    update_funding(RelayerFundingResponse {
+        low_balance: Some(low_balance.into()),
+        target_balance: Some(target_balance.into()),
+        treasury_relayer_id: Some(DEFAULT_RELAYER_ID.to_string()),
+        tokens: None,
+    })
+    .await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    await_balance(&provider, target_balance, address.0).await?;
+
+    // A single top-up is sent
+    tokio::time::sleep(Duration::from_secs(20)).await;
+    let balance = provider.get_balance(address.0, None).await?;
+    assert_eq!(balance, target_balance);
+
+    Ok(())
+}
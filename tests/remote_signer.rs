@@ -0,0 +1,235 @@
+mod common;
+
+use ethers::signers::{LocalWallet, Signer};
+use ethers::types::Signature;
+use poem::http;
+use serde_json::json;
+use tx_sitter::config::{KeysConfig, RemoteKeysConfig};
+use tx_sitter_client::apis::admin_v1_api::{
+    CreateRelayerParams, DecommissionRelayerParams,
+    GetRelayerDecommissionParams, RelayerCreateApiKeyParams,
+    UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::{
+    CreateTransactionParams, SignMessageParams, SignTypedDataParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+use crate::common::remote_signer::RemoteSignerStub;
+
+#[tokio::test]
+async fn remote_signer() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let secondary_wallet =
+        LocalWallet::from_bytes(SECONDARY_ANVIL_PRIVATE_KEY)?;
+    let remote_signer = RemoteSignerStub::spawn(vec![
+        LocalWallet::from_bytes(DEFAULT_ANVIL_PRIVATE_KEY)?,
+        secondary_wallet.clone(),
+    ])
+    .await?;
+
+    let (_service, client) = ServiceBuilder::default()
+        .keys(KeysConfig::Remote(RemoteKeysConfig {
+            url: remote_signer.url().to_string(),
+            auth_token: None,
+        }))
+        .build(&anvil, &db_url)
+        .await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    // Transactions are signed by the remote signer
+    let value: U256 = parse_units("1", "ether")?.into();
+    tx_sitter_client::apis::relayer_v1_api::create_transaction(
+        &relayer_client(&client, api_key.clone()),
+        CreateTransactionParams {
+            send_tx_request: SendTxRequest {
+                to: Some(ARBITRARY_ADDRESS.into()),
+                value: value.into(),
+                gas_limit: U256::from(21_000).into(),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    await_balance(&provider, value, ARBITRARY_ADDRESS).await?;
+
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                signing_enabled: Some(true),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let SignatureResponse { hash, signature } =
+        tx_sitter_client::apis::relayer_v1_api::sign_message(
+            &relayer_client(&client, api_key.clone()),
+            SignMessageParams {
+                sign_message_request: SignMessageRequest {
+                    message: b"remote".to_vec().into(),
+                },
+            },
+        )
+        .await?;
+
+    let signature = Signature::try_from(&signature.0[..])?;
+    assert_eq!(signature.recover(hash.0)?, DEFAULT_ANVIL_ACCOUNT);
+
+    let SignatureResponse { hash, signature } =
+        tx_sitter_client::apis::relayer_v1_api::sign_typed_data(
+            &relayer_client(&client, api_key),
+            SignTypedDataParams {
+                sign_typed_data_request: SignTypedDataRequest {
+                    typed_data: json!({
+                        "types": {
+                            "EIP712Domain": [
+                                { "name": "name", "type": "string" },
+                                { "name": "chainId", "type": "uint256" }
+                            ],
+                            "Login": [
+                                { "name": "nonce", "type": "uint256" }
+                            ]
+                        },
+                        "primaryType": "Login",
+                        "domain": { "name": "tx-sitter", "chainId": 31337 },
+                        "message": { "nonce": 1 }
+                    }),
+                },
+            },
+        )
+        .await?;
+
+    let signature = Signature::try_from(&signature.0[..])?;
+    assert_eq!(signature.recover(hash.0)?, DEFAULT_ANVIL_ACCOUNT);
+
+    // New relayers are assigned unused remote keys
+    let CreateRelayerResponse { address, .. } =
+        tx_sitter_client::apis::admin_v1_api::create_relayer(
+            &client,
+            CreateRelayerParams {
+                create_relayer_request: CreateRelayerRequest::new(
+                    "Remote relayer".to_string(),
+                    DEFAULT_ANVIL_CHAIN_ID as i32,
+                ),
+            },
+        )
+        .await?;
+
+    assert_eq!(address.0, secondary_wallet.address());
+
+    let res = tx_sitter_client::apis::admin_v1_api::create_relayer(
+        &client,
+        CreateRelayerParams {
+            create_relayer_request: CreateRelayerRequest::new(
+                "Remote relayer".to_string(),
+                DEFAULT_ANVIL_CHAIN_ID as i32,
+            ),
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected relayer creation without unused keys to fail");
+    };
+    assert_eq!(e.status, http::StatusCode::INTERNAL_SERVER_ERROR);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remote_signer_skips_decommissioned_keys() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let secondary_wallet =
+        LocalWallet::from_bytes(SECONDARY_ANVIL_PRIVATE_KEY)?;
+    let remote_signer = RemoteSignerStub::spawn(vec![
+        LocalWallet::from_bytes(DEFAULT_ANVIL_PRIVATE_KEY)?,
+        secondary_wallet.clone(),
+    ])
+    .await?;
+
+    let (_service, client) = ServiceBuilder::default()
+        .keys(KeysConfig::Remote(RemoteKeysConfig {
+            url: remote_signer.url().to_string(),
+            auth_token: None,
+        }))
+        .build(&anvil, &db_url)
+        .await?;
+
+    tx_sitter_client::apis::admin_v1_api::decommission_relayer(
+        &client,
+        DecommissionRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            decommission_relayer_request: DecommissionRelayerRequest {
+                sweep_to: ARBITRARY_ADDRESS.into(),
+                cancel_unsent: Some(true),
+            },
+        },
+    )
+    .await?;
+
+    loop {
+        let decommission =
+            tx_sitter_client::apis::admin_v1_api::get_relayer_decommission(
+                &client,
+                GetRelayerDecommissionParams {
+                    relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                },
+            )
+            .await?;
+
+        if decommission.deleted {
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_secs(1)).await;
+    }
+
+    // The key of the deleted relayer is skipped
+    let create_relayer = || {
+        tx_sitter_client::apis::admin_v1_api::create_relayer(
+            &client,
+            CreateRelayerParams {
+                create_relayer_request: CreateRelayerRequest::new(
+                    "Remote relayer".to_string(),
+                    DEFAULT_ANVIL_CHAIN_ID as i32,
+                ),
+            },
+        )
+    };
+
+    let CreateRelayerResponse { address, .. } = create_relayer().await?;
+    assert_eq!(address.0, secondary_wallet.address());
+
+    let Err(Error::ResponseError(e)) = create_relayer().await else {
+        eyre::bail!("Expected the key of the deleted relayer not to be reused");
+    };
+    assert_eq!(e.status, http::StatusCode::INTERNAL_SERVER_ERROR);
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+mod common;
+
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    GetRelayerParams, RelayerCreateApiKeyParams, RotateRelayerKeyParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::CreateTransactionParams;
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn rotate_relayer_key() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+    let provider = setup_provider(anvil.endpoint()).await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let api_client = relayer_client(&client, &api_key);
+
+    let value: U256 = parse_units("1", "ether")?.into();
+    let send_tx = || {
+        tx_sitter_client::apis::relayer_v1_api::create_transaction(
+            &api_client,
+            CreateTransactionParams {
+                send_tx_request: SendTxRequest {
+                    to: Some(ARBITRARY_ADDRESS.into()),
+                    value: value.into(),
+                    gas_limit: U256::from(21_000).into(),
+                    ..Default::default()
+                },
+            },
+        )
+    };
+
+    // The rotation waits for this transaction to be mined
+    send_tx().await?;
+
+    tracing::info!("Rotating relayer key");
+    let RotateRelayerKeyResponse {
+        previous_address,
+        address,
+    } = tx_sitter_client::apis::admin_v1_api::rotate_relayer_key(
+        &client,
+        RotateRelayerKeyParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await?;
+
+    assert_eq!(previous_address.0, DEFAULT_ANVIL_ACCOUNT);
+    assert_ne!(address.0, DEFAULT_ANVIL_ACCOUNT);
+
+    let Err(Error::ResponseError(e)) = send_tx().await else {
+        eyre::bail!("Expected transactions during rotation to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    let res = tx_sitter_client::apis::admin_v1_api::rotate_relayer_key(
+        &client,
+        RotateRelayerKeyParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected a second rotation to be rejected");
+    };
+    assert_eq!(e.status, http::StatusCode::CONFLICT);
+
+    tracing::info!("Waiting for the rotation to complete");
+    for _ in 0..50 {
+        let relayer = tx_sitter_client::apis::admin_v1_api::get_relayer(
+            &client,
+            GetRelayerParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            },
+        )
+        .await?;
+
+        if relayer.address.0 == address.0 {
+            break;
+        }
+
+        tokio::time::sleep(Duration::from_secs(2)).await;
+    }
+
+    // The remaining balance was swept to the new key
+    let old_balance = provider.get_balance(DEFAULT_ANVIL_ACCOUNT, None).await?;
+    let new_balance = provider.get_balance(address.0, None).await?;
+    assert!(new_balance > value);
+    assert!(old_balance < value);
+
+    // Transactions are sent from the new key
+    send_tx().await?;
+
+    let expected_balance = value * 2;
+    await_balance(&provider, expected_balance, ARBITRARY_ADDRESS).await?;
+
+    Ok(())
+}
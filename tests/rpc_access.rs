@@ -1,6 +1,8 @@
 mod common;
 
+use tx_sitter::config::RpcProxyConfig;
 use tx_sitter_client::apis::admin_v1_api::RelayerCreateApiKeyParams;
+use tx_sitter_client::apis::relayer_v1_api::CallRpcParams;
 
 use crate::common::prelude::*;
 
@@ -11,14 +13,19 @@ async fn rpc_access() -> eyre::Result<()> {
     let (db_url, _db_container) = setup_db().await?;
     let anvil = AnvilBuilder::default().spawn().await?;
 
-    let (service, client) =
-        ServiceBuilder::default().build(&anvil, &db_url).await?;
+    let (service, client) = ServiceBuilder::default()
+        .path_api_keys(true)
+        .build(&anvil, &db_url)
+        .await?;
 
-    let CreateApiKeyResponse { api_key } =
+    let CreateApiKeyResponse { api_key, .. } =
         tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
             &client,
             RelayerCreateApiKeyParams {
                 relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
             },
         )
         .await?;
@@ -37,3 +44,103 @@ async fn rpc_access() -> eyre::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn rpc_batches_and_denied_methods() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) = ServiceBuilder::default()
+        .rpc_proxy(RpcProxyConfig {
+            cache_ttl: Duration::from_secs(60 * 60),
+            ..Default::default()
+        })
+        .build(&anvil, &db_url)
+        .await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let client = relayer_client(&client, api_key);
+
+    let request = |id: i32, method: &str| RpcRequest {
+        id,
+        method: method.to_string(),
+        params: Some(Some(serde_json::json!([]))),
+        jsonrpc: JsonRpcVersion::Variant2Period0,
+    };
+
+    let responses = tx_sitter_client::apis::relayer_v1_api::call_rpc(
+        &client,
+        CallRpcParams {
+            rpc_payload: RpcPayload::Array(vec![
+                request(7, "eth_chainId"),
+                request(7, "debug_traceBlock"),
+                request(9, "eth_blockNumber"),
+            ]),
+        },
+    )
+    .await?;
+
+    let responses = responses.as_array().cloned().unwrap_or_default();
+    assert_eq!(responses.len(), 3);
+
+    // Responses keep the order and ids of the batch
+    assert_eq!(responses[0]["id"], 7);
+    assert_eq!(
+        responses[0]["result"],
+        format!("{DEFAULT_ANVIL_CHAIN_ID:#x}")
+    );
+    assert_eq!(responses[1]["id"], 7);
+    assert_eq!(responses[1]["error"]["code"], -32601);
+    assert_eq!(responses[2]["id"], 9);
+    let cached_block_number = responses[2]["result"].clone();
+
+    // Block numbers are served from the cache
+    tokio::time::sleep(Duration::from_secs(DEFAULT_ANVIL_BLOCK_TIME * 2)).await;
+
+    let response = tx_sitter_client::apis::relayer_v1_api::call_rpc(
+        &client,
+        CallRpcParams {
+            rpc_payload: RpcPayload::RpcRequest(Box::new(request(
+                1,
+                "eth_blockNumber",
+            ))),
+        },
+    )
+    .await?;
+    assert_eq!(response["result"], cached_block_number);
+
+    let provider = setup_provider(anvil.endpoint()).await?;
+    let latest_block_number = provider.get_block_number().await?;
+    assert_ne!(
+        serde_json::json!(format!("{latest_block_number:#x}")),
+        cached_block_number
+    );
+
+    // Oversized batches are rejected
+    let res = tx_sitter_client::apis::relayer_v1_api::call_rpc(
+        &client,
+        CallRpcParams {
+            rpc_payload: RpcPayload::Array(vec![
+                request(1, "eth_chainId");
+                101
+            ]),
+        },
+    )
+    .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
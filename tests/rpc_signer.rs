@@ -0,0 +1,104 @@
+mod common;
+
+use ethers::types::TransactionRequest;
+use tx_sitter_client::apis::admin_v1_api::{
+    GetRelayerParams, RelayerCreateApiKeyParams, UpdateRelayerParams,
+};
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn rpc_signer() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (service, client) = ServiceBuilder::default()
+        .path_api_keys(true)
+        .build(&anvil, &db_url)
+        .await?;
+
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+            relayer_update_request: RelayerUpdateRequest {
+                signing_enabled: Some(true),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let relayer = tx_sitter_client::apis::admin_v1_api::get_relayer(
+        &client,
+        GetRelayerParams {
+            relayer_id: DEFAULT_RELAYER_ID.to_string(),
+        },
+    )
+    .await?;
+    let relayer_address = relayer.address.0;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let rpc_url =
+        format!("http://{}/1/api/{}/rpc", service.local_addr(), api_key);
+    let provider = Provider::new(Http::new(rpc_url.parse::<Url>()?))
+        .interval(Duration::from_millis(500));
+
+    // The relayer acts as the only unlocked account
+    let accounts = provider.get_accounts().await?;
+    assert_eq!(accounts, vec![relayer_address]);
+
+    // Transactions are queued by tx-sitter and resolve to their receipt
+    let value: U256 = parse_units("1", "ether")?.into();
+    let receipt = provider
+        .send_transaction(
+            TransactionRequest::new()
+                .from(relayer_address)
+                .to(ARBITRARY_ADDRESS)
+                .value(value),
+            None,
+        )
+        .await?
+        // Transactions are unknown until tx-sitter sends them
+        .retries(20)
+        .await?
+        .ok_or_else(|| eyre::eyre!("Missing receipt"))?;
+
+    assert_eq!(receipt.from, relayer_address);
+    assert_eq!(receipt.to, Some(ARBITRARY_ADDRESS));
+
+    let anvil_provider = setup_provider(anvil.endpoint()).await?;
+    await_balance(&anvil_provider, value, ARBITRARY_ADDRESS).await?;
+
+    // Messages are signed with the relayer key
+    let message = b"Login challenge: 1234".to_vec();
+    let signature = provider.sign(message.clone(), &relayer_address).await?;
+    assert_eq!(signature.recover(message)?, relayer_address);
+
+    // Other accounts are unknown
+    let res = provider
+        .send_transaction(
+            TransactionRequest::new()
+                .from(ARBITRARY_ADDRESS)
+                .to(relayer_address)
+                .value(1),
+            None,
+        )
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
@@ -21,11 +21,14 @@ async fn send_too_many_txs() -> eyre::Result<()> {
     let (_service, client) =
         ServiceBuilder::default().build(&anvil, &db_url).await?;
 
-    let CreateApiKeyResponse { api_key } =
+    let CreateApiKeyResponse { api_key, .. } =
         tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
             &client,
             RelayerCreateApiKeyParams {
                 relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
             },
         )
         .await?;
@@ -49,17 +52,18 @@ async fn send_too_many_txs() -> eyre::Result<()> {
 
     // Send some funds to created relayer
     tx_sitter_client::apis::relayer_v1_api::create_transaction(
-        &client,
+        &relayer_client(&client, api_key.clone()),
         CreateTransactionParams {
-            api_token: api_key.clone(),
             send_tx_request: SendTxRequest {
-                to: secondary_relayer_address.clone(),
+                to: Some(secondary_relayer_address.clone()),
                 value: init_value.into(),
                 data: None,
                 gas_limit: U256::from(21_000).into(),
                 priority: Some(TransactionPriority::Regular),
                 tx_id: None,
                 blobs: None,
+                call: None,
+                route_key: None,
             },
         },
     )
@@ -71,10 +75,14 @@ async fn send_too_many_txs() -> eyre::Result<()> {
 
     let CreateApiKeyResponse {
         api_key: secondary_api_key,
+        ..
     } = tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
         &client,
         RelayerCreateApiKeyParams {
             relayer_id: secondary_relayer_id.clone(),
+            name: None,
+            scopes: None,
+            expires_at: None,
         },
     )
     .await?;
@@ -99,17 +107,18 @@ async fn send_too_many_txs() -> eyre::Result<()> {
     tracing::info!("Sending txs");
     for _ in 0..=MAX_QUEUED_TXS {
         tx_sitter_client::apis::relayer_v1_api::create_transaction(
-            &client,
+            &relayer_client(&client, secondary_api_key.clone()),
             CreateTransactionParams {
-                api_token: secondary_api_key.clone(),
                 send_tx_request: SendTxRequest {
-                    to: ARBITRARY_ADDRESS.into(),
+                    to: Some(ARBITRARY_ADDRESS.into()),
                     value: value.into(),
                     data: None,
                     gas_limit: U256::from(21_000).into(),
                     priority: Some(TransactionPriority::Regular),
                     tx_id: None,
                     blobs: None,
+                    call: None,
+                    route_key: None,
                 },
             },
         )
@@ -118,17 +127,18 @@ async fn send_too_many_txs() -> eyre::Result<()> {
 
     // Sending one more tx should fail
     let res = tx_sitter_client::apis::relayer_v1_api::create_transaction(
-        &client,
+        &relayer_client(&client, secondary_api_key.clone()),
         CreateTransactionParams {
-            api_token: secondary_api_key.clone(),
             send_tx_request: SendTxRequest {
-                to: ARBITRARY_ADDRESS.into(),
+                to: Some(ARBITRARY_ADDRESS.into()),
                 value: value.into(),
                 data: None,
                 gas_limit: U256::from(21_000).into(),
                 priority: Some(TransactionPriority::Regular),
                 tx_id: None,
                 blobs: None,
+                call: None,
+                route_key: None,
             },
         },
     )
@@ -146,17 +156,18 @@ async fn send_too_many_txs() -> eyre::Result<()> {
     let total_required_value = send_value + parse_units("1", "ether")?;
 
     tx_sitter_client::apis::relayer_v1_api::create_transaction(
-        &client,
+        &relayer_client(&client, api_key.clone()),
         CreateTransactionParams {
-            api_token: api_key.clone(),
             send_tx_request: SendTxRequest {
-                to: secondary_relayer_address.clone(),
+                to: Some(secondary_relayer_address.clone()),
                 value: total_required_value.into(),
                 data: None,
                 gas_limit: U256::from(21_000).into(),
                 priority: Some(TransactionPriority::Regular),
                 tx_id: None,
                 blobs: None,
+                call: None,
+                route_key: None,
             },
         },
     )
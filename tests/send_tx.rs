@@ -14,11 +14,14 @@ async fn send_tx() -> eyre::Result<()> {
 
     let (_service, client) =
         ServiceBuilder::default().build(&anvil, &db_url).await?;
-    let CreateApiKeyResponse { api_key } =
+    let CreateApiKeyResponse { api_key, .. } =
         tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
             &client,
             RelayerCreateApiKeyParams {
                 relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
             },
         )
         .await?;
@@ -28,11 +31,10 @@ async fn send_tx() -> eyre::Result<()> {
     // Send a transaction
     let value: U256 = parse_units("1", "ether")?.into();
     tx_sitter_client::apis::relayer_v1_api::create_transaction(
-        &client,
+        &relayer_client(&client, api_key.clone()),
         CreateTransactionParams {
-            api_token: api_key.clone(),
             send_tx_request: SendTxRequest {
-                to: ARBITRARY_ADDRESS.into(),
+                to: Some(ARBITRARY_ADDRESS.into()),
                 value: value.into(),
                 gas_limit: U256::from(21_000).into(),
                 ..Default::default()
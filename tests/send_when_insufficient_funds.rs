@@ -25,11 +25,14 @@ async fn send_when_insufficient_funds() -> eyre::Result<()> {
         .build(&anvil, &db_url)
         .await?;
 
-    let CreateApiKeyResponse { api_key } =
+    let CreateApiKeyResponse { api_key, .. } =
         tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
             &client,
             RelayerCreateApiKeyParams {
                 relayer_id: DEFAULT_RELAYER_ID.to_string(),
+                name: None,
+                scopes: None,
+                expires_at: None,
             },
         )
         .await?;
@@ -40,11 +43,10 @@ async fn send_when_insufficient_funds() -> eyre::Result<()> {
     let value: U256 = parse_units("9999.9999", "ether")?.into();
 
     tx_sitter_client::apis::relayer_v1_api::create_transaction(
-        &client,
+        &relayer_client(&client, api_key.clone()),
         CreateTransactionParams {
-            api_token: api_key.clone(),
             send_tx_request: SendTxRequest {
-                to: ARBITRARY_ADDRESS.into(),
+                to: Some(ARBITRARY_ADDRESS.into()),
                 value: value.into(),
                 gas_limit: U256::from(21_000).into(),
                 ..Default::default()
@@ -57,11 +59,10 @@ async fn send_when_insufficient_funds() -> eyre::Result<()> {
 
     for _ in 0..5 {
         let tx = tx_sitter_client::apis::relayer_v1_api::create_transaction(
-            &client,
+            &relayer_client(&client, api_key.clone()),
             CreateTransactionParams {
-                api_token: api_key.clone(),
                 send_tx_request: SendTxRequest {
-                    to: ARBITRARY_ADDRESS.into(),
+                    to: Some(ARBITRARY_ADDRESS.into()),
                     value: value.into(),
                     gas_limit: U256::from(21_000).into(),
                     ..Default::default()
@@ -0,0 +1,130 @@
+mod common;
+
+use ethers::types::Signature;
+use poem::http;
+use tx_sitter_client::apis::admin_v1_api::{
+    CreateRelayerParams, RelayerCreateApiKeyParams, UpdateRelayerParams,
+};
+use tx_sitter_client::apis::relayer_v1_api::{
+    SignMessageParams, SignTypedDataParams,
+};
+use tx_sitter_client::apis::Error;
+
+use crate::common::prelude::*;
+
+#[tokio::test]
+async fn sign_message() -> eyre::Result<()> {
+    setup_tracing();
+
+    let (db_url, _db_container) = setup_db().await?;
+    let anvil = AnvilBuilder::default().spawn().await?;
+
+    let (_service, client) =
+        ServiceBuilder::default().build(&anvil, &db_url).await?;
+
+    let CreateRelayerResponse {
+        relayer_id,
+        address,
+    } = tx_sitter_client::apis::admin_v1_api::create_relayer(
+        &client,
+        CreateRelayerParams {
+            create_relayer_request: CreateRelayerRequest::new(
+                "Test relayer".to_string(),
+                DEFAULT_ANVIL_CHAIN_ID as i32,
+            ),
+        },
+    )
+    .await?;
+
+    let CreateApiKeyResponse { api_key, .. } =
+        tx_sitter_client::apis::admin_v1_api::relayer_create_api_key(
+            &client,
+            RelayerCreateApiKeyParams {
+                relayer_id: relayer_id.clone(),
+                name: None,
+                scopes: None,
+                expires_at: None,
+            },
+        )
+        .await?;
+
+    let message = b"Login challenge: 1234".to_vec();
+
+    // Signing is disabled by default
+    let res = tx_sitter_client::apis::relayer_v1_api::sign_message(
+        &relayer_client(&client, api_key.clone()),
+        SignMessageParams {
+            sign_message_request: SignMessageRequest {
+                message: message.clone().into(),
+            },
+        },
+    )
+    .await;
+
+    let Err(Error::ResponseError(e)) = res else {
+        eyre::bail!("Expected signing to be disabled");
+    };
+    assert_eq!(e.status, http::StatusCode::FORBIDDEN);
+
+    tracing::info!("Enabling signing");
+    tx_sitter_client::apis::admin_v1_api::update_relayer(
+        &client,
+        UpdateRelayerParams {
+            relayer_id: relayer_id.clone(),
+            relayer_update_request: RelayerUpdateRequest {
+                signing_enabled: Some(true),
+                ..Default::default()
+            },
+        },
+    )
+    .await?;
+
+    let SignatureResponse { hash, signature } =
+        tx_sitter_client::apis::relayer_v1_api::sign_message(
+            &relayer_client(&client, api_key.clone()),
+            SignMessageParams {
+                sign_message_request: SignMessageRequest {
+                    message: message.clone().into(),
+                },
+            },
+        )
+        .await?;
+
+    assert_eq!(hash.0, ethers::utils::hash_message(&message));
+    let signature = Signature::try_from(&signature.0[..])?;
+    assert_eq!(signature.recover(hash.0)?, address.0);
+
+    let typed_data = serde_json::json!({
+        "types": {
+            "EIP712Domain": [
+                { "name": "name", "type": "string" },
+                { "name": "chainId", "type": "uint256" }
+            ],
+            "Login": [
+                { "name": "nonce", "type": "uint256" }
+            ]
+        },
+        "primaryType": "Login",
+        "domain": {
+            "name": "Test",
+            "chainId": DEFAULT_ANVIL_CHAIN_ID
+        },
+        "message": {
+            "nonce": 1
+        }
+    });
+
+    let SignatureResponse { hash, signature } =
+        tx_sitter_client::apis::relayer_v1_api::sign_typed_data(
+            &relayer_client(&client, api_key.clone()),
+            SignTypedDataParams {
+                sign_typed_data_request: SignTypedDataRequest { typed_data },
+            },
+        )
+        .await?;
+
+    let signature = Signature::try_from(&signature.0[..])?;
+    assert_eq!(signature.recover(hash.0)?, address.0);
+
+    Ok(())
+}